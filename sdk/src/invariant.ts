@@ -347,13 +347,19 @@ export class Invariant {
   }
 
   async getFeeTiers(): Promise<FeeTier[]> {
-    return sendQuery(
+    const result = await sendQuery(
       this.contract,
       this.gasLimit,
       this.storageDepositLimit,
       InvariantQuery.GetFeeTiers,
       []
     )
+
+    if (result.ok) {
+      return parse(result.ok)
+    } else {
+      throw new Error(extractError(result.err))
+    }
   }
 
   async feeTierExist(
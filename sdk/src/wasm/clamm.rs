@@ -217,7 +217,7 @@ pub fn get_delta_y(
     };
 
     Ok(TokenAmount(delta_y.try_into().map_err(|_| {
-        err!(TrackableError::cast::<TokenAmount>().as_str())
+        err!(&TrackableError::cast::<TokenAmount>())
     })?))
 }
 #[wasm_wrapper]
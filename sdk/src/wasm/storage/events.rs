@@ -16,12 +16,16 @@ pub struct CreatePositionEvent {
     timestamp: u64,
     address: String,
     pool: PoolKey,
+    #[tsify(type = "bigint")]
+    index: u32,
     liquidity: Liquidity,
     #[tsify(type = "bigint")]
     lower_tick: i32,
     #[tsify(type = "bigint")]
     upper_tick: i32,
     current_sqrt_price: SqrtPrice,
+    amount_x: TokenAmount,
+    amount_y: TokenAmount,
 }
 
 #[derive(Default, Debug, PartialEq, Serialize, Deserialize, Tsify)]
@@ -32,6 +36,8 @@ pub struct ChangeLiquidityEvent {
     timestamp: u64,
     address: String,
     pool: PoolKey,
+    #[tsify(type = "bigint")]
+    index: u32,
     delta_liquidity: Liquidity,
     add_liquidity: bool,
     #[tsify(type = "bigint")]
@@ -39,6 +45,8 @@ pub struct ChangeLiquidityEvent {
     #[tsify(type = "bigint")]
     upper_tick: i32,
     current_sqrt_price: SqrtPrice,
+    amount_x: TokenAmount,
+    amount_y: TokenAmount,
 }
 
 #[derive(Default, Debug, PartialEq, Serialize, Deserialize, Tsify)]
@@ -61,12 +69,16 @@ pub struct RemovePositionEvent {
     timestamp: u64,
     address: String,
     pool: PoolKey,
+    #[tsify(type = "bigint")]
+    index: u32,
     liquidity: Liquidity,
     #[tsify(type = "bigint")]
     lower_tick: i32,
     #[tsify(type = "bigint")]
     upper_tick: i32,
     current_sqrt_price: SqrtPrice,
+    amount_x: TokenAmount,
+    amount_y: TokenAmount,
 }
 
 #[derive(Default, Debug, PartialEq, Serialize, Deserialize, Tsify)]
@@ -83,4 +95,7 @@ pub struct SwapEvent {
     start_sqrt_price: SqrtPrice,
     target_sqrt_price: SqrtPrice,
     x_to_y: bool,
+    liquidity: Liquidity,
+    #[tsify(type = "bigint")]
+    current_tick_index: i32,
 }
@@ -43,7 +43,7 @@ impl SqrtPrice {
             .checked_div(U256::from(Self::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<TokenAmount>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?;
         Ok(TokenAmount(result))
     }
 
@@ -71,7 +71,7 @@ impl SqrtPrice {
             .checked_div(U256::from(Self::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<TokenAmount>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?;
         Ok(TokenAmount::new(result))
     }
 
@@ -100,7 +100,7 @@ impl SqrtPrice {
                 .checked_div(denominator)
                 .ok_or_else(|| err!(TrackableError::DIV))?
                 .try_into()
-                .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?,
+                .map_err(|_| err!(&TrackableError::cast::<Self>()))?,
         ))
     }
 
@@ -123,7 +123,7 @@ impl SqrtPrice {
                 .checked_div(denominator)
                 .ok_or_else(|| err!(TrackableError::DIV))?
                 .try_into()
-                .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?,
+                .map_err(|_| err!(&TrackableError::cast::<Self>()))?,
         ))
     }
 }
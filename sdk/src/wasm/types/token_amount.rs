@@ -22,7 +22,7 @@ impl TokenAmount {
             .checked_div(U256::from(SqrtPrice::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<Self>()))?;
 
         Ok(TokenAmount(result))
     }
@@ -34,7 +34,7 @@ impl TokenAmount {
             .checked_div(U256::from(SqrtPrice::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<Self>()))?;
         Ok(TokenAmount(result))
     }
 }
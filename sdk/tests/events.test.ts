@@ -1,6 +1,7 @@
 import {
   CreatePositionEvent,
   CrossTickEvent,
+  calculateTick,
   ChangeLiquidityEvent,
   RemovePositionEvent,
   SwapEvent,
@@ -65,7 +66,10 @@ describe('events', async () => {
       liquidity: 1000000000000n,
       lowerTick: -10n,
       pool: poolKey,
+      index: 0n,
       upperTick: 10n,
+      amountX: 500n,
+      amountY: 500n,
       timestamp: 0n
     }
 
@@ -166,6 +170,8 @@ describe('events', async () => {
       startSqrtPrice: 1000000000000000000000000n,
       targetSqrtPrice: 997534045508785821944214n,
       xToY: true,
+      liquidity: 1000000000000n,
+      currentTickIndex: calculateTick(997534045508785821944214n, 1n),
       timestamp: 0n
     }
 
@@ -242,7 +248,10 @@ describe('events', async () => {
       liquidity: 1000000000000n,
       lowerTick: -10n,
       pool: poolKey,
+      index: 0n,
       upperTick: 10n,
+      amountX: 499n,
+      amountY: 499n,
       timestamp: 0n
     }
 
@@ -293,6 +302,9 @@ describe('events', async () => {
       lowerTick: -10n,
       upperTick: 10n,
       pool: poolKey,
+      index: 0n,
+      amountX: 500n,
+      amountY: 500n,
       timestamp: 0n
     }
 
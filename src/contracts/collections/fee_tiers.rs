@@ -1,10 +1,16 @@
 use crate::contracts::{FeeTier, InvariantError};
 use alloc::vec::Vec;
+use ink::storage::{Lazy, Mapping};
 
 #[ink::storage_item]
 #[derive(Debug, Default)]
 pub struct FeeTiers {
-    fee_tiers: Vec<FeeTier>,
+    // fee tiers stored in the root cell before storage version 2, moved to the mappings by `migrate`
+    legacy_fee_tiers: Vec<FeeTier>,
+    fee_tiers: Mapping<u16, FeeTier>,
+    fee_tier_indexes: Mapping<FeeTier, u16>,
+    // kept out of the root cell, so that its layout stays compatible with older versions
+    fee_tiers_length: Lazy<u16>,
 }
 
 impl FeeTiers {
@@ -13,27 +19,88 @@ impl FeeTiers {
             return Err(InvariantError::FeeTierAlreadyExist);
         }
 
-        self.fee_tiers.push(fee_tier);
-        Ok(())
+        // appending to the mappings before the legacy fee tiers are moved would change the order
+        if !self.legacy_fee_tiers.is_empty() {
+            self.legacy_fee_tiers.push(fee_tier);
+            return Ok(());
+        }
+
+        self.push(fee_tier)
     }
 
     pub fn remove(&mut self, fee_tier: FeeTier) -> Result<(), InvariantError> {
-        let index = self
-            .fee_tiers
+        if let Some(index) = self
+            .legacy_fee_tiers
             .iter()
             .position(|vec_fee_tier| *vec_fee_tier == fee_tier)
+        {
+            self.legacy_fee_tiers.remove(index);
+            return Ok(());
+        }
+
+        let index = self
+            .fee_tier_indexes
+            .get(fee_tier)
             .ok_or(InvariantError::FeeTierNotFound)?;
+        let length = self.fee_tiers_length.get_or_default();
+
+        // shift the following fee tiers to keep the index compact and the order of insertion
+        for next_index in index + 1..length {
+            let next_fee_tier = self
+                .fee_tiers
+                .get(next_index)
+                .ok_or(InvariantError::FeeTierNotFound)?;
+            self.fee_tiers.insert(next_index - 1, &next_fee_tier);
+            self.fee_tier_indexes
+                .insert(next_fee_tier, &(next_index - 1));
+        }
 
-        self.fee_tiers.remove(index);
+        self.fee_tiers.remove(length - 1);
+        self.fee_tier_indexes.remove(fee_tier);
+        self.fee_tiers_length.set(&(length - 1));
         Ok(())
     }
 
     pub fn contains(&self, fee_tier: FeeTier) -> bool {
-        self.fee_tiers.contains(&fee_tier)
+        self.fee_tier_indexes.contains(fee_tier) || self.legacy_fee_tiers.contains(&fee_tier)
+    }
+
+    pub fn get_all(&self) -> Result<Vec<FeeTier>, InvariantError> {
+        let length = self.fee_tiers_length.get_or_default();
+
+        (0..length)
+            .map(|index| {
+                self.fee_tiers
+                    .get(index)
+                    .ok_or(InvariantError::FeeTierNotFound)
+            })
+            .chain(self.legacy_fee_tiers.iter().copied().map(Ok))
+            .collect()
+    }
+
+    // Moves up to `size` legacy fee tiers to the mappings, returns their number and whether none are left.
+    pub fn migrate(&mut self, size: u32) -> Result<(u32, bool), InvariantError> {
+        let count = (size as usize).min(self.legacy_fee_tiers.len());
+
+        let fee_tiers: Vec<FeeTier> = self.legacy_fee_tiers.drain(..count).collect();
+        for fee_tier in fee_tiers {
+            self.push(fee_tier)?;
+        }
+
+        Ok((count as u32, self.legacy_fee_tiers.is_empty()))
     }
 
-    pub fn get_all(&self) -> Vec<FeeTier> {
-        self.fee_tiers.clone()
+    fn push(&mut self, fee_tier: FeeTier) -> Result<(), InvariantError> {
+        let length = self.fee_tiers_length.get_or_default();
+
+        self.fee_tiers.insert(length, &fee_tier);
+        self.fee_tier_indexes.insert(fee_tier, &length);
+        self.fee_tiers_length.set(
+            &length
+                .checked_add(1)
+                .ok_or(InvariantError::AddOverflow(length as u128, 1))?,
+        );
+        Ok(())
     }
 }
 
@@ -77,15 +144,66 @@ mod tests {
         let fee_tier_key = FeeTier::default();
         let new_fee_tier_key = FeeTier::new(Percentage::new(0), 2).unwrap();
 
-        let result = fee_tier_keys.get_all();
+        let result = fee_tier_keys.get_all().unwrap();
         assert_eq!(result, vec![]);
         assert_eq!(result.len(), 0);
 
         fee_tier_keys.add(fee_tier_key).unwrap();
         fee_tier_keys.add(new_fee_tier_key).unwrap();
 
-        let result = fee_tier_keys.get_all();
+        let result = fee_tier_keys.get_all().unwrap();
         assert_eq!(result, vec![fee_tier_key, new_fee_tier_key]);
         assert_eq!(result.len(), 2);
     }
+
+    #[ink::test]
+    fn test_remove_keeps_order() {
+        let fee_tier_keys = &mut FeeTiers::default();
+        let fee_tiers: Vec<FeeTier> = (1..=4)
+            .map(|tick_spacing| FeeTier::new(Percentage::new(0), tick_spacing).unwrap())
+            .collect();
+
+        for fee_tier in fee_tiers.iter() {
+            fee_tier_keys.add(*fee_tier).unwrap();
+        }
+
+        fee_tier_keys.remove(fee_tiers[1]).unwrap();
+        assert_eq!(
+            fee_tier_keys.get_all().unwrap(),
+            vec![fee_tiers[0], fee_tiers[2], fee_tiers[3]]
+        );
+        assert!(!fee_tier_keys.contains(fee_tiers[1]));
+
+        fee_tier_keys.remove(fee_tiers[3]).unwrap();
+        fee_tier_keys.add(fee_tiers[1]).unwrap();
+        assert_eq!(
+            fee_tier_keys.get_all().unwrap(),
+            vec![fee_tiers[0], fee_tiers[2], fee_tiers[1]]
+        );
+    }
+
+    #[ink::test]
+    fn test_migrate() {
+        let fee_tier_keys = &mut FeeTiers::default();
+        let fee_tiers: Vec<FeeTier> = (1..=4)
+            .map(|tick_spacing| FeeTier::new(Percentage::new(0), tick_spacing).unwrap())
+            .collect();
+        fee_tier_keys.legacy_fee_tiers = fee_tiers[..3].to_vec();
+
+        assert!(fee_tier_keys.contains(fee_tiers[0]));
+        fee_tier_keys.add(fee_tiers[3]).unwrap();
+        assert_eq!(fee_tier_keys.get_all().unwrap(), fee_tiers);
+
+        assert_eq!(fee_tier_keys.migrate(2), Ok((2, false)));
+        assert_eq!(fee_tier_keys.get_all().unwrap(), fee_tiers);
+
+        fee_tier_keys.remove(fee_tiers[2]).unwrap();
+        assert_eq!(fee_tier_keys.migrate(2), Ok((1, true)));
+        assert!(fee_tier_keys.legacy_fee_tiers.is_empty());
+        assert_eq!(
+            fee_tier_keys.get_all().unwrap(),
+            vec![fee_tiers[0], fee_tiers[1], fee_tiers[3]]
+        );
+        assert_eq!(fee_tier_keys.migrate(2), Ok((0, true)));
+    }
 }
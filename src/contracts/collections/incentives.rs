@@ -0,0 +1,81 @@
+use crate::contracts::{Incentive, InvariantError};
+use ink::storage::{Lazy, Mapping};
+
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct Incentives {
+    incentives: Mapping<u32, Incentive>,
+    // kept out of the root cell so that the packed root layout stays unchanged
+    incentives_length: Lazy<u32>,
+}
+
+impl Incentives {
+    pub fn add(&mut self, incentive: &Incentive) -> Result<u32, InvariantError> {
+        let incentive_id = self.incentives_length.get_or_default();
+
+        self.incentives.insert(incentive_id, incentive);
+        self.incentives_length.set(
+            &incentive_id
+                .checked_add(1)
+                .ok_or(InvariantError::AddOverflow(incentive_id as u128, 1))?,
+        );
+
+        Ok(incentive_id)
+    }
+
+    pub fn update(
+        &mut self,
+        incentive_id: u32,
+        incentive: &Incentive,
+    ) -> Result<(), InvariantError> {
+        self.get(incentive_id)?;
+
+        self.incentives.insert(incentive_id, incentive);
+        Ok(())
+    }
+
+    pub fn get(&self, incentive_id: u32) -> Result<Incentive, InvariantError> {
+        self.incentives
+            .get(incentive_id)
+            .ok_or(InvariantError::IncentiveNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::types::token_amount::TokenAmount;
+
+    #[ink::test]
+    fn test_add() {
+        let incentives = &mut Incentives::default();
+        let incentive = Incentive::default();
+        let new_incentive = Incentive {
+            total_reward: TokenAmount(100),
+            ..Incentive::default()
+        };
+
+        assert_eq!(incentives.add(&incentive), Ok(0));
+        assert_eq!(incentives.add(&new_incentive), Ok(1));
+        assert_eq!(incentives.get(0), Ok(incentive));
+        assert_eq!(incentives.get(1), Ok(new_incentive));
+        assert_eq!(incentives.get(2), Err(InvariantError::IncentiveNotFound));
+    }
+
+    #[ink::test]
+    fn test_update() {
+        let incentives = &mut Incentives::default();
+        let incentive = Incentive::default();
+        let new_incentive = Incentive {
+            total_reward: TokenAmount(100),
+            ..Incentive::default()
+        };
+
+        let result = incentives.update(0, &new_incentive);
+        assert_eq!(result, Err(InvariantError::IncentiveNotFound));
+
+        incentives.add(&incentive).unwrap();
+        incentives.update(0, &new_incentive).unwrap();
+        assert_eq!(incentives.get(0), Ok(new_incentive));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::contracts::{InvariantError, PoolKey};
+use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
+
+// owners that locked a position in the pool, positions move between indexes so they are looked up
+// in the owner lists when queried
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct LockedPositions {
+    owners_length: Mapping<PoolKey, u32>,
+    owners: Mapping<(PoolKey, u32), AccountId>,
+    registered: Mapping<(PoolKey, AccountId), ()>,
+}
+
+impl LockedPositions {
+    pub fn add_owner(&mut self, pool_key: PoolKey, owner: AccountId) -> Result<(), InvariantError> {
+        if self.registered.contains((pool_key, owner)) {
+            return Ok(());
+        }
+
+        let owners_length = self.get_length(pool_key);
+        self.owners.insert((pool_key, owners_length), &owner);
+        self.owners_length.insert(
+            pool_key,
+            &(owners_length
+                .checked_add(1)
+                .ok_or(InvariantError::AddOverflow(owners_length as u128, 1))?),
+        );
+        self.registered.insert((pool_key, owner), &());
+
+        Ok(())
+    }
+
+    pub fn get_owners(&self, pool_key: PoolKey) -> Vec<AccountId> {
+        (0..self.get_length(pool_key))
+            .filter_map(|index| self.owners.get((pool_key, index)))
+            .collect()
+    }
+
+    pub fn get_length(&self, pool_key: PoolKey) -> u32 {
+        self.owners_length.get(pool_key).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::FeeTier;
+    use crate::math::percentage::Percentage;
+    use decimal::*;
+
+    #[ink::test]
+    fn test_add_owner() {
+        let locked_positions = &mut LockedPositions::default();
+        let pool_key = PoolKey::new(
+            AccountId::from([0x01; 32]),
+            AccountId::from([0x02; 32]),
+            FeeTier::new(Percentage::new(1), 1).unwrap(),
+        )
+        .unwrap();
+        let other_pool_key = PoolKey {
+            token_x: AccountId::from([0x03; 32]),
+            ..pool_key
+        };
+        let owner = AccountId::from([0x04; 32]);
+        let other_owner = AccountId::from([0x05; 32]);
+
+        assert_eq!(locked_positions.get_owners(pool_key), vec![]);
+
+        locked_positions.add_owner(pool_key, owner).unwrap();
+        locked_positions.add_owner(pool_key, owner).unwrap();
+        locked_positions.add_owner(pool_key, other_owner).unwrap();
+        locked_positions.add_owner(other_pool_key, owner).unwrap();
+
+        assert_eq!(locked_positions.get_length(pool_key), 2);
+        assert_eq!(
+            locked_positions.get_owners(pool_key),
+            vec![owner, other_owner]
+        );
+        assert_eq!(locked_positions.get_owners(other_pool_key), vec![owner]);
+    }
+}
@@ -1,11 +1,25 @@
 pub mod fee_tiers;
+pub mod incentives;
+pub mod locked_positions;
+pub mod pool_creation_fees;
 pub mod pool_keys;
+pub mod pool_statistics;
 pub mod pools;
 pub mod positions;
+pub mod referral_fees;
+pub mod stakes;
 pub mod ticks;
+pub mod token_statuses;
 
 pub use fee_tiers::*;
+pub use incentives::*;
+pub use locked_positions::*;
+pub use pool_creation_fees::*;
 pub use pool_keys::*;
+pub use pool_statistics::*;
 pub use pools::*;
 pub use positions::*;
+pub use referral_fees::*;
+pub use stakes::*;
 pub use ticks::*;
+pub use token_statuses::*;
@@ -0,0 +1,59 @@
+use crate::contracts::{FeeAsset, InvariantError};
+use crate::math::types::token_amount::TokenAmount;
+use decimal::*;
+use ink::storage::Mapping;
+
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct PoolCreationFees {
+    collected: Mapping<FeeAsset, TokenAmount>,
+}
+
+impl PoolCreationFees {
+    pub fn add(&mut self, asset: FeeAsset, amount: TokenAmount) -> Result<(), InvariantError> {
+        let collected = self.get(asset);
+        let collected = collected
+            .checked_add(amount)
+            .map_err(|_| InvariantError::AddOverflow(collected.get(), amount.get()))?;
+
+        self.collected.insert(asset, &collected);
+        Ok(())
+    }
+
+    pub fn take(&mut self, asset: FeeAsset) -> TokenAmount {
+        self.collected.take(asset).unwrap_or(TokenAmount(0))
+    }
+
+    pub fn get(&self, asset: FeeAsset) -> TokenAmount {
+        self.collected.get(asset).unwrap_or(TokenAmount(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::primitives::AccountId;
+
+    #[ink::test]
+    fn test_add_and_take() {
+        let pool_creation_fees = &mut PoolCreationFees::default();
+        let token = FeeAsset::Token(AccountId::from([0x01; 32]));
+
+        assert_eq!(pool_creation_fees.get(FeeAsset::Native), TokenAmount(0));
+
+        pool_creation_fees.add(token, TokenAmount(10)).unwrap();
+        pool_creation_fees.add(token, TokenAmount(5)).unwrap();
+        pool_creation_fees
+            .add(FeeAsset::Native, TokenAmount(1))
+            .unwrap();
+        assert_eq!(pool_creation_fees.get(token), TokenAmount(15));
+        assert_eq!(pool_creation_fees.get(FeeAsset::Native), TokenAmount(1));
+
+        let result = pool_creation_fees.add(token, TokenAmount(u128::MAX));
+        assert_eq!(result, Err(InvariantError::AddOverflow(15, u128::MAX)));
+
+        assert_eq!(pool_creation_fees.take(token), TokenAmount(15));
+        assert_eq!(pool_creation_fees.get(token), TokenAmount(0));
+        assert_eq!(pool_creation_fees.get(FeeAsset::Native), TokenAmount(1));
+    }
+}
@@ -7,6 +7,8 @@ use ink::storage::Mapping;
 pub struct PoolKeys {
     pool_keys: Mapping<PoolKey, u16>,
     pool_keys_by_index: Mapping<u16, PoolKey>,
+    // stays in the root cell: it has a fixed encoded size, and moving it behind a `Lazy` would
+    // still leave a legacy field in the packed root layout that deployed contracts decode
     pool_keys_length: u16,
 }
 
@@ -31,7 +33,7 @@ impl PoolKeys {
     }
 
     pub fn contains(&self, pool_key: PoolKey) -> bool {
-        self.pool_keys.get(pool_key).is_some()
+        self.pool_keys.contains(pool_key)
     }
 
     pub fn get_all(&self, size: u16, offset: u16) -> Vec<PoolKey> {
@@ -54,6 +56,12 @@ impl PoolKeys {
             .collect()
     }
 
+    pub fn get(&self, index: u16) -> Result<PoolKey, InvariantError> {
+        self.pool_keys_by_index
+            .get(index)
+            .ok_or(InvariantError::PoolKeyNotFound)
+    }
+
     pub fn count(&self) -> u16 {
         self.pool_keys_length
     }
@@ -0,0 +1,18 @@
+use crate::contracts::{PoolKey, PoolStats};
+use ink::storage::Mapping;
+
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct PoolStatistics {
+    stats: Mapping<PoolKey, PoolStats>,
+}
+
+impl PoolStatistics {
+    pub fn update(&mut self, pool_key: PoolKey, stats: &PoolStats) {
+        self.stats.insert(pool_key, stats);
+    }
+
+    pub fn get(&self, pool_key: PoolKey) -> PoolStats {
+        self.stats.get(pool_key).unwrap_or_default()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::contracts::InvariantError;
+use crate::math::types::token_amount::TokenAmount;
+use decimal::*;
+use ink::{primitives::AccountId, storage::Mapping};
+
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct ReferralFees {
+    balances: Mapping<(AccountId, AccountId), TokenAmount>,
+}
+
+impl ReferralFees {
+    pub fn add(
+        &mut self,
+        referrer: AccountId,
+        token: AccountId,
+        amount: TokenAmount,
+    ) -> Result<(), InvariantError> {
+        let balance = self.get(referrer, token);
+        let balance = balance
+            .checked_add(amount)
+            .map_err(|_| InvariantError::AddOverflow(balance.get(), amount.get()))?;
+
+        self.balances.insert((referrer, token), &balance);
+        Ok(())
+    }
+
+    pub fn take(&mut self, referrer: AccountId, token: AccountId) -> TokenAmount {
+        self.balances
+            .take((referrer, token))
+            .unwrap_or(TokenAmount(0))
+    }
+
+    pub fn get(&self, referrer: AccountId, token: AccountId) -> TokenAmount {
+        self.balances
+            .get((referrer, token))
+            .unwrap_or(TokenAmount(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn test_add_and_take() {
+        let referral_fees = &mut ReferralFees::default();
+        let referrer = AccountId::from([0x01; 32]);
+        let token = AccountId::from([0x02; 32]);
+        let other_token = AccountId::from([0x03; 32]);
+
+        assert_eq!(referral_fees.get(referrer, token), TokenAmount(0));
+
+        referral_fees.add(referrer, token, TokenAmount(10)).unwrap();
+        referral_fees.add(referrer, token, TokenAmount(5)).unwrap();
+        referral_fees
+            .add(referrer, other_token, TokenAmount(1))
+            .unwrap();
+        assert_eq!(referral_fees.get(referrer, token), TokenAmount(15));
+        assert_eq!(referral_fees.get(referrer, other_token), TokenAmount(1));
+
+        let result = referral_fees.add(referrer, token, TokenAmount(u128::MAX));
+        assert_eq!(result, Err(InvariantError::AddOverflow(15, u128::MAX)));
+
+        assert_eq!(referral_fees.take(referrer, token), TokenAmount(15));
+        assert_eq!(referral_fees.get(referrer, token), TokenAmount(0));
+        assert_eq!(referral_fees.take(referrer, token), TokenAmount(0));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::contracts::{InvariantError, Stake};
+use ink::{primitives::AccountId, storage::Mapping};
+
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct Stakes {
+    stakes_length: Mapping<AccountId, u32>,
+    stakes: Mapping<(AccountId, u32), Stake>,
+}
+
+impl Stakes {
+    pub fn add(&mut self, account_id: AccountId, stake: &Stake) -> Result<u32, InvariantError> {
+        let stakes_length = self.get_length(account_id);
+
+        self.stakes.insert((account_id, stakes_length), stake);
+        self.stakes_length.insert(
+            account_id,
+            &(stakes_length
+                .checked_add(1)
+                .ok_or(InvariantError::AddOverflow(stakes_length as u128, 1))?),
+        );
+
+        Ok(stakes_length)
+    }
+
+    pub fn update(
+        &mut self,
+        account_id: AccountId,
+        index: u32,
+        stake: &Stake,
+    ) -> Result<(), InvariantError> {
+        if index >= self.get_length(account_id) {
+            return Err(InvariantError::StakeNotFound);
+        }
+
+        self.stakes.insert((account_id, index), stake);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, account_id: AccountId, index: u32) -> Result<Stake, InvariantError> {
+        let stake = self.get(account_id, index)?;
+        let last_index = self
+            .get_length(account_id)
+            .checked_sub(1)
+            .ok_or(InvariantError::StakeNotFound)?;
+
+        // the last stake takes the place of the removed one
+        if index < last_index {
+            let last_stake = self.get(account_id, last_index)?;
+            self.stakes.insert((account_id, index), &last_stake);
+        }
+        self.stakes.remove((account_id, last_index));
+        self.stakes_length.insert(account_id, &last_index);
+
+        Ok(stake)
+    }
+
+    pub fn get(&self, account_id: AccountId, index: u32) -> Result<Stake, InvariantError> {
+        self.stakes
+            .get((account_id, index))
+            .ok_or(InvariantError::StakeNotFound)
+    }
+
+    pub fn get_length(&self, account_id: AccountId) -> u32 {
+        self.stakes_length.get(account_id).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn test_add_remove() {
+        let stakes = &mut Stakes::default();
+        let account_id = AccountId::from([0x01; 32]);
+        let stake = Stake::default();
+        let new_stake = Stake {
+            incentive_id: 1,
+            ..Stake::default()
+        };
+
+        assert_eq!(stakes.add(account_id, &stake), Ok(0));
+        assert_eq!(stakes.add(account_id, &new_stake), Ok(1));
+        assert_eq!(stakes.get_length(account_id), 2);
+
+        assert_eq!(stakes.remove(account_id, 0), Ok(stake));
+        assert_eq!(stakes.get(account_id, 0), Ok(new_stake));
+        assert_eq!(
+            stakes.get(account_id, 1),
+            Err(InvariantError::StakeNotFound)
+        );
+        assert_eq!(stakes.get_length(account_id), 1);
+
+        assert_eq!(stakes.remove(account_id, 0), Ok(new_stake));
+        assert_eq!(stakes.get_length(account_id), 0);
+        assert_eq!(
+            stakes.remove(account_id, 0),
+            Err(InvariantError::StakeNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn test_update() {
+        let stakes = &mut Stakes::default();
+        let account_id = AccountId::from([0x01; 32]);
+        let stake = Stake::default();
+        let new_stake = Stake {
+            incentive_id: 1,
+            ..Stake::default()
+        };
+
+        let result = stakes.update(account_id, 0, &new_stake);
+        assert_eq!(result, Err(InvariantError::StakeNotFound));
+
+        stakes.add(account_id, &stake).unwrap();
+        stakes.update(account_id, 0, &new_stake).unwrap();
+        assert_eq!(stakes.get(account_id, 0), Ok(new_stake));
+    }
+}
@@ -34,6 +34,13 @@ impl Ticks {
         Ok(())
     }
 
+    // Writes ticks read in the same call, so their existence is not checked again
+    pub fn update_batch(&mut self, pool_key: PoolKey, ticks: &[Tick]) {
+        for tick in ticks {
+            self.ticks.insert((pool_key, tick.index), tick);
+        }
+    }
+
     pub fn remove(&mut self, pool_key: PoolKey, index: i32) -> Result<(), InvariantError> {
         self.get(pool_key, index)?;
 
@@ -103,6 +110,31 @@ mod tests {
         assert_eq!(result, Err(InvariantError::TickNotFound));
     }
 
+    #[ink::test]
+    fn test_update_batch() {
+        let ticks = &mut Ticks::default();
+        let token_x = AccountId::from([0x01; 32]);
+        let token_y = AccountId::from([0x02; 32]);
+        let fee_tier = FeeTier {
+            fee: Percentage::new(0),
+            tick_spacing: 1,
+        };
+        let pool_key = PoolKey::new(token_x, token_y, fee_tier).unwrap();
+        let new_ticks = [-1, 1].map(|index| Tick {
+            index,
+            seconds_outside: 1,
+            ..Tick::default()
+        });
+
+        for tick in new_ticks.iter() {
+            ticks.add(pool_key, tick.index, &Tick::default()).unwrap();
+        }
+
+        ticks.update_batch(pool_key, &new_ticks);
+        assert_eq!(ticks.get(pool_key, -1), Ok(new_ticks[0]));
+        assert_eq!(ticks.get(pool_key, 1), Ok(new_ticks[1]));
+    }
+
     #[ink::test]
     fn test_remove() {
         let ticks = &mut Ticks::default();
@@ -0,0 +1,47 @@
+use crate::contracts::TokenStatus;
+use ink::primitives::AccountId;
+use ink::storage::Mapping;
+
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct TokenStatuses {
+    token_statuses: Mapping<AccountId, TokenStatus>,
+}
+
+impl TokenStatuses {
+    pub fn set(&mut self, token: AccountId, status: TokenStatus) {
+        if status == TokenStatus::Unlisted {
+            self.token_statuses.remove(token);
+        } else {
+            self.token_statuses.insert(token, &status);
+        }
+    }
+
+    pub fn get(&self, token: AccountId) -> TokenStatus {
+        self.token_statuses.get(token).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn test_set() {
+        let token_statuses = &mut TokenStatuses::default();
+        let token = AccountId::from([0x01; 32]);
+        let other_token = AccountId::from([0x02; 32]);
+
+        assert_eq!(token_statuses.get(token), TokenStatus::Unlisted);
+
+        token_statuses.set(token, TokenStatus::Allowed);
+        assert_eq!(token_statuses.get(token), TokenStatus::Allowed);
+        assert_eq!(token_statuses.get(other_token), TokenStatus::Unlisted);
+
+        token_statuses.set(token, TokenStatus::Denied);
+        assert_eq!(token_statuses.get(token), TokenStatus::Denied);
+
+        token_statuses.set(token, TokenStatus::Unlisted);
+        assert_eq!(token_statuses.get(token), TokenStatus::Unlisted);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     contracts::{
-        CalculateSwapResult, FeeTier, InvariantError, LiquidityTick, Pool, PoolKey, Position,
-        QuoteResult, SwapHop, Tick,
+        CalculateSwapResult, FeeAsset, FeeTier, Incentive, InvariantCall, InvariantCallResult,
+        InvariantError, LiquidityTick, Pool, PoolCreationFee, PoolHook, PoolKey, PoolStats,
+        PoolStatus, Position, QuoteResult, Referral, SplitRoute, Stake, SwapHop, Tick, TokenStatus,
     },
     math::{
         liquidity::Liquidity, percentage::Percentage, sqrt_price::SqrtPrice,
@@ -23,6 +24,9 @@ pub trait InvariantEntrypoints {
     /// # Parameters
     /// - `pool_key`: A unique key that identifies the specified pool.
     ///
+    /// # Events
+    /// - On successful withdrawal, emits a `Withdraw Protocol Fee` event.
+    ///
     /// # Errors
     /// - Reverts the call when the caller is an unauthorized receiver.
     ///
@@ -36,17 +40,89 @@ pub trait InvariantEntrypoints {
     /// # Parameters
     /// - `protocol_fee`: The expected fee represented as a percentage.
     ///
+    /// # Events
+    /// - Emits a `Change Protocol Fee` event upon success.
+    ///
     /// # Errors
     /// - Reverts the call when the caller is an unauthorized user.
     #[ink(message)]
     fn change_protocol_fee(&mut self, protocol_fee: Percentage) -> Result<(), InvariantError>;
 
+    /// Retrieves the maximum number of swap steps performed within a single swap.
+    #[ink(message)]
+    fn get_max_swap_steps(&self) -> u32;
+
+    /// Allows an admin to adjust the maximum number of swap steps performed within a single swap.
+    ///
+    /// # Parameters
+    /// - `max_swap_steps`: The maximum number of swap steps, defaults to `MAX_SWAP_STEPS`.
+    ///
+    /// # Events
+    /// - Emits a `Change Max Swap Steps` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if the maximum number of swap steps is zero.
+    #[ink(message)]
+    fn change_max_swap_steps(&mut self, max_swap_steps: u32) -> Result<(), InvariantError>;
+
+    /// Retrieves the fee charged for creating a pool, `None` if pool creation is free.
+    #[ink(message)]
+    fn get_pool_creation_fee(&self) -> Option<PoolCreationFee>;
+
+    /// Allows an admin to set the fee charged for creating a pool, in native AZERO or a PSP22 token.
+    ///
+    /// # Parameters
+    /// - `pool_creation_fee`: The asset and amount of the fee, `None` disables the fee.
+    ///
+    /// # Events
+    /// - Emits a `Change Pool Creation Fee` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    #[ink(message)]
+    fn change_pool_creation_fee(
+        &mut self,
+        pool_creation_fee: Option<PoolCreationFee>,
+    ) -> Result<(), InvariantError>;
+
+    /// Retrieves the amount of pool creation fees collected in the specified asset and not yet withdrawn.
+    ///
+    /// # Parameters
+    /// - `asset`: Native AZERO or the address of a PSP22 token.
+    #[ink(message)]
+    fn get_collected_pool_creation_fee(&self, asset: FeeAsset) -> TokenAmount;
+
+    /// Allows an admin to withdraw the pool creation fees collected in the specified asset to the default fee receiver.
+    ///
+    /// # Parameters
+    /// - `asset`: Native AZERO or the address of a PSP22 token.
+    ///
+    /// # Events
+    /// - On successful withdrawal, emits a `Withdraw Pool Creation Fee` event.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if no fees were collected in the asset.
+    /// - Fails if the transfer fails.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn withdraw_pool_creation_fee(
+        &mut self,
+        asset: FeeAsset,
+    ) -> Result<TokenAmount, InvariantError>;
+
     /// Allows admin to change current fee receiver.
     ///
     /// # Parameters
     /// - `pool_key`: A unique key that identifies the specified pool.
     /// - `fee_receiver`: An `AccountId` identifying the user authorized to claim fees.
     ///
+    /// # Events
+    /// - Emits a `Change Fee Receiver` event upon success.
+    ///
     /// # Errors
     /// - Reverts the call when the caller is an unauthorized user.
     #[ink(message)]
@@ -56,6 +132,124 @@ pub trait InvariantEntrypoints {
         fee_receiver: AccountId,
     ) -> Result<(), InvariantError>;
 
+    /// Allows admin to change the fee receiver of many pools at once.
+    ///
+    /// # Parameters
+    /// - `pool_keys`: Unique keys that identify the pools.
+    /// - `fee_receiver`: An `AccountId` identifying the user authorized to claim fees.
+    ///
+    /// # Events
+    /// - Emits a `Change Fee Receiver` event for every pool upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if any of the pools does not exist.
+    #[ink(message)]
+    fn change_fee_receivers(
+        &mut self,
+        pool_keys: Vec<PoolKey>,
+        fee_receiver: AccountId,
+    ) -> Result<(), InvariantError>;
+
+    /// Retrieves the fee receiver assigned to newly created pools, which also receives the pool creation fees.
+    #[ink(message)]
+    fn get_default_fee_receiver(&self) -> AccountId;
+
+    /// Allows admin to change the fee receiver assigned to newly created pools. Existing pools keep their fee receivers.
+    ///
+    /// # Parameters
+    /// - `fee_receiver`: An `AccountId` identifying the user authorized to claim fees.
+    ///
+    /// # Events
+    /// - Emits a `Change Default Fee Receiver` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    #[ink(message)]
+    fn change_default_fee_receiver(
+        &mut self,
+        fee_receiver: AccountId,
+    ) -> Result<(), InvariantError>;
+
+    /// Allows admin to change the status of a pool, e.g. to wind down pools on removed fee tiers.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `status`: The new status of the pool: active, withdraw-only or closed.
+    ///
+    /// # Events
+    /// - Emits a `Change Pool Status` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if pool does not exist
+    #[ink(message)]
+    fn change_pool_status(
+        &mut self,
+        pool_key: PoolKey,
+        status: PoolStatus,
+    ) -> Result<(), InvariantError>;
+
+    /// Allows admin to mark a pool as curated (verified) or remove the mark.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `curated`: Whether the pool should be listed as curated.
+    ///
+    /// # Events
+    /// - Emits a `Change Pool Curation` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if pool does not exist
+    #[ink(message)]
+    fn change_pool_curation(
+        &mut self,
+        pool_key: PoolKey,
+        curated: bool,
+    ) -> Result<(), InvariantError>;
+
+    /// Allows admin to unregister the hook contract of a pool, e.g. when it blocks swaps or deposits.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    ///
+    /// # Events
+    /// - Emits a `Remove Pool Hook` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if pool does not exist
+    /// - Fails if the pool has no hook
+    #[ink(message)]
+    fn remove_pool_hook(&mut self, pool_key: PoolKey) -> Result<(), InvariantError>;
+
+    /// Allows admin to allowlist or denylist a token. Pools cannot be created with denied tokens,
+    /// pools created with two allowed tokens are curated.
+    ///
+    /// # Parameters
+    /// - `token`: The address of the token.
+    /// - `status`: The new status of the token: unlisted, allowed or denied.
+    ///
+    /// # Events
+    /// - Emits a `Change Token Status` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    #[ink(message)]
+    fn change_token_status(
+        &mut self,
+        token: AccountId,
+        status: TokenStatus,
+    ) -> Result<(), InvariantError>;
+
+    /// Retrieves the allowlist/denylist status of a token.
+    ///
+    /// # Parameters
+    /// - `token`: The address of the token.
+    #[ink(message)]
+    fn get_token_status(&self, token: AccountId) -> TokenStatus;
+
     /// Opens a position.
     ///
     /// # Parameters
@@ -75,9 +269,12 @@ pub trait InvariantEntrypoints {
     /// - Fails if the price has reached the slippage limit.
     /// - Fails if the allowance is insufficient or the user balance transfer fails.
     /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    /// - Fails if the pool `before_modify_position` hook call fails
     ///
     /// # External contracts
     /// - PSP22
+    /// - InvariantHooks
     #[ink(message)]
     fn create_position(
         &mut self,
@@ -89,6 +286,48 @@ pub trait InvariantEntrypoints {
         slippage_limit_upper: SqrtPrice,
     ) -> Result<Position, InvariantError>;
 
+    /// Opens a position from token amounts, computing the liquidity on-chain at the current pool price.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `lower_tick`: The index of the lower tick for opening the position.
+    /// - `upper_tick`: The index of the upper tick for opening the position.
+    /// - `max_x`: The maximum amount of token x the user is willing to deposit.
+    /// - `max_y`: The maximum amount of token y the user is willing to deposit.
+    /// - `min_x`: The minimum amount of token x that has to be deposited.
+    /// - `min_y`: The minimum amount of token y that has to be deposited.
+    /// - `slippage_limit_lower`: The price limit for downward movement to implement slippage protection.
+    /// - `slippage_limit_upper`: The price limit for upward movement to implement slippage protection.
+    ///
+    /// # Events
+    /// - On successful transfer, emits a `Create Position` event for the newly opened position.
+    ///
+    /// # Errors
+    /// - Fails if the amounts result in zero liquidity.
+    /// - Fails if the user attempts to create a position with invalid tick indexes or tick spacing.
+    /// - Fails if less than the minimum amounts would be deposited.
+    /// - Fails if the price has reached the slippage limit.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[allow(clippy::too_many_arguments)]
+    #[ink(message)]
+    fn create_position_by_amounts(
+        &mut self,
+        pool_key: PoolKey,
+        lower_tick: i32,
+        upper_tick: i32,
+        max_x: TokenAmount,
+        max_y: TokenAmount,
+        min_x: TokenAmount,
+        min_y: TokenAmount,
+        slippage_limit_lower: SqrtPrice,
+        slippage_limit_upper: SqrtPrice,
+    ) -> Result<Position, InvariantError>;
+
     /// Changes a liquidity of a position.
     ///
     /// # Parameters
@@ -104,9 +343,13 @@ pub trait InvariantEntrypoints {
     /// - Fails if the price has reached the slippage limit.
     /// - Fails if the allowance is insufficient or the user balance transfer fails.
     /// - Fails if position does not exist
+    /// - Fails if liquidity is added to a pool that is not active or changed in a closed pool
+    /// - Fails if liquidity is removed from a locked position
+    /// - Fails if the pool `before_modify_position` hook call fails when adding liquidity
     ///
     /// # External contracts
     /// - PSP22
+    /// - InvariantHooks
     #[ink(message)]
     fn change_liquidity(
         &mut self,
@@ -136,9 +379,13 @@ pub trait InvariantEntrypoints {
     /// - Fails if the allowance is insufficient or the user balance transfer fails.
     /// - Fails if there is insufficient liquidity in pool
     /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    /// - Fails if the swap would exceed the maximum number of swap steps
+    /// - Fails if the pool `before_swap` hook vetoes the swap or its call fails
     ///
     /// # External contracts
     /// - PSP22
+    /// - InvariantHooks
     #[ink(message)]
     fn swap(
         &mut self,
@@ -149,6 +396,39 @@ pub trait InvariantEntrypoints {
         sqrt_price_limit: SqrtPrice,
     ) -> Result<CalculateSwapResult, InvariantError>;
 
+    /// Performs a single swap that stops at the price limit or after the maximum number of swap steps instead of failing, leaving the rest of the amount unfilled.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit at which the swap stops.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if there is insufficient liquidity in pool
+    /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn swap_partial(
+        &mut self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
     /// Performs atomic swap involving several pools based on the provided parameters.
     ///
     /// # Parameters
@@ -167,67 +447,381 @@ pub trait InvariantEntrypoints {
     /// - Fails if the allowance is insufficient or the user balance transfer fails.
     /// - Fails if the minimum amount out after a single swap is insufficient to perform the next swap to achieve the expected amount out.
     /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    /// - Fails if any of the swaps would exceed the maximum number of swap steps
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn swap_route(
+        &mut self,
+        amount_in: TokenAmount,
+        expected_amount_out: TokenAmount,
+        slippage: Percentage,
+        swaps: Vec<SwapHop>,
+    ) -> Result<(), InvariantError>;
+
+    /// Performs a single swap that charges a referral fee on top of the pool fee, accrued to the referrer.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `referral`: The referrer and its fee, taken from the amount out when swapping by amount in and added to the amount in otherwise.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    /// - On a successful swap, emits a `Referral Fee` event if a nonzero fee was accrued.
+    ///
+    /// # Errors
+    /// - Fails if the referral fee exceeds the maximum referral fee.
+    /// - Fails in every case `swap` would fail.
+    ///
+    /// # External contracts
+    /// - PSP22
+    /// - InvariantHooks
+    #[ink(message)]
+    fn swap_with_referral(
+        &mut self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+        referral: Referral,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Performs atomic swap involving several pools, charging a referral fee on the final amount out.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `expected_amount_out`: The amount of tokens that the user wants to receive as a result of the swaps.
+    /// - `slippage`: The max acceptable percentage difference between the expected and actual amount of output tokens, after the referral fee.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    /// - `referral`: The referrer and its fee.
+    ///
+    /// # Events
+    /// - On every successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On every successful swap, emits a `Cross Tick` event for every single tick crossed.
+    /// - On the last swap, emits a `Referral Fee` event if a nonzero fee was accrued.
+    ///
+    /// # Errors
+    /// - Fails if the referral fee exceeds the maximum referral fee.
+    /// - Fails in every case `swap_route` would fail.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn swap_route_with_referral(
+        &mut self,
+        amount_in: TokenAmount,
+        expected_amount_out: TokenAmount,
+        slippage: Percentage,
+        swaps: Vec<SwapHop>,
+        referral: Referral,
+    ) -> Result<(), InvariantError>;
+
+    /// Transfers all referral fees accrued to the caller in the specified token.
+    ///
+    /// # Parameters
+    /// - `token`: Address of the token to claim.
+    ///
+    /// # Events
+    /// - On successful claim, emits a `Claim Referral Fee` event.
+    ///
+    /// # Errors
+    /// - Fails if the caller has no referral fees accrued in the token.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn claim_referral_fee(&mut self, token: AccountId) -> Result<TokenAmount, InvariantError>;
+
+    /// Retrieves the amount of referral fees accrued to the referrer in the specified token.
+    ///
+    /// # Parameters
+    /// - `referrer`: Address of the referrer.
+    /// - `token`: Address of the token.
+    #[ink(message)]
+    fn get_referral_fee(&self, referrer: AccountId, token: AccountId) -> TokenAmount;
+
+    /// Performs a single swap on the pool of the token pair that gives the best result across all fee tiers.
+    ///
+    /// # Parameters
+    /// - `token_in`: Address of the token the user wants to swap.
+    /// - `token_out`: Address of the token the user wants to receive.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `amount_limit`: The minimum amount out when swapping by amount in, the maximum amount in otherwise.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the tokens are the same.
+    /// - Fails if no pool of the token pair can perform the swap.
+    /// - Fails if the amount out is lower or the amount in is higher than the `amount_limit`.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn swap_best(
+        &mut self,
+        token_in: AccountId,
+        token_out: AccountId,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        amount_limit: TokenAmount,
+    ) -> Result<(PoolKey, CalculateSwapResult), InvariantError>;
+
+    /// Performs several routes of swaps atomically, splitting the trade across multiple pools.
+    ///
+    /// # Parameters
+    /// - `routes`: A vector of routes, each with its own input amount and swap steps. All routes must start and end with the same tokens and may not share pools.
+    /// - `expected_amount_out`: The combined amount of tokens that the user wants to receive from all routes.
+    /// - `slippage`: The max acceptable percentage difference between the expected and actual combined amount of output tokens.
+    ///
+    /// # Events
+    /// - On every successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On every successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the routes are empty, contain no swaps, use different input or output tokens or share a pool.
+    /// - Fails if the combined amount out is insufficient.
+    /// - Fails if any of the swaps in the routes fails.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn swap_split(
+        &mut self,
+        routes: Vec<SplitRoute>,
+        expected_amount_out: TokenAmount,
+        slippage: Percentage,
+    ) -> Result<Vec<TokenAmount>, InvariantError>;
+
+    /// Simulates the swap without its execution. When the maximum number of swap steps is reached,
+    /// the simulation stops and returns the amount left unfilled with `max_swap_steps_reached` set.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: The amount of tokens that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the price has reached the specified limit.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    #[ink(message)]
+    fn quote(
+        &self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+    ) -> Result<QuoteResult, InvariantError>;
+
+    /// Simulates the swap on every pool of the token pair and returns the one with the best result.
+    ///
+    /// # Parameters
+    /// - `token_in`: Address of the token the user wants to swap.
+    /// - `token_out`: Address of the token the user wants to receive.
+    /// - `amount`: The amount of tokens that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    ///
+    /// # Errors
+    /// - Fails if the tokens are the same.
+    /// - Fails if no pool of the token pair can perform the swap.
+    #[ink(message)]
+    fn quote_best(
+        &self,
+        token_in: AccountId,
+        token_out: AccountId,
+        amount: TokenAmount,
+        by_amount_in: bool,
+    ) -> Result<(PoolKey, QuoteResult), InvariantError>;
+
+    /// Simulates the partial-fill swap without its execution, returning the amount left unfilled at the price limit or after the maximum number of swap steps.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: The amount of tokens that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit at which the swap stops.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    #[ink(message)]
+    fn quote_partial(
+        &self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+    ) -> Result<QuoteResult, InvariantError>;
+
+    /// Simulates multiple swaps without its execution.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    /// - Fails if the pool is not active
+    /// - Fails if any of the swaps would exceed the maximum number of swap steps
+    #[ink(message)]
+    fn quote_route(
+        &self,
+        amount_in: TokenAmount,
+        swaps: Vec<SwapHop>,
+    ) -> Result<TokenAmount, InvariantError>;
+
+    /// Simulates the split routes without their execution, returning the amount out of every route.
+    ///
+    /// # Parameters
+    /// - `routes`: A vector of routes, each with its own input amount and swap steps.
+    ///
+    /// # Errors
+    /// - Fails if the routes are empty, contain no swaps, use different input or output tokens or share a pool.
+    /// - Fails if any of the swaps in the routes fails.
+    #[ink(message)]
+    fn quote_split(&self, routes: Vec<SplitRoute>) -> Result<Vec<TokenAmount>, InvariantError>;
+
+    /// Executes several mutating messages sequentially within a single transaction.
+    ///
+    /// # Parameters
+    /// - `calls`: A vector of calls, each mirroring a mutating message of the contract.
+    ///
+    /// # Events
+    /// - Emits the events of every executed call.
+    ///
+    /// # Errors
+    /// - Fails with the error of the first failing call, reverting all of the calls.
+    /// - Fails on calls creating a pool while the pool creation fee is paid in the native token.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn multicall(
+        &mut self,
+        calls: Vec<InvariantCall>,
+    ) -> Result<Vec<InvariantCallResult>, InvariantError>;
+
+    /// Transfers a position between users.
+    /// Also used to burn positions.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the user position to transfer.
+    /// - `receiver`: An `AccountId` identifying the user who will own the position.
+    ///
+    /// # Events
+    /// - Emits a `Transfer Position` event containing the index of the position on both accounts.
+    ///
+    /// # Errors
+    /// - Fails if position cannot be found
+    /// - Fails if the pool is closed
+    /// - Fails if the position is locked
+    #[ink(message)]
+    fn transfer_position(&mut self, index: u32, receiver: AccountId) -> Result<(), InvariantError>;
+
+    /// Carves a new position with the same range out of a user position and gives it to the receiver.
+    /// Fees accrued so far are split proportionally to liquidity, the new position starts with the
+    /// same fee growth checkpoints. Returns the index of the new position on the receiver account.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the user position to split.
+    /// - `liquidity`: The liquidity moved to the new position.
+    /// - `receiver`: An `AccountId` identifying the user who will own the new position.
+    ///
+    /// # Events
+    /// - Emits a `Split Position` event containing the index of the position on both accounts.
     ///
-    /// # External contracts
-    /// - PSP22
+    /// # Errors
+    /// - Fails if position cannot be found
+    /// - Fails if the pool is closed
+    /// - Fails if the position is locked
+    /// - Fails if the liquidity is zero or not lower than the position liquidity
+    /// - Fails if the pool `before_modify_position` hook call for the receiver fails
     #[ink(message)]
-    fn swap_route(
+    fn split_position(
         &mut self,
-        amount_in: TokenAmount,
-        expected_amount_out: TokenAmount,
-        slippage: Percentage,
-        swaps: Vec<SwapHop>,
-    ) -> Result<(), InvariantError>;
+        index: u32,
+        liquidity: Liquidity,
+        receiver: AccountId,
+    ) -> Result<u32, InvariantError>;
 
-    /// Simulates the swap without its execution.
+    /// Merges two user positions with the same pool and range into one, summing their liquidity,
+    /// owed fees and lifetime totals and keeping the later lock. Returns the index of the merged position,
+    /// the second position is removed.
     ///
     /// # Parameters
-    /// - `pool_key`: A unique key that identifies the specified pool.
-    /// - `x_to_y`: A boolean specifying the swap direction.
-    /// - `amount`: The amount of tokens that the user wants to swap.
-    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
-    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `index_a`: The index of the position that is kept.
+    /// - `index_b`: The index of the position merged into it.
+    ///
+    /// # Events
+    /// - Emits a `Merge Positions` event upon success.
     ///
     /// # Errors
-    /// - Fails if the user attempts to perform a swap with zero amounts.
-    /// - Fails if the price has reached the specified limit.
-    /// - Fails if the user would receive zero tokens.
-    /// - Fails if pool does not exist
+    /// - Fails if either position cannot be found
+    /// - Fails if the indexes are equal or the positions differ in pool or range
+    /// - Fails if the pool is closed
+    /// - Fails if the pool `before_modify_position` hook call fails
+    ///
+    /// # External contracts
+    /// - InvariantHooks
     #[ink(message)]
-    fn quote(
-        &self,
-        pool_key: PoolKey,
-        x_to_y: bool,
-        amount: TokenAmount,
-        by_amount_in: bool,
-        sqrt_price_limit: SqrtPrice,
-    ) -> Result<QuoteResult, InvariantError>;
+    fn merge_positions(&mut self, index_a: u32, index_b: u32) -> Result<u32, InvariantError>;
 
-    /// Simulates multiple swaps without its execution.
+    /// Locks the liquidity of a user position until the given time. Locked positions cannot be removed,
+    /// decreased or transferred, fees can still be claimed. The lock can only be extended.
     ///
     /// # Parameters
-    /// - `amount_in`: The amount of tokens that the user wants to swap.
-    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    /// - `index`: The index of the user position to lock.
+    /// - `locked_until`: Timestamp in seconds until which the position stays locked.
+    ///
+    /// # Events
+    /// - Emits a `Lock Position` event upon success.
     ///
     /// # Errors
-    /// - Fails if the user attempts to perform a swap with zero amounts.
-    /// - Fails if the user would receive zero tokens.
-    /// - Fails if pool does not exist
+    /// - Fails if position cannot be found
+    /// - Fails if the pool is closed
+    /// - Fails if the timestamp is not in the future or does not extend the current lock
     #[ink(message)]
-    fn quote_route(
-        &self,
-        amount_in: TokenAmount,
-        swaps: Vec<SwapHop>,
-    ) -> Result<TokenAmount, InvariantError>;
+    fn lock_position(&mut self, index: u32, locked_until: u64) -> Result<(), InvariantError>;
 
-    /// Transfers a position between users.
-    /// Also used to burn positions.
+    /// Retrieves positions in the pool that are currently locked, together with their owners and indexes.
+    /// Staked positions are held by their program and are not listed until they are unstaked.
     ///
     /// # Parameters
-    /// - `index`: The index of the user position to transfer.
-    /// - `receiver`: An `AccountId` identifying the user who will own the position.
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `size`: Amount of positions to retrieve.
+    /// - `offset`: The number of locked positions to skip.
     #[ink(message)]
-    fn transfer_position(&mut self, index: u32, receiver: AccountId) -> Result<(), InvariantError>;
+    fn get_locked_positions(
+        &self,
+        pool_key: PoolKey,
+        size: u32,
+        offset: u32,
+    ) -> Vec<(AccountId, u32, Position)>;
 
     /// Retrieves information about a single position.
     ///
@@ -263,15 +857,47 @@ pub trait InvariantEntrypoints {
     /// # Parameters
     /// - `index`: The index of the user position from which fees will be claimed.
     ///
+    /// # Events
+    /// - Emits a `Claim Fee` event upon success.
+    ///
     /// # Errors
     /// - Fails if the position cannot be found.
+    /// - Fails if the pool is closed.
     ///
     /// # External contracts
     /// - PSP22
     #[ink(message)]
     fn claim_fee(&mut self, index: u32) -> Result<(TokenAmount, TokenAmount), InvariantError>;
 
+    /// Distributes tokens sent by the caller to the liquidity providers currently in range, without the protocol fee cut.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `amount_x`: The amount of token x to donate.
+    /// - `amount_y`: The amount of token y to donate.
+    ///
+    /// # Events
+    /// - Emits a `Donate` event upon success.
+    ///
+    /// # Errors
+    /// - Fails if both amounts are zero.
+    /// - Fails if the pool cannot be found.
+    /// - Fails if the pool is closed.
+    /// - Fails if the pool has no active liquidity.
+    /// - Fails if the allowance is insufficient or the user balance is too low.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn donate(
+        &mut self,
+        pool_key: PoolKey,
+        amount_x: TokenAmount,
+        amount_y: TokenAmount,
+    ) -> Result<(), InvariantError>;
+
     /// Removes a position. Sends tokens associated with specified position to the owner.
+    /// Allowed in every pool status, including closed pools.
     ///
     /// # Parameters
     /// - `index`: The index of the user position to be removed.
@@ -281,9 +907,11 @@ pub trait InvariantEntrypoints {
     ///
     /// # Errors
     /// - Fails if Position cannot be found
+    /// - Fails if the position is locked
     ///
     /// # External contracts
     /// - PSP22
+    /// - InvariantHooks
     #[ink(message)]
     fn remove_position(&mut self, index: u32)
         -> Result<(TokenAmount, TokenAmount), InvariantError>;
@@ -293,6 +921,9 @@ pub trait InvariantEntrypoints {
     /// # Parameters
     /// - `fee_tier`: A struct identifying the pool fee and tick spacing.
     ///
+    /// # Events
+    /// - Emits an `Add Fee Tier` event upon success.
+    ///
     /// # Errors
     /// - Fails if an unauthorized user attempts to create a fee tier.
     /// - Fails if the tick spacing is invalid.
@@ -313,6 +944,9 @@ pub trait InvariantEntrypoints {
     /// # Parameters
     /// - `fee_tier`: A struct identifying the pool fee and tick spacing.
     ///
+    /// # Events
+    /// - Emits a `Remove Fee Tier` event upon success.
+    ///
     /// # Errors
     /// - Fails if an unauthorized user attempts to remove a fee tier.
     /// - Fails if fee tier does not exist
@@ -322,6 +956,7 @@ pub trait InvariantEntrypoints {
     /// Allows a user to create a custom pool on a specified token pair and fee tier.
     /// The contract specifies the order of tokens as x and y, the lower token address assigned as token x.
     /// The choice is deterministic.
+    /// If a pool creation fee is set, native AZERO has to be transferred with the call or PSP22 tokens are transferred from the caller.
     ///
     /// # Parameters
     /// - `token_0`: The address of the first token.
@@ -330,13 +965,22 @@ pub trait InvariantEntrypoints {
     /// - `init_sqrt_price`: The square root of the price for the initial pool related to `init_tick`.
     /// - `init_tick`: The initial tick at which the pool will be created.
     ///
+    /// # Events
+    /// - Emits a `Create Pool` event upon success.
+    ///
     /// # Errors
     /// - Fails if the specified fee tier cannot be found.
     /// - Fails if the user attempts to create a pool for the same tokens.
     /// - Fails if Pool with same tokens and fee tier already exist.
     /// - Fails if the init tick is not divisible by the tick spacing.
     /// - Fails if the init sqrt price is not related to the init tick.
-    #[ink(message)]
+    /// - Fails if either of the tokens is denied.
+    /// - Fails if the transferred value does not match the pool creation fee.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message, payable)]
     fn create_pool(
         &mut self,
         token_0: AccountId,
@@ -346,6 +990,80 @@ pub trait InvariantEntrypoints {
         init_tick: i32,
     ) -> Result<(), InvariantError>;
 
+    /// Creates a pool like `create_pool` and registers a hook contract implementing `InvariantHooks` on it.
+    /// The hook is called on swaps and position changes in the pool, depending on the enabled flags.
+    /// Only `before_swap` and `before_modify_position` on deposits can revert the operation, by
+    /// vetoing or failing. Failures of every other callback are ignored, so withdrawals cannot be blocked.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee_tier`: A struct identifying the pool fee and tick spacing.
+    /// - `init_sqrt_price`: The square root of the price for the initial pool related to `init_tick`.
+    /// - `init_tick`: The initial tick at which the pool will be created.
+    /// - `hook`: The address of the hook contract and the flags of the enabled callbacks.
+    ///
+    /// # Events
+    /// - Emits a `Create Pool` event upon success.
+    ///
+    /// # Errors
+    /// - Fails if no callback is enabled or the flags contain unknown bits.
+    /// - Fails for the same reasons as `create_pool`.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message, payable)]
+    fn create_pool_with_hook(
+        &mut self,
+        token_0: AccountId,
+        token_1: AccountId,
+        fee_tier: FeeTier,
+        init_sqrt_price: SqrtPrice,
+        init_tick: i32,
+        hook: PoolHook,
+    ) -> Result<(), InvariantError>;
+
+    /// Creates a pool and opens its first position in a single call, so the initial price cannot be
+    /// moved between the two operations. The init tick is derived from `init_sqrt_price`.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee_tier`: A struct identifying the pool fee and tick spacing.
+    /// - `init_sqrt_price`: The square root of the price for the initial pool.
+    /// - `lower_tick`: The index of the lower tick for opening the position.
+    /// - `upper_tick`: The index of the upper tick for opening the position.
+    /// - `liquidity_delta`: The desired liquidity provided by the user in the specified range.
+    ///
+    /// # Events
+    /// - Emits a `Create Pool` event and a `Create Position` event upon success.
+    ///
+    /// # Errors
+    /// - Fails if the specified fee tier cannot be found.
+    /// - Fails if the user attempts to create a pool for the same tokens.
+    /// - Fails if Pool with same tokens and fee tier already exist.
+    /// - Fails if the init sqrt price is out of range.
+    /// - Fails if either of the tokens is denied.
+    /// - Fails if the transferred value does not match the pool creation fee.
+    /// - Fails if the user attempts to open a position with zero liquidity.
+    /// - Fails if the user attempts to create a position with invalid tick indexes or tick spacing.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[allow(clippy::too_many_arguments)]
+    #[ink(message, payable)]
+    fn create_pool_with_position(
+        &mut self,
+        token_0: AccountId,
+        token_1: AccountId,
+        fee_tier: FeeTier,
+        init_sqrt_price: SqrtPrice,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: Liquidity,
+    ) -> Result<Position, InvariantError>;
+
     /// Retrieves information about a pool created on a specified token pair with an associated fee tier.
     ///
     /// # Parameters
@@ -363,6 +1081,17 @@ pub trait InvariantEntrypoints {
         fee_tier: FeeTier,
     ) -> Result<Pool, InvariantError>;
 
+    /// Retrieves cumulative swap statistics of a pool: volume and fees in both tokens and the number of swaps.
+    /// Counters wrap on overflow, volume over a period is the difference of two snapshots.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    ///
+    /// # Errors
+    /// - Fails if pool does not exist
+    #[ink(message)]
+    fn get_pool_stats(&self, pool_key: PoolKey) -> Result<PoolStats, InvariantError>;
+
     /// Retrieves information about a tick at a specified index.
     ///
     /// # Parameters
@@ -388,6 +1117,19 @@ pub trait InvariantEntrypoints {
     #[ink(message)]
     fn get_pool_keys(&self, size: u16, offset: u16) -> Result<(Vec<PoolKey>, u16), InvariantError>;
 
+    /// Retrieves listed pool keys along with the status of each pool
+    /// - `size`: Amount of pool keys to retrive, at most `MAX_POOL_KEYS_WITH_STATUS_RETURNED`
+    /// - `offset`: The offset from which retrive pools.
+    /// - `curated`: Optionally lists only curated (`Some(true)`) or uncurated (`Some(false)`) pools.
+    ///   The offset and the returned count then refer to the matching pools only.
+    #[ink(message)]
+    fn get_pool_keys_with_status(
+        &self,
+        size: u16,
+        offset: u16,
+        curated: Option<bool>,
+    ) -> Result<(Vec<(PoolKey, PoolStatus)>, u16), InvariantError>;
+
     /// Retrieves listed pools for provided token pair
     /// - `token0`: Address of first token
     /// - `token1`: Address of second token
@@ -399,8 +1141,11 @@ pub trait InvariantEntrypoints {
     ) -> Result<Vec<(FeeTier, Pool)>, InvariantError>;
 
     /// Retrieves available fee tiers
+    ///
+    /// # Errors
+    /// - Fails if a stored fee tier cannot be found
     #[ink(message)]
-    fn get_fee_tiers(&self) -> Vec<FeeTier>;
+    fn get_fee_tiers(&self) -> Result<Vec<FeeTier>, InvariantError>;
 
     /// Retrieves the amount of positions held by the user.
     ///
@@ -464,22 +1209,197 @@ pub trait InvariantEntrypoints {
     #[ink(message)]
     fn withdraw_all_wazero(&self, address: AccountId) -> Result<(), InvariantError>;
 
+    /// Funds a liquidity mining program rewarding in-range liquidity of positions staked to it.
+    ///
+    /// # Parameters
+    /// - `pool_key`: A unique key that identifies the pool whose liquidity is rewarded.
+    /// - `reward_token`: Address of the token paid out as the reward.
+    /// - `reward`: The total amount of the reward distributed over the program.
+    /// - `start_timestamp`: The time from which positions can be staked, the reward is spread over the whole program duration.
+    /// - `end_timestamp`: The time after which positions no longer earn rewards.
+    ///
+    /// # Events
+    /// - On success, emits a `Create Incentive` event.
+    ///
+    /// # Errors
+    /// - Fails if pool does not exist
+    /// - Fails if the reward is zero.
+    /// - Fails if the program does not end after it starts or has already ended.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn create_incentive(
+        &mut self,
+        pool_key: PoolKey,
+        reward_token: AccountId,
+        reward: TokenAmount,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) -> Result<u32, InvariantError>;
+
+    /// Refunds the reward left unclaimed after the program ended to its funder. All stakes have to be unstaked
+    /// first, so that the rewards they earned are paid out before the refund. Stakes left after the program
+    /// ended can be unstaked by anyone with `force_unstake_position`.
+    ///
+    /// # Parameters
+    /// - `incentive_id`: An identifier of the program.
+    ///
+    /// # Events
+    /// - On success, emits an `End Incentive` event.
+    ///
+    /// # Errors
+    /// - Fails if the program does not exist.
+    /// - Fails if the program has not ended yet.
+    /// - Fails if any position is still staked to the program.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn end_incentive(&mut self, incentive_id: u32) -> Result<TokenAmount, InvariantError>;
+
+    /// Stakes a position to a liquidity mining program. The position is held by the program until it is unstaked.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the user position.
+    /// - `incentive_id`: An identifier of the program.
+    ///
+    /// # Events
+    /// - On success, emits a `Stake Position` event.
+    ///
+    /// # Errors
+    /// - Fails if the program or the position does not exist.
+    /// - Fails if the program is not running.
+    /// - Fails if the position belongs to a different pool than the program.
+    /// - Fails if the pool is closed.
+    /// - Fails if the position has no liquidity.
+    #[ink(message)]
+    fn stake_position(&mut self, index: u32, incentive_id: u32) -> Result<u32, InvariantError>;
+
+    /// Claims the reward earned by a staked position since it was staked or last claimed.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the user stake.
+    ///
+    /// # Events
+    /// - On success, emits a `Claim Incentive Reward` event.
+    ///
+    /// # Errors
+    /// - Fails if the stake does not exist.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn claim_incentive_reward(&mut self, index: u32) -> Result<TokenAmount, InvariantError>;
+
+    /// Claims the earned reward and returns the staked position to the user.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the user stake.
+    ///
+    /// # Events
+    /// - On success, emits an `Unstake Position` event.
+    ///
+    /// # Errors
+    /// - Fails if the stake does not exist.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn unstake_position(&mut self, index: u32) -> Result<TokenAmount, InvariantError>;
+
+    /// Unstakes a position of any user once its program has ended. The earned reward is paid out and
+    /// the position is returned to its owner.
+    ///
+    /// # Parameters
+    /// - `owner`: An `AccountId` identifying the owner of the stake.
+    /// - `index`: The index of the stake on the owner account.
+    ///
+    /// # Events
+    /// - On success, emits an `Unstake Position` event.
+    ///
+    /// # Errors
+    /// - Fails if the stake does not exist.
+    /// - Fails if the program has not ended yet.
+    ///
+    /// # External contracts
+    /// - PSP22
+    #[ink(message)]
+    fn force_unstake_position(
+        &mut self,
+        owner: AccountId,
+        index: u32,
+    ) -> Result<TokenAmount, InvariantError>;
+
+    /// Retrieves information about a liquidity mining program.
+    ///
+    /// # Parameters
+    /// - `incentive_id`: An identifier of the program.
+    ///
+    /// # Errors
+    /// - Fails if the program does not exist.
+    #[ink(message)]
+    fn get_incentive(&self, incentive_id: u32) -> Result<Incentive, InvariantError>;
+
+    /// Retrieves information about a single stake.
+    ///
+    /// # Parameters
+    /// - `owner`: An `AccountId` identifying the user who owns the stake.
+    /// - `index`: The index of the user stake.
+    ///
+    /// # Errors
+    /// - Fails if the stake does not exist.
+    #[ink(message)]
+    fn get_stake(&self, owner: AccountId, index: u32) -> Result<Stake, InvariantError>;
+
     /// Updates seconds per liquidity for a given position
     ///
     /// # Parameters
     /// - `index`: index of the updated position
+    ///
+    /// # Errors
+    /// - Fails if position cannot be found
+    /// - Fails if the pool is closed
     #[ink(message)]
     fn update_position_seconds_per_liquidity(&mut self, index: u32) -> Result<(), InvariantError>;
     /// Modifies the contract code. Only the admin can call this function.
     ///
     /// WARNING: The state of this contract and the contract you want to change the code to must be the same.
     /// See: https://use.ink/basics/upgradeable-contracts#storage-compatibility.
+    /// Records stored in an older layout are decoded with defaults for the new fields, `migrate` rewrites them.
     ///
     /// # Parameters
     /// - `code_hash`: The code hash of the contract you want to change the code to.
+    ///
+    /// # Events
+    /// - Emits a `Set Code` event upon success.
     #[ink(message)]
     fn set_code(&mut self, code_hash: Hash) -> Result<(), InvariantError>;
 
+    /// Retrieves the version of the storage layout, lower than `STORAGE_VERSION` until `migrate` completes after an upgrade.
+    #[ink(message)]
+    fn get_storage_version(&self) -> u32;
+
+    /// Converts records stored in an older layout to the current one after `set_code`, in batches.
+    /// The contract stays usable during the migration, as legacy records are decoded with defaults.
+    /// Positions are not enumerable and are rewritten in the current layout on their next update.
+    ///
+    /// # Parameters
+    /// - `batch_size`: The maximum number of records converted in this call.
+    ///
+    /// # Returns
+    /// - `true` once the storage version is equal to `STORAGE_VERSION`, `false` if more calls are needed.
+    ///
+    /// # Events
+    /// - Emits a `Migrate` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if the storage is already at the current version.
+    #[ink(message)]
+    fn migrate(&mut self, batch_size: u32) -> Result<bool, InvariantError>;
+
     /// Retrieves the admin of the contract.
     ///
     /// # Returns
@@ -492,6 +1412,9 @@ pub trait InvariantEntrypoints {
     /// # Parameters
     /// - `new_admin`: The new admin address.
     ///
+    /// # Events
+    /// - Emits a `Change Admin` event upon success.
+    ///
     /// # Errors
     /// - Fails if the caller is not the current admin.
     #[ink(message)]
@@ -1,3 +1,5 @@
+use traceable_result::{ErrorKind, TrackableError};
+
 #[derive(Debug, PartialEq, Eq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 pub enum InvariantError {
@@ -38,4 +40,148 @@ pub enum InvariantError {
     SetCodeHashError,
     LiquidityChangeZero,
     WAZEROIncorrectMainnetAddressId,
+    PoolNotActive,
+    PoolClosed,
+    TokenDenied,
+    AmountUnderMinimumDeposit,
+    AmountOverMaximumDeposit,
+    MathError(MathError),
+    MaxSwapStepsReached,
+    InvalidMaxSwapSteps,
+    AmountOverMaximumAmountIn,
+    InvalidSplitRoutes,
+    IncentiveNotFound,
+    InvalidIncentiveTime,
+    IncentiveNotActive,
+    IncentiveNotEnded,
+    InvalidIncentivePool,
+    StakeNotFound,
+    InvalidHookFlags,
+    HookCallFailed,
+    SwapVetoedByHook,
+    InvalidSplitLiquidity,
+    PositionsNotMergeable,
+    PositionLocked,
+    InvalidLockTime,
+    InvalidReferralFee,
+    InvalidPoolCreationFee,
+    AlreadyMigrated,
+    IncentiveHasStakes,
+    PoolHasNoHook,
+    MulticallNotPayable,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum MathError {
+    Overflow,
+    Underflow,
+    Cast,
+    DivByZero,
+    PriceOutOfRange,
+    Other,
+}
+
+impl From<&TrackableError> for MathError {
+    fn from(err: &TrackableError) -> Self {
+        match err.kind {
+            ErrorKind::Overflow => MathError::Overflow,
+            ErrorKind::Underflow => MathError::Underflow,
+            ErrorKind::DivByZero => MathError::DivByZero,
+            ErrorKind::Cast => MathError::Cast,
+            ErrorKind::PriceOutOfRange => MathError::PriceOutOfRange,
+            ErrorKind::Other => MathError::Other,
+        }
+    }
+}
+
+impl From<TrackableError> for InvariantError {
+    fn from(err: TrackableError) -> Self {
+        InvariantError::MathError(MathError::from(&err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{
+        get_tick_at_sqrt_price,
+        sqrt_price::{calculate_sqrt_price, SqrtPrice},
+        token_amount::TokenAmount,
+        CURRENT_SQRT_PRICE_BELOW_LOWER, INVALID_TICKS, MAX_SQRT_PRICE, MAX_TICK,
+        SQRT_PRICE_OUT_OF_RANGE, TICK_OUT_OF_RANGE, UPPER_SQRT_PRICE_BELOW_CURRENT,
+    };
+    use decimal::*;
+    use traceable_result::*;
+
+    #[test]
+    fn test_math_error_from_trackable_error() {
+        let cases = [
+            (err!(TrackableError::ADD), MathError::Overflow),
+            (err!(TrackableError::MUL), MathError::Overflow),
+            (err!(TrackableError::SUB), MathError::Underflow),
+            (err!(TrackableError::DIV), MathError::DivByZero),
+            (err!(&TrackableError::cast::<u128>()), MathError::Cast),
+            (
+                err!(&TrackableError::cast::<TokenAmount>()),
+                MathError::Cast,
+            ),
+            (err!(SQRT_PRICE_OUT_OF_RANGE), MathError::PriceOutOfRange),
+            (err!(TICK_OUT_OF_RANGE), MathError::PriceOutOfRange),
+            (err!(INVALID_TICKS), MathError::PriceOutOfRange),
+            (
+                err!(UPPER_SQRT_PRICE_BELOW_CURRENT),
+                MathError::PriceOutOfRange,
+            ),
+            (
+                err!(CURRENT_SQRT_PRICE_BELOW_LOWER),
+                MathError::PriceOutOfRange,
+            ),
+            (err!("InsufficientLiquidity"), MathError::Other),
+            (err!("conversion to u128 type failed"), MathError::Other),
+            (err!("addition overflow"), MathError::Other),
+        ];
+
+        for (err, expected) in cases {
+            assert_eq!(
+                InvariantError::from(err),
+                InvariantError::MathError(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_math_error_from_math_failures() {
+        assert_eq!(
+            MathError::from(&calculate_sqrt_price(MAX_TICK + 1).unwrap_err()),
+            MathError::PriceOutOfRange
+        );
+        assert_eq!(
+            MathError::from(
+                &get_tick_at_sqrt_price(SqrtPrice::new(MAX_SQRT_PRICE + 1), 1).unwrap_err()
+            ),
+            MathError::PriceOutOfRange
+        );
+        assert_eq!(
+            MathError::from(
+                &TokenAmount(u128::MAX)
+                    .checked_add(TokenAmount(1))
+                    .map_err(|_| err!(TrackableError::ADD))
+                    .unwrap_err()
+            ),
+            MathError::Overflow
+        );
+        assert_eq!(
+            MathError::from(
+                &SqrtPrice::big_div_values_to_token(U256::from(1), U256::from(0)).unwrap_err()
+            ),
+            MathError::DivByZero
+        );
+        assert_eq!(
+            MathError::from(
+                &SqrtPrice::big_div_values_to_token(U256::MAX, U256::from(1)).unwrap_err()
+            ),
+            MathError::Cast
+        );
+    }
 }
@@ -1,39 +1,57 @@
 use crate::{
-    contracts::PoolKey,
-    math::{liquidity::Liquidity, sqrt_price::SqrtPrice, token_amount::TokenAmount},
+    contracts::{FeeAsset, FeeTier, PoolCreationFee, PoolHook, PoolKey, PoolStatus, TokenStatus},
+    math::{
+        liquidity::Liquidity, percentage::Percentage, sqrt_price::SqrtPrice,
+        token_amount::TokenAmount,
+    },
+};
+use ink::{
+    prelude::vec::Vec,
+    primitives::{AccountId, Hash},
 };
-use ink::{prelude::vec::Vec, primitives::AccountId};
 
 #[ink::event]
 pub struct CreatePositionEvent {
     #[ink(topic)]
     pub timestamp: u64,
+    #[ink(topic)]
     pub address: AccountId,
+    #[ink(topic)]
     pub pool: PoolKey,
+    pub index: u32,
     pub liquidity: Liquidity,
     pub lower_tick: i32,
     pub upper_tick: i32,
     pub current_sqrt_price: SqrtPrice,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
 }
 
 #[ink::event]
 pub struct ChangeLiquidityEvent {
     #[ink(topic)]
     pub timestamp: u64,
+    #[ink(topic)]
     pub address: AccountId,
+    #[ink(topic)]
     pub pool: PoolKey,
+    pub index: u32,
     pub delta_liquidity: Liquidity,
     pub add_liquidity: bool,
     pub lower_tick: i32,
     pub upper_tick: i32,
     pub current_sqrt_price: SqrtPrice,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
 }
 
 #[ink::event]
 pub struct CrossTickEvent {
     #[ink(topic)]
     pub timestamp: u64,
+    #[ink(topic)]
     pub address: AccountId,
+    #[ink(topic)]
     pub pool: PoolKey,
     pub indexes: Vec<i32>,
 }
@@ -42,19 +60,26 @@ pub struct CrossTickEvent {
 pub struct RemovePositionEvent {
     #[ink(topic)]
     pub timestamp: u64,
+    #[ink(topic)]
     pub address: AccountId,
+    #[ink(topic)]
     pub pool: PoolKey,
+    pub index: u32,
     pub liquidity: Liquidity,
     pub lower_tick: i32,
     pub upper_tick: i32,
     pub current_sqrt_price: SqrtPrice,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
 }
 
 #[ink::event]
 pub struct SwapEvent {
     #[ink(topic)]
     pub timestamp: u64,
+    #[ink(topic)]
     pub address: AccountId,
+    #[ink(topic)]
     pub pool: PoolKey,
     pub amount_in: TokenAmount,
     pub amount_out: TokenAmount,
@@ -62,4 +87,352 @@ pub struct SwapEvent {
     pub start_sqrt_price: SqrtPrice,
     pub target_sqrt_price: SqrtPrice,
     pub x_to_y: bool,
+    pub liquidity: Liquidity,
+    pub current_tick_index: i32,
+}
+
+#[ink::event]
+pub struct CreatePoolEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub token_x: AccountId,
+    #[ink(topic)]
+    pub token_y: AccountId,
+    pub address: AccountId,
+    pub fee_tier: FeeTier,
+    pub init_sqrt_price: SqrtPrice,
+    pub init_tick: i32,
+    pub fee_receiver: AccountId,
+}
+
+#[ink::event]
+pub struct DonateEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+    pub liquidity: Liquidity,
+}
+
+#[ink::event]
+pub struct ReferralFeeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub referrer: AccountId,
+    pub pool: PoolKey,
+    pub token: AccountId,
+    pub amount: TokenAmount,
+}
+
+#[ink::event]
+pub struct ClaimReferralFeeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub token: AccountId,
+    pub amount: TokenAmount,
+}
+
+#[ink::event]
+pub struct ClaimFeeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub index: u32,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+}
+
+#[ink::event]
+pub struct WithdrawProtocolFeeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+}
+
+#[ink::event]
+pub struct AddFeeTierEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub fee_tier: FeeTier,
+}
+
+#[ink::event]
+pub struct RemoveFeeTierEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub fee_tier: FeeTier,
+}
+
+#[ink::event]
+pub struct ChangeProtocolFeeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub protocol_fee: Percentage,
+}
+
+#[ink::event]
+pub struct ChangePoolCreationFeeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub pool_creation_fee: Option<PoolCreationFee>,
+}
+
+#[ink::event]
+pub struct WithdrawPoolCreationFeeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub receiver: AccountId,
+    pub asset: FeeAsset,
+    pub amount: TokenAmount,
+}
+
+#[ink::event]
+pub struct ChangeMaxSwapStepsEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub max_swap_steps: u32,
+}
+
+#[ink::event]
+pub struct ChangeDefaultFeeReceiverEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub fee_receiver: AccountId,
+}
+
+#[ink::event]
+pub struct ChangeFeeReceiverEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    #[ink(topic)]
+    pub fee_receiver: AccountId,
+    pub address: AccountId,
+}
+
+#[ink::event]
+pub struct ChangePoolStatusEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub status: PoolStatus,
+}
+
+#[ink::event]
+pub struct ChangePoolCurationEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub curated: bool,
+}
+
+#[ink::event]
+pub struct RemovePoolHookEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub hook: PoolHook,
+}
+
+#[ink::event]
+pub struct ChangeTokenStatusEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub token: AccountId,
+    pub status: TokenStatus,
+}
+
+#[ink::event]
+pub struct TransferPositionEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub receiver: AccountId,
+    pub pool: PoolKey,
+    pub index: u32,
+    pub receiver_index: u32,
+}
+
+#[ink::event]
+pub struct LockPositionEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub index: u32,
+    pub locked_until: u64,
+}
+
+#[ink::event]
+pub struct SplitPositionEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub receiver: AccountId,
+    pub pool: PoolKey,
+    pub index: u32,
+    pub receiver_index: u32,
+    pub liquidity: Liquidity,
+}
+
+#[ink::event]
+pub struct MergePositionsEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub index_a: u32,
+    pub index_b: u32,
+    pub index: u32,
+    pub liquidity: Liquidity,
+}
+
+#[ink::event]
+pub struct ChangeAdminEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub new_admin: AccountId,
+}
+
+#[ink::event]
+pub struct SetCodeEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub code_hash: Hash,
+}
+
+#[ink::event]
+pub struct MigrateEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub storage_version: u32,
+    pub migrated: u32,
+}
+
+#[ink::event]
+pub struct CreateIncentiveEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub incentive_id: u32,
+    pub reward_token: AccountId,
+    pub reward: TokenAmount,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+}
+
+#[ink::event]
+pub struct EndIncentiveEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub incentive_id: u32,
+    pub refund: TokenAmount,
+}
+
+#[ink::event]
+pub struct StakePositionEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub incentive_id: u32,
+    pub index: u32,
+    pub liquidity: Liquidity,
+}
+
+#[ink::event]
+pub struct ClaimIncentiveRewardEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub incentive_id: u32,
+    pub index: u32,
+    pub reward: TokenAmount,
+}
+
+#[ink::event]
+pub struct UnstakePositionEvent {
+    #[ink(topic)]
+    pub timestamp: u64,
+    #[ink(topic)]
+    pub address: AccountId,
+    #[ink(topic)]
+    pub pool: PoolKey,
+    pub incentive_id: u32,
+    pub index: u32,
+    pub reward: TokenAmount,
 }
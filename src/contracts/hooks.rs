@@ -0,0 +1,104 @@
+use crate::{
+    contracts::PoolKey,
+    math::{liquidity::Liquidity, sqrt_price::SqrtPrice, token_amount::TokenAmount},
+};
+use ink::primitives::AccountId;
+
+/// Callbacks invoked by the DEX on the hook contract registered for a pool.
+/// Only the callbacks enabled in the pool hook flags are called, each with `PoolHook::GAS_LIMIT`.
+/// Liquidity moved between positions by a split or a merge is reported as removed from the
+/// source position and added to the destination position, with no token amounts.
+#[ink::trait_definition]
+pub trait InvariantHooks {
+    /// Called after a swap on the pool is calculated and before it is applied to the pool
+    /// and the tokens are transferred.
+    ///
+    /// # Parameters
+    /// - `caller`: The account performing the swap.
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `x_to_y`: The direction of the swap.
+    /// - `amount`: Amount of tokens you want to receive or give.
+    /// - `by_amount_in`: Indicates whether the entered amount represents the tokens you wish to receive or give.
+    /// - `sqrt_price_limit`: Price limit of the swap.
+    ///
+    /// Returning `false` or failing vetoes the swap.
+    #[ink(message)]
+    fn before_swap(
+        &mut self,
+        caller: AccountId,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+    ) -> bool;
+
+    /// Called after a swap on the pool is executed. Failures are ignored.
+    ///
+    /// # Parameters
+    /// - `caller`: The account performing the swap.
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `x_to_y`: The direction of the swap.
+    /// - `amount_in`: The amount of tokens paid by the caller, including any referral fee.
+    /// - `amount_out`: The amount of tokens received by the caller, net of any referral fee.
+    /// - `fee`: The fee charged on the swap.
+    /// - `sqrt_price`: The square root of the price after the swap.
+    #[allow(clippy::too_many_arguments)]
+    #[ink(message)]
+    fn after_swap(
+        &mut self,
+        caller: AccountId,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount_in: TokenAmount,
+        amount_out: TokenAmount,
+        fee: TokenAmount,
+        sqrt_price: SqrtPrice,
+    );
+
+    /// Called before liquidity of a position in the pool is added or removed.
+    /// Failing vetoes adding liquidity, failures on removal are ignored.
+    ///
+    /// # Parameters
+    /// - `caller`: The owner of the position.
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `lower_tick`: The index of the lower tick of the position.
+    /// - `upper_tick`: The index of the upper tick of the position.
+    /// - `liquidity_delta`: The amount of liquidity added or removed.
+    /// - `add_liquidity`: Indicates whether liquidity is added or removed.
+    #[ink(message)]
+    fn before_modify_position(
+        &mut self,
+        caller: AccountId,
+        pool_key: PoolKey,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: Liquidity,
+        add_liquidity: bool,
+    );
+
+    /// Called after liquidity of a position in the pool is added or removed. Failures are ignored.
+    ///
+    /// # Parameters
+    /// - `caller`: The owner of the position.
+    /// - `pool_key`: A unique key that identifies the specified pool.
+    /// - `lower_tick`: The index of the lower tick of the position.
+    /// - `upper_tick`: The index of the upper tick of the position.
+    /// - `liquidity_delta`: The amount of liquidity added or removed.
+    /// - `add_liquidity`: Indicates whether liquidity is added or removed.
+    /// - `amount_x`: The amount of token x deposited or withdrawn.
+    /// - `amount_y`: The amount of token y deposited or withdrawn.
+    #[allow(clippy::too_many_arguments)]
+    #[ink(message)]
+    fn after_modify_position(
+        &mut self,
+        caller: AccountId,
+        pool_key: PoolKey,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: Liquidity,
+        add_liquidity: bool,
+        amount_x: TokenAmount,
+        amount_y: TokenAmount,
+    );
+}
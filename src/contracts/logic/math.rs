@@ -4,7 +4,9 @@ use traceable_result::*;
 use crate::math::liquidity::Liquidity;
 use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
 use crate::math::token_amount::TokenAmount;
-use crate::math::MAX_TICK;
+use crate::math::{
+    CURRENT_SQRT_PRICE_BELOW_LOWER, INVALID_TICKS, MAX_TICK, UPPER_SQRT_PRICE_BELOW_CURRENT,
+};
 
 #[derive(Debug)]
 pub struct LiquidityResult {
@@ -29,7 +31,7 @@ pub fn get_liquidity(
     rounding_up: bool,
 ) -> TrackableResult<LiquidityResult> {
     if lower_tick < -MAX_TICK || upper_tick > MAX_TICK {
-        return Err(err!("Invalid Ticks"));
+        return Err(err!(INVALID_TICKS));
     }
 
     let lower_sqrt_price = ok_or_mark_trace!(calculate_sqrt_price(lower_tick))?;
@@ -102,7 +104,7 @@ pub fn get_liquidity_by_x(
     rounding_up: bool,
 ) -> TrackableResult<SingleTokenLiquidity> {
     if lower_tick < -MAX_TICK || upper_tick > MAX_TICK {
-        return Err(err!("Invalid Ticks"));
+        return Err(err!(INVALID_TICKS));
     }
 
     let lower_sqrt_price = ok_or_mark_trace!(calculate_sqrt_price(lower_tick))?;
@@ -124,7 +126,7 @@ pub fn get_liquidity_by_x_sqrt_price(
     rounding_up: bool,
 ) -> TrackableResult<SingleTokenLiquidity> {
     if upper_sqrt_price < current_sqrt_price {
-        return Err(err!("Upper Sqrt Price < Current Sqrt Price"));
+        return Err(err!(UPPER_SQRT_PRICE_BELOW_CURRENT));
     }
 
     if current_sqrt_price < lower_sqrt_price {
@@ -132,7 +134,7 @@ pub fn get_liquidity_by_x_sqrt_price(
             (lower_sqrt_price.big_mul(upper_sqrt_price)).big_div(SqrtPrice::from_integer(1));
         let denominator = upper_sqrt_price
             .checked_sub(lower_sqrt_price)
-            .map_err(|_| err!("Underflow while calculating denominator"))?;
+            .map_err(|_| err!(TrackableError::SUB))?;
         let liquidity = Liquidity::new(
             (U256::from(x.get())
                 .checked_mul(U256::from(nominator.get()))
@@ -142,7 +144,7 @@ pub fn get_liquidity_by_x_sqrt_price(
                 .checked_div(U256::from(denominator.get()))
                 .ok_or_else(|| err!(TrackableError::DIV))?)
             .try_into()
-            .map_err(|_| err!("Overflow in calculating liquidity"))?,
+            .map_err(|_| err!(TrackableError::MUL))?,
         );
         return Ok(SingleTokenLiquidity {
             l: liquidity,
@@ -155,7 +157,7 @@ pub fn get_liquidity_by_x_sqrt_price(
         .big_div(SqrtPrice::from_integer(1));
     let denominator = upper_sqrt_price
         .checked_sub(current_sqrt_price)
-        .map_err(|_| err!("Underflow while calculating denominator"))?;
+        .map_err(|_| err!(TrackableError::SUB))?;
     let liquidity = Liquidity::new(
         (U256::from(x.get())
             .checked_mul(U256::from(nominator.get()))
@@ -165,12 +167,12 @@ pub fn get_liquidity_by_x_sqrt_price(
             .checked_div(U256::from(denominator.get()))
             .ok_or_else(|| err!(TrackableError::DIV))?)
         .try_into()
-        .map_err(|_| err!("Overflow in calculating liquidity"))?,
+        .map_err(|_| err!(TrackableError::MUL))?,
     );
 
     let sqrt_price_diff = current_sqrt_price
         .checked_sub(lower_sqrt_price)
-        .map_err(|_| err!("Underflow while calculating sqrt price difference"))?;
+        .map_err(|_| err!(TrackableError::SUB))?;
     let y = calculate_y(sqrt_price_diff, liquidity, rounding_up)?;
     Ok(SingleTokenLiquidity {
         l: liquidity,
@@ -187,7 +189,7 @@ pub fn get_liquidity_by_y(
     rounding_up: bool,
 ) -> TrackableResult<SingleTokenLiquidity> {
     if lower_tick < -MAX_TICK || upper_tick > MAX_TICK {
-        return Err(err!("Invalid Ticks"));
+        return Err(err!(INVALID_TICKS));
     }
 
     let lower_sqrt_price = ok_or_mark_trace!(calculate_sqrt_price(lower_tick))?;
@@ -211,13 +213,13 @@ pub fn get_liquidity_by_y_sqrt_price(
     rounding_up: bool,
 ) -> TrackableResult<SingleTokenLiquidity> {
     if current_sqrt_price < lower_sqrt_price {
-        return Err(err!("Current Sqrt Price < Lower Sqrt Price"));
+        return Err(err!(CURRENT_SQRT_PRICE_BELOW_LOWER));
     }
 
     if upper_sqrt_price <= current_sqrt_price {
         let sqrt_price_diff = upper_sqrt_price
             .checked_sub(lower_sqrt_price)
-            .map_err(|_| err!("Underflow while calculating sqrt price difference"))?;
+            .map_err(|_| err!(TrackableError::SUB))?;
         let liquidity = Liquidity::new(
             (U256::from(y.get())
                 .checked_mul(U256::from(SqrtPrice::from_integer(1).get()))
@@ -227,7 +229,7 @@ pub fn get_liquidity_by_y_sqrt_price(
                 .checked_div(U256::from(sqrt_price_diff.get()))
                 .ok_or_else(|| err!(TrackableError::DIV))?)
             .try_into()
-            .map_err(|_| err!("Overflow while calculating liquidity"))?,
+            .map_err(|_| err!(TrackableError::MUL))?,
         );
         return Ok(SingleTokenLiquidity {
             l: liquidity,
@@ -237,7 +239,7 @@ pub fn get_liquidity_by_y_sqrt_price(
 
     let sqrt_price_diff = current_sqrt_price
         .checked_sub(lower_sqrt_price)
-        .map_err(|_| err!("Underflow while calculating sqrt price difference"))?;
+        .map_err(|_| err!(TrackableError::SUB))?;
     let liquidity = Liquidity::new(
         (U256::from(y.get())
             .checked_mul(U256::from(SqrtPrice::from_integer(1).get()))
@@ -247,13 +249,13 @@ pub fn get_liquidity_by_y_sqrt_price(
             .checked_div(U256::from(sqrt_price_diff.get()))
             .ok_or_else(|| err!(TrackableError::DIV))?)
         .try_into()
-        .map_err(|_| err!("Overflow while calculating liquidity"))?,
+        .map_err(|_| err!(TrackableError::MUL))?,
     );
     let denominator =
         (current_sqrt_price.big_mul(upper_sqrt_price)).big_div(SqrtPrice::from_integer(1));
     let nominator = upper_sqrt_price
         .checked_sub(current_sqrt_price)
-        .map_err(|_| err!("Underflow while calculating nominator"))?;
+        .map_err(|_| err!(TrackableError::SUB))?;
 
     let x = calculate_x(nominator, denominator, liquidity, rounding_up)?;
 
@@ -282,14 +284,14 @@ pub fn calculate_x(
             .checked_div(U256::from(Liquidity::from_integer(1).get())))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!("Overflow while casting to TokenAmount"))?,
+            .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?,
         )
     } else {
         TokenAmount::new(
             (U256::from(common).checked_div(U256::from(Liquidity::from_integer(1).get())))
                 .ok_or_else(|| err!(TrackableError::DIV))?
                 .try_into()
-                .map_err(|_| err!("Overflow while casting to TokenAmount"))?,
+                .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?,
         )
     })
 }
@@ -311,13 +313,13 @@ pub fn calculate_y(
                     SqrtPrice::from_integer(1)
                         .get()
                         .checked_sub(1)
-                        .ok_or_else(|| err!("Overflow while calculating TokenAmount"))?,
+                        .ok_or_else(|| err!(TrackableError::SUB))?,
                 ))
                 .ok_or_else(|| err!(TrackableError::ADD))?)
             .checked_div(U256::from(SqrtPrice::from_integer(1).get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!("Overflow in calculating TokenAmount"))?,
+            .map_err(|_| err!(TrackableError::MUL))?,
         )
     } else {
         TokenAmount::new(
@@ -327,7 +329,7 @@ pub fn calculate_y(
                 .checked_div(U256::from(SqrtPrice::from_integer(1).get()))
                 .ok_or_else(|| err!(TrackableError::DIV))?)
             .try_into()
-            .map_err(|_| err!("Overflow in calculating TokenAmount"))?,
+            .map_err(|_| err!(TrackableError::MUL))?,
         )
     })
 }
@@ -2,8 +2,10 @@ pub mod collections;
 pub mod entrypoints;
 pub mod error;
 pub mod events;
+pub mod hooks;
 pub mod logic;
 pub mod macros;
+pub mod multicall;
 pub mod storage;
 pub mod swap_structs;
 
@@ -11,5 +13,7 @@ pub use collections::*;
 pub use entrypoints::*;
 pub use error::*;
 pub use events::*;
+pub use hooks::*;
+pub use multicall::*;
 pub use storage::*;
 pub use swap_structs::*;
@@ -0,0 +1,237 @@
+use crate::{
+    contracts::{
+        CalculateSwapResult, FeeAsset, FeeTier, PoolCreationFee, PoolHook, PoolKey, PoolStatus,
+        Position, Referral, SplitRoute, SwapHop, TokenStatus,
+    },
+    math::types::{
+        liquidity::Liquidity, percentage::Percentage, sqrt_price::SqrtPrice,
+        token_amount::TokenAmount,
+    },
+};
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+
+/// A single mutating message of `InvariantEntrypoints` executed as a part of `multicall`.
+#[derive(Clone, Debug)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum InvariantCall {
+    WithdrawProtocolFee {
+        pool_key: PoolKey,
+    },
+    ChangeProtocolFee {
+        protocol_fee: Percentage,
+    },
+    ChangeMaxSwapSteps {
+        max_swap_steps: u32,
+    },
+    ChangePoolCreationFee {
+        pool_creation_fee: Option<PoolCreationFee>,
+    },
+    WithdrawPoolCreationFee {
+        asset: FeeAsset,
+    },
+    ChangeFeeReceiver {
+        pool_key: PoolKey,
+        fee_receiver: AccountId,
+    },
+    ChangeFeeReceivers {
+        pool_keys: Vec<PoolKey>,
+        fee_receiver: AccountId,
+    },
+    ChangeDefaultFeeReceiver {
+        fee_receiver: AccountId,
+    },
+    ChangePoolStatus {
+        pool_key: PoolKey,
+        status: PoolStatus,
+    },
+    ChangePoolCuration {
+        pool_key: PoolKey,
+        curated: bool,
+    },
+    ChangeTokenStatus {
+        token: AccountId,
+        status: TokenStatus,
+    },
+    CreatePosition {
+        pool_key: PoolKey,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: SqrtPrice,
+        slippage_limit_upper: SqrtPrice,
+    },
+    CreatePositionByAmounts {
+        pool_key: PoolKey,
+        lower_tick: i32,
+        upper_tick: i32,
+        max_x: TokenAmount,
+        max_y: TokenAmount,
+        min_x: TokenAmount,
+        min_y: TokenAmount,
+        slippage_limit_lower: SqrtPrice,
+        slippage_limit_upper: SqrtPrice,
+    },
+    ChangeLiquidity {
+        index: u32,
+        delta_liquidity: Liquidity,
+        add_liquidity: bool,
+        slippage_limit_lower: SqrtPrice,
+        slippage_limit_upper: SqrtPrice,
+    },
+    Swap {
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+    },
+    SwapPartial {
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+    },
+    SwapRoute {
+        amount_in: TokenAmount,
+        expected_amount_out: TokenAmount,
+        slippage: Percentage,
+        swaps: Vec<SwapHop>,
+    },
+    SwapWithReferral {
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+        referral: Referral,
+    },
+    SwapRouteWithReferral {
+        amount_in: TokenAmount,
+        expected_amount_out: TokenAmount,
+        slippage: Percentage,
+        swaps: Vec<SwapHop>,
+        referral: Referral,
+    },
+    ClaimReferralFee {
+        token: AccountId,
+    },
+    SwapBest {
+        token_in: AccountId,
+        token_out: AccountId,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        amount_limit: TokenAmount,
+    },
+    SwapSplit {
+        routes: Vec<SplitRoute>,
+        expected_amount_out: TokenAmount,
+        slippage: Percentage,
+    },
+    TransferPosition {
+        index: u32,
+        receiver: AccountId,
+    },
+    SplitPosition {
+        index: u32,
+        liquidity: Liquidity,
+        receiver: AccountId,
+    },
+    MergePositions {
+        index_a: u32,
+        index_b: u32,
+    },
+    LockPosition {
+        index: u32,
+        locked_until: u64,
+    },
+    ClaimFee {
+        index: u32,
+    },
+    Donate {
+        pool_key: PoolKey,
+        amount_x: TokenAmount,
+        amount_y: TokenAmount,
+    },
+    RemovePosition {
+        index: u32,
+    },
+    AddFeeTier {
+        fee_tier: FeeTier,
+    },
+    RemoveFeeTier {
+        fee_tier: FeeTier,
+    },
+    CreatePool {
+        token_0: AccountId,
+        token_1: AccountId,
+        fee_tier: FeeTier,
+        init_sqrt_price: SqrtPrice,
+        init_tick: i32,
+    },
+    CreatePoolWithHook {
+        token_0: AccountId,
+        token_1: AccountId,
+        fee_tier: FeeTier,
+        init_sqrt_price: SqrtPrice,
+        init_tick: i32,
+        hook: PoolHook,
+    },
+    CreatePoolWithPosition {
+        token_0: AccountId,
+        token_1: AccountId,
+        fee_tier: FeeTier,
+        init_sqrt_price: SqrtPrice,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: Liquidity,
+    },
+    CreateIncentive {
+        pool_key: PoolKey,
+        reward_token: AccountId,
+        reward: TokenAmount,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    },
+    EndIncentive {
+        incentive_id: u32,
+    },
+    StakePosition {
+        index: u32,
+        incentive_id: u32,
+    },
+    ClaimIncentiveReward {
+        index: u32,
+    },
+    UnstakePosition {
+        index: u32,
+    },
+    UpdatePositionSecondsPerLiquidity {
+        index: u32,
+    },
+    ChangeAdmin {
+        new_admin: AccountId,
+    },
+    RemovePoolHook {
+        pool_key: PoolKey,
+    },
+    ForceUnstakePosition {
+        owner: AccountId,
+        index: u32,
+    },
+}
+
+/// The value returned by a single `InvariantCall`.
+#[derive(Clone, Debug, PartialEq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum InvariantCallResult {
+    Empty,
+    Position(Position),
+    Swap(CalculateSwapResult),
+    SwapBest(PoolKey, CalculateSwapResult),
+    AmountsOut(Vec<TokenAmount>),
+    Amounts(TokenAmount, TokenAmount),
+    Amount(TokenAmount),
+    Index(u32),
+}
@@ -0,0 +1,215 @@
+use super::{PoolKey, Position};
+use crate::math::types::{
+    liquidity::Liquidity, seconds_per_liquidity::SecondsPerLiquidity, token_amount::TokenAmount,
+};
+use decimal::*;
+use ink::primitives::AccountId;
+use traceable_result::*;
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Incentive {
+    pub pool_key: PoolKey,
+    pub reward_token: AccountId,
+    pub funder: AccountId,
+    pub total_reward: TokenAmount,
+    pub total_reward_unclaimed: TokenAmount,
+    // seconds scaled by `SecondsPerLiquidity::one()` already paid out to stakers
+    pub total_seconds_claimed: u128,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub number_of_stakes: u32,
+}
+
+impl Default for Incentive {
+    fn default() -> Self {
+        Self {
+            pool_key: PoolKey::default(),
+            reward_token: AccountId::from([0x0; 32]),
+            funder: AccountId::from([0x0; 32]),
+            total_reward: TokenAmount(0),
+            total_reward_unclaimed: TokenAmount(0),
+            total_seconds_claimed: 0,
+            start_timestamp: 0,
+            end_timestamp: 0,
+            number_of_stakes: 0,
+        }
+    }
+}
+
+#[derive(PartialEq, Default, Debug, Copy, Clone)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct Stake {
+    pub incentive_id: u32,
+    pub seconds_per_liquidity_inside_initial: SecondsPerLiquidity,
+    pub position: Position,
+}
+
+impl Incentive {
+    pub fn is_active(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.start_timestamp && current_timestamp < self.end_timestamp
+    }
+
+    // Reward is the part of the unclaimed reward proportional to the seconds the liquidity spent in range
+    // against all of the seconds of the program not claimed yet. Once the program ended the remaining seconds
+    // keep growing, so late claims are diluted instead of draining the rest of the reward.
+    pub fn calculate_reward(
+        &self,
+        liquidity: Liquidity,
+        seconds_per_liquidity_inside_initial: SecondsPerLiquidity,
+        seconds_per_liquidity_inside: SecondsPerLiquidity,
+        current_timestamp: u64,
+    ) -> TrackableResult<(TokenAmount, u128)> {
+        let seconds_inside = U256::from(
+            seconds_per_liquidity_inside
+                .unchecked_sub(seconds_per_liquidity_inside_initial)
+                .get(),
+        )
+        .checked_mul(U256::from(liquidity.get()))
+        .ok_or_else(|| err!(TrackableError::MUL))?
+        .checked_div(U256::from(Liquidity::one().get()))
+        .ok_or_else(|| err!(TrackableError::DIV))?;
+
+        let end_timestamp = self.end_timestamp.max(current_timestamp);
+        let total_seconds_unclaimed = U256::from(
+            end_timestamp
+                .checked_sub(self.start_timestamp)
+                .ok_or_else(|| err!(TrackableError::SUB))?,
+        )
+        .checked_mul(U256::from(SecondsPerLiquidity::one().get()))
+        .ok_or_else(|| err!(TrackableError::MUL))?
+        .checked_sub(U256::from(self.total_seconds_claimed))
+        .ok_or_else(|| err!(TrackableError::SUB))?;
+
+        if total_seconds_unclaimed.is_zero() {
+            return Ok((TokenAmount(0), 0));
+        }
+
+        let seconds_inside = seconds_inside.min(total_seconds_unclaimed);
+        let reward = U256::from(self.total_reward_unclaimed.get())
+            .checked_mul(seconds_inside)
+            .ok_or_else(|| err!(TrackableError::MUL))?
+            .checked_div(total_seconds_unclaimed)
+            .ok_or_else(|| err!(TrackableError::DIV))?;
+
+        Ok((
+            TokenAmount::new(
+                reward
+                    .try_into()
+                    .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?,
+            ),
+            seconds_inside
+                .try_into()
+                .map_err(|_| err!(&TrackableError::cast::<u128>()))?,
+        ))
+    }
+
+    pub fn claim(&mut self, reward: TokenAmount, seconds_inside: u128) -> TrackableResult<()> {
+        self.total_reward_unclaimed = self
+            .total_reward_unclaimed
+            .checked_sub(reward)
+            .map_err(|_| err!(TrackableError::SUB))?;
+        self.total_seconds_claimed = self
+            .total_seconds_claimed
+            .checked_add(seconds_inside)
+            .ok_or_else(|| err!(TrackableError::ADD))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_reward() {
+        let incentive = Incentive {
+            total_reward: TokenAmount(1000),
+            total_reward_unclaimed: TokenAmount(1000),
+            start_timestamp: 100,
+            end_timestamp: 200,
+            ..Default::default()
+        };
+        let liquidity = Liquidity::from_integer(10);
+        let initial = SecondsPerLiquidity::new(0);
+        // in range for 50 seconds with a tenth of the pool liquidity
+        let current = SecondsPerLiquidity::from_integer(5);
+
+        // half of the program time in range
+        {
+            let (reward, seconds_inside) = incentive
+                .calculate_reward(liquidity, initial, current, 150)
+                .unwrap();
+            assert_eq!(reward, TokenAmount(500));
+            assert_eq!(seconds_inside, 50 * SecondsPerLiquidity::one().get());
+        }
+        // claiming after the end dilutes the reward over the elapsed time
+        {
+            let (reward, _) = incentive
+                .calculate_reward(liquidity, initial, current, 250)
+                .unwrap();
+            assert_eq!(reward, TokenAmount(333));
+        }
+        // nothing in range
+        {
+            let (reward, seconds_inside) = incentive
+                .calculate_reward(liquidity, current, current, 150)
+                .unwrap();
+            assert_eq!(reward, TokenAmount(0));
+            assert_eq!(seconds_inside, 0);
+        }
+        // reward is capped at the unclaimed amount
+        {
+            let current = SecondsPerLiquidity::from_integer(20);
+            let (reward, _) = incentive
+                .calculate_reward(liquidity, initial, current, 150)
+                .unwrap();
+            assert_eq!(reward, TokenAmount(1000));
+        }
+    }
+
+    #[test]
+    fn test_claim() {
+        let mut incentive = Incentive {
+            total_reward: TokenAmount(1000),
+            total_reward_unclaimed: TokenAmount(1000),
+            start_timestamp: 100,
+            end_timestamp: 200,
+            ..Default::default()
+        };
+        let liquidity = Liquidity::from_integer(10);
+        let current = SecondsPerLiquidity::from_integer(5);
+
+        let (reward, seconds_inside) = incentive
+            .calculate_reward(liquidity, SecondsPerLiquidity::new(0), current, 150)
+            .unwrap();
+        incentive.claim(reward, seconds_inside).unwrap();
+        assert_eq!(incentive.total_reward_unclaimed, TokenAmount(500));
+        assert_eq!(incentive.total_seconds_claimed, seconds_inside);
+
+        // the same seconds claimed by another stake at the end get the rest of the reward
+        let (reward, _) = incentive
+            .calculate_reward(liquidity, SecondsPerLiquidity::new(0), current, 200)
+            .unwrap();
+        assert_eq!(reward, TokenAmount(500));
+
+        let result = incentive.claim(TokenAmount(501), 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_active() {
+        let incentive = Incentive {
+            start_timestamp: 100,
+            end_timestamp: 200,
+            ..Default::default()
+        };
+        assert!(!incentive.is_active(99));
+        assert!(incentive.is_active(100));
+        assert!(incentive.is_active(199));
+        assert!(!incentive.is_active(200));
+    }
+}
@@ -1,11 +1,25 @@
-use crate::math::types::percentage::Percentage;
+use crate::contracts::PoolCreationFee;
+use crate::math::{types::percentage::Percentage, MAX_SWAP_STEPS};
 use ink::primitives::AccountId;
+use ink::scale::{Decode, Error, Input};
 
-#[ink::storage_item]
-#[derive(Debug)]
+// Version of the storage layout written by this code, `migrate` converts records of older versions.
+// 0 - config without a version, pools without statuses, curation and hooks
+// 1 - fee tiers in a vector in the root cell
+pub const STORAGE_VERSION: u32 = 2;
+
+#[derive(Debug, PartialEq)]
+#[ink::scale_derive(Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 pub struct InvariantConfig {
     pub admin: AccountId,
     pub protocol_fee: Percentage,
+    pub max_swap_steps: u32,
+    pub pool_creation_fee: Option<PoolCreationFee>,
+    pub default_fee_receiver: AccountId,
+    pub storage_version: u32,
+    // progress of the current migration step, reset when the step completes
+    pub migration_cursor: u32,
 }
 
 impl Default for InvariantConfig {
@@ -13,6 +27,106 @@ impl Default for InvariantConfig {
         Self {
             admin: AccountId::from([0x0; 32]),
             protocol_fee: Default::default(),
+            max_swap_steps: MAX_SWAP_STEPS,
+            pool_creation_fee: None,
+            default_fee_receiver: AccountId::from([0x0; 32]),
+            storage_version: STORAGE_VERSION,
+            migration_cursor: 0,
+        }
+    }
+}
+
+impl Decode for InvariantConfig {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        // the config is the last field of the root cell and fields were appended to it over time,
+        // a config stored by older code ends early and the missing fields keep their defaults,
+        // a config without a version is treated as version 0
+        let mut config = Self {
+            admin: Decode::decode(input)?,
+            protocol_fee: Decode::decode(input)?,
+            storage_version: 0,
+            ..Default::default()
+        };
+        config.default_fee_receiver = config.admin;
+
+        if input.remaining_len()? == Some(0) {
+            return Ok(config);
+        }
+        config.max_swap_steps = Decode::decode(input)?;
+
+        if input.remaining_len()? == Some(0) {
+            return Ok(config);
+        }
+        config.pool_creation_fee = Decode::decode(input)?;
+
+        if input.remaining_len()? == Some(0) {
+            return Ok(config);
         }
+        config.default_fee_receiver = Decode::decode(input)?;
+
+        if input.remaining_len()? == Some(0) {
+            return Ok(config);
+        }
+        config.storage_version = Decode::decode(input)?;
+        config.migration_cursor = Decode::decode(input)?;
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decimal::*;
+    use ink::scale::Encode;
+
+    #[test]
+    fn test_decode_legacy_config() {
+        let config = InvariantConfig {
+            admin: AccountId::from([0x01; 32]),
+            protocol_fee: Percentage::new(10),
+            max_swap_steps: 5,
+            default_fee_receiver: AccountId::from([0x02; 32]),
+            migration_cursor: 3,
+            ..Default::default()
+        };
+        let encoded = config.encode();
+        assert_eq!(InvariantConfig::decode(&mut &encoded[..]).unwrap(), config);
+
+        let legacy = (config.admin, config.protocol_fee).encode();
+        let decoded = InvariantConfig::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(
+            decoded,
+            InvariantConfig {
+                admin: config.admin,
+                protocol_fee: config.protocol_fee,
+                max_swap_steps: MAX_SWAP_STEPS,
+                pool_creation_fee: None,
+                default_fee_receiver: config.admin,
+                storage_version: 0,
+                migration_cursor: 0,
+            }
+        );
+
+        let legacy = (config.admin, config.protocol_fee, config.max_swap_steps).encode();
+        let decoded = InvariantConfig::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(decoded.max_swap_steps, config.max_swap_steps);
+        assert_eq!(decoded.pool_creation_fee, None);
+        assert_eq!(decoded.default_fee_receiver, config.admin);
+        assert_eq!(decoded.storage_version, 0);
+
+        let legacy = (
+            config.admin,
+            config.protocol_fee,
+            config.max_swap_steps,
+            config.pool_creation_fee,
+            config.default_fee_receiver,
+        )
+            .encode();
+        let decoded = InvariantConfig::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(decoded.max_swap_steps, config.max_swap_steps);
+        assert_eq!(decoded.default_fee_receiver, config.default_fee_receiver);
+        assert_eq!(decoded.storage_version, 0);
+        assert_eq!(decoded.migration_cursor, 0);
     }
 }
@@ -1,15 +1,25 @@
 pub mod fee_tier;
+pub mod incentive;
 pub mod invariant_config;
 pub mod pool;
+pub mod pool_creation_fee;
+pub mod pool_hook;
 pub mod pool_key;
+pub mod pool_stats;
 pub mod position;
 pub mod tick;
 pub mod tickmap;
+pub mod token_status;
 
 pub use fee_tier::*;
+pub use incentive::*;
 pub use invariant_config::*;
 pub use pool::*;
+pub use pool_creation_fee::*;
+pub use pool_hook::*;
 pub use pool_key::*;
+pub use pool_stats::*;
 pub use position::*;
 pub use tick::*;
 pub use tickmap::*;
+pub use token_status::*;
@@ -1,4 +1,4 @@
-use super::{FeeTier, Tick};
+use super::{FeeTier, PoolHook, Tick};
 use crate::math::types::sqrt_price::check_tick_to_sqrt_price_relationship;
 use crate::{
     contracts::InvariantError,
@@ -18,11 +18,28 @@ use crate::{
 
 use decimal::*;
 use ink::primitives::AccountId;
+use ink::scale::{Decode, Error, Input};
 use traceable_result::*;
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Default, Copy, Clone)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum PoolStatus {
+    /// Swaps and all position operations are allowed.
+    #[default]
+    Active,
+    /// Swaps, creating positions and adding liquidity are disabled, liquidity providers can
+    /// still decrease liquidity, claim fees, split, merge, lock, transfer, stake and remove
+    /// their positions.
+    WithdrawOnly,
+    /// Only removing positions is allowed, every other position operation fails with
+    /// `PoolClosed`. Staked positions can still be unstaked so that they can be removed.
+    Closed,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+#[ink::scale_derive(Encode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 pub struct Pool {
     pub liquidity: Liquidity,
     pub sqrt_price: SqrtPrice,
@@ -35,6 +52,50 @@ pub struct Pool {
     pub last_timestamp: u64,
     pub fee_receiver: AccountId,
     pub seconds_per_liquidity_global: SecondsPerLiquidity,
+    pub status: PoolStatus,
+    pub curated: bool,
+    pub hook: Option<PoolHook>,
+}
+
+impl Decode for Pool {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut pool = Pool {
+            liquidity: Decode::decode(input)?,
+            sqrt_price: Decode::decode(input)?,
+            current_tick_index: Decode::decode(input)?,
+            fee_growth_global_x: Decode::decode(input)?,
+            fee_growth_global_y: Decode::decode(input)?,
+            fee_protocol_token_x: Decode::decode(input)?,
+            fee_protocol_token_y: Decode::decode(input)?,
+            start_timestamp: Decode::decode(input)?,
+            last_timestamp: Decode::decode(input)?,
+            fee_receiver: Decode::decode(input)?,
+            seconds_per_liquidity_global: Decode::decode(input)?,
+            ..Default::default()
+        };
+
+        // pools created before `set_code` to a version with pool statuses, curation or hooks end here,
+        // missing fields fall back to an active, uncurated pool without a hook
+        if input.remaining_len()? == Some(0) {
+            return Ok(pool);
+        }
+
+        pool.status = Decode::decode(input)?;
+
+        if input.remaining_len()? == Some(0) {
+            return Ok(pool);
+        }
+
+        pool.curated = Decode::decode(input)?;
+
+        if input.remaining_len()? == Some(0) {
+            return Ok(pool);
+        }
+
+        pool.hook = Decode::decode(input)?;
+
+        Ok(pool)
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -58,6 +119,9 @@ impl Default for Pool {
             last_timestamp: u64::default(),
             fee_receiver: AccountId::from([0x0; 32]),
             seconds_per_liquidity_global: SecondsPerLiquidity::default(),
+            status: PoolStatus::default(),
+            curated: false,
+            hook: None,
         }
     }
 }
@@ -98,7 +162,7 @@ impl Pool {
 
         let pool_fee = amount
             .checked_sub(protocol_fee)
-            .map_err(|_| err!("Underflow while calculating pool fee"))?;
+            .map_err(|_| err!(TrackableError::SUB))?;
 
         if (pool_fee.is_zero() && protocol_fee.is_zero()) || self.liquidity.is_zero() {
             return Ok(());
@@ -111,17 +175,30 @@ impl Pool {
             self.fee_protocol_token_x = self
                 .fee_protocol_token_x
                 .checked_add(protocol_fee)
-                .map_err(|_| err!("Overflow while calculating fee protocol token X"))?;
+                .map_err(|_| err!(TrackableError::ADD))?;
         } else {
             self.fee_growth_global_y = self.fee_growth_global_y.unchecked_add(fee_growth);
             self.fee_protocol_token_y = self
                 .fee_protocol_token_y
                 .checked_add(protocol_fee)
-                .map_err(|_| err!("Overflow while calculating fee protocol token Y"))?;
+                .map_err(|_| err!(TrackableError::ADD))?;
         }
         Ok(())
     }
 
+    pub fn donate(&mut self, amount_x: TokenAmount, amount_y: TokenAmount) -> TrackableResult<()> {
+        if self.liquidity.is_zero() {
+            return Err(err!("Cannot donate to pool without liquidity"));
+        }
+
+        let fee_growth_x = ok_or_mark_trace!(FeeGrowth::from_fee(self.liquidity, amount_x))?;
+        let fee_growth_y = ok_or_mark_trace!(FeeGrowth::from_fee(self.liquidity, amount_y))?;
+
+        self.fee_growth_global_x = self.fee_growth_global_x.unchecked_add(fee_growth_x);
+        self.fee_growth_global_y = self.fee_growth_global_y.unchecked_add(fee_growth_y);
+        Ok(())
+    }
+
     pub fn update_liquidity(
         &mut self,
         liquidity_delta: Liquidity,
@@ -146,13 +223,13 @@ impl Pool {
             self.liquidity = self
                 .liquidity
                 .checked_add(liquidity_delta)
-                .map_err(|_| err!("update_liquidity: liquidity + liquidity_delta overflow"))?;
+                .map_err(|_| err!(TrackableError::ADD))?;
             Ok((x, y))
         } else {
             self.liquidity = self
                 .liquidity
                 .checked_sub(liquidity_delta)
-                .map_err(|_| err!("update_liquidity: liquidity - liquidity_delta underflow"))?;
+                .map_err(|_| err!(TrackableError::SUB))?;
             Ok((x, y))
         }
     }
@@ -169,36 +246,36 @@ impl Pool {
         current_timestamp: u64,
         protocol_fee: Percentage,
         fee_tier: FeeTier,
-    ) -> (TokenAmount, TokenAmount, bool) {
+    ) -> TrackableResult<(TokenAmount, TokenAmount, bool)> {
         let mut has_crossed = false;
         let mut total_amount = TokenAmount(0);
 
         if UpdatePoolTick::NoTick == *tick || swap_limit != result.next_sqrt_price {
-            self.current_tick_index = unwrap!(get_tick_at_sqrt_price(
+            self.current_tick_index = ok_or_mark_trace!(get_tick_at_sqrt_price(
                 result.next_sqrt_price,
                 fee_tier.tick_spacing
-            ));
+            ))?;
 
-            return (total_amount, remaining_amount, has_crossed);
+            return Ok((total_amount, remaining_amount, has_crossed));
         };
 
-        let is_enough_amount_to_cross = unwrap!(is_enough_amount_to_change_price(
+        let is_enough_amount_to_cross = ok_or_mark_trace!(is_enough_amount_to_change_price(
             remaining_amount,
             result.next_sqrt_price,
             self.liquidity,
             fee_tier.fee,
             by_amount_in,
             x_to_y,
-        ));
+        ))?;
 
         let tick_index = match tick {
             UpdatePoolTick::TickInitialized(tick) => {
                 if !x_to_y || is_enough_amount_to_cross {
-                    tick.cross(self, current_timestamp).unwrap();
+                    ok_or_mark_trace!(tick.cross(self, current_timestamp))?;
                     has_crossed = true;
                 } else if !remaining_amount.is_zero() {
                     if by_amount_in {
-                        unwrap!(self.add_fee(remaining_amount, x_to_y, protocol_fee));
+                        ok_or_mark_trace!(self.add_fee(remaining_amount, x_to_y, protocol_fee))?;
                         total_amount = remaining_amount;
                     }
                     remaining_amount = TokenAmount(0);
@@ -213,12 +290,28 @@ impl Pool {
         self.current_tick_index = if x_to_y && is_enough_amount_to_cross {
             tick_index
                 .checked_sub(fee_tier.tick_spacing as i32)
-                .unwrap()
+                .ok_or_else(|| err!(TrackableError::SUB))?
         } else {
             tick_index
         };
 
-        (total_amount, remaining_amount, has_crossed)
+        Ok((total_amount, remaining_amount, has_crossed))
+    }
+
+    pub fn ensure_active(&self) -> Result<(), InvariantError> {
+        if self.status != PoolStatus::Active {
+            return Err(InvariantError::PoolNotActive);
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_not_closed(&self) -> Result<(), InvariantError> {
+        if self.status == PoolStatus::Closed {
+            return Err(InvariantError::PoolClosed);
+        }
+
+        Ok(())
     }
 
     pub fn withdraw_protocol_fee(&mut self) -> (TokenAmount, TokenAmount) {
@@ -511,6 +604,35 @@ mod tests {
             assert_eq!({ pool.fee_protocol_token_y }, TokenAmount(0));
         }
     }
+    #[test]
+    fn test_donate() {
+        let pool = Pool {
+            liquidity: Liquidity::from_integer(10),
+            ..Default::default()
+        };
+        {
+            let mut pool = pool.clone();
+            pool.donate(TokenAmount::from_integer(6), TokenAmount::from_integer(200))
+                .unwrap();
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::from_scale(6, 1));
+            assert_eq!({ pool.fee_growth_global_y }, FeeGrowth::from_integer(20));
+            assert_eq!({ pool.fee_protocol_token_x }, TokenAmount(0));
+            assert_eq!({ pool.fee_protocol_token_y }, TokenAmount(0));
+        }
+        {
+            let mut pool = pool.clone();
+            pool.donate(TokenAmount(0), TokenAmount::from_integer(1))
+                .unwrap();
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::from_integer(0));
+            assert_eq!({ pool.fee_growth_global_y }, FeeGrowth::from_scale(1, 1));
+        }
+        {
+            let mut pool = Pool::default();
+            let result = pool.donate(TokenAmount(1), TokenAmount(1));
+            assert!(result.is_err());
+        }
+    }
+
     #[test]
     fn test_update_liquidity() {
         // Add liquidity
@@ -808,4 +930,72 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_status() {
+        let mut pool = Pool::default();
+        assert_eq!(pool.status, PoolStatus::Active);
+        assert_eq!(pool.ensure_active(), Ok(()));
+        assert_eq!(pool.ensure_not_closed(), Ok(()));
+
+        pool.status = PoolStatus::WithdrawOnly;
+        assert_eq!(pool.ensure_active(), Err(InvariantError::PoolNotActive));
+        assert_eq!(pool.ensure_not_closed(), Ok(()));
+
+        pool.status = PoolStatus::Closed;
+        assert_eq!(pool.ensure_active(), Err(InvariantError::PoolNotActive));
+        assert_eq!(pool.ensure_not_closed(), Err(InvariantError::PoolClosed));
+    }
+
+    #[test]
+    fn test_decode_legacy_pool() {
+        let pool = Pool {
+            liquidity: Liquidity::from_integer(1),
+            current_tick_index: 10,
+            fee_protocol_token_x: TokenAmount(5),
+            status: PoolStatus::Closed,
+            curated: true,
+            ..Default::default()
+        };
+        let encoded = ink::scale::Encode::encode(&pool);
+
+        let decoded = Pool::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, pool);
+
+        let pool_with_hook = Pool {
+            hook: Some(PoolHook::new(AccountId::from([0x01; 32]), PoolHook::ALL).unwrap()),
+            ..pool.clone()
+        };
+        let encoded_with_hook = ink::scale::Encode::encode(&pool_with_hook);
+        let decoded = Pool::decode(&mut encoded_with_hook.as_slice()).unwrap();
+        assert_eq!(decoded, pool_with_hook);
+
+        // layout without the hook
+        let legacy = &encoded[..encoded.len() - 1];
+        let decoded = Pool::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(decoded, pool);
+
+        // layout without the curated flag
+        let legacy = &encoded[..encoded.len() - 2];
+        let decoded = Pool::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(
+            decoded,
+            Pool {
+                curated: false,
+                ..pool.clone()
+            }
+        );
+
+        // layout without the status field
+        let legacy = &encoded[..encoded.len() - 3];
+        let decoded = Pool::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(
+            decoded,
+            Pool {
+                status: PoolStatus::Active,
+                curated: false,
+                ..pool
+            }
+        );
+    }
 }
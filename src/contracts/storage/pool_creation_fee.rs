@@ -0,0 +1,20 @@
+use crate::math::types::token_amount::TokenAmount;
+use ink::primitives::AccountId;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum FeeAsset {
+    /// Native AZERO transferred along with the call.
+    Native,
+    /// PSP22 token transferred from the caller.
+    Token(AccountId),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PoolCreationFee {
+    pub asset: FeeAsset,
+    pub amount: TokenAmount,
+}
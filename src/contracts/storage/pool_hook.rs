@@ -0,0 +1,70 @@
+use crate::contracts::InvariantError;
+use ink::primitives::AccountId;
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PoolHook {
+    /// Contract implementing `InvariantHooks`.
+    pub address: AccountId,
+    /// Bit set of the callbacks invoked on the hook contract.
+    pub flags: u8,
+}
+
+impl PoolHook {
+    pub const BEFORE_SWAP: u8 = 1;
+    pub const AFTER_SWAP: u8 = 1 << 1;
+    pub const BEFORE_MODIFY_POSITION: u8 = 1 << 2;
+    pub const AFTER_MODIFY_POSITION: u8 = 1 << 3;
+    pub const ALL: u8 = Self::BEFORE_SWAP
+        | Self::AFTER_SWAP
+        | Self::BEFORE_MODIFY_POSITION
+        | Self::AFTER_MODIFY_POSITION;
+    /// Ref time available to a single callback, a hook running out of it fails the callback
+    /// instead of consuming the gas of the whole call.
+    pub const GAS_LIMIT: u64 = 50_000_000_000;
+
+    pub fn new(address: AccountId, flags: u8) -> Result<Self, InvariantError> {
+        if flags == 0 || flags & !Self::ALL != 0 {
+            return Err(InvariantError::InvalidHookFlags);
+        }
+
+        Ok(Self { address, flags })
+    }
+
+    pub fn is_enabled(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let address = AccountId::from([0x01; 32]);
+
+        let hook = PoolHook::new(address, PoolHook::ALL).unwrap();
+        assert_eq!(hook.flags, 15);
+
+        let result = PoolHook::new(address, 0);
+        assert_eq!(result, Err(InvariantError::InvalidHookFlags));
+        let result = PoolHook::new(address, 1 << 4);
+        assert_eq!(result, Err(InvariantError::InvalidHookFlags));
+    }
+
+    #[test]
+    fn test_is_enabled() {
+        let hook = PoolHook::new(
+            AccountId::from([0x01; 32]),
+            PoolHook::BEFORE_SWAP | PoolHook::AFTER_MODIFY_POSITION,
+        )
+        .unwrap();
+
+        assert!(hook.is_enabled(PoolHook::BEFORE_SWAP));
+        assert!(!hook.is_enabled(PoolHook::AFTER_SWAP));
+        assert!(!hook.is_enabled(PoolHook::BEFORE_MODIFY_POSITION));
+        assert!(hook.is_enabled(PoolHook::AFTER_MODIFY_POSITION));
+    }
+}
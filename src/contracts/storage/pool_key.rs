@@ -9,6 +9,7 @@ use super::tickmap::MAX_RESULT_SIZE;
 
 pub const POOL_KEY_SIZE: usize = 32 + 32 + 64 + 16;
 pub const MAX_POOL_KEYS_RETURNED: u16 = (MAX_RESULT_SIZE / POOL_KEY_SIZE) as u16;
+pub const MAX_POOL_KEYS_WITH_STATUS_RETURNED: u16 = (MAX_RESULT_SIZE / (POOL_KEY_SIZE + 8)) as u16;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -0,0 +1,105 @@
+use crate::math::types::token_amount::TokenAmount;
+use decimal::*;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct PoolStats {
+    /// Cumulative amount of token x swapped in and out of the pool, fees included.
+    pub volume_x: TokenAmount,
+    /// Cumulative amount of token y swapped in and out of the pool, fees included.
+    pub volume_y: TokenAmount,
+    /// Cumulative swap fees in token x accrued to liquidity providers.
+    pub lp_fee_x: TokenAmount,
+    /// Cumulative swap fees in token y accrued to liquidity providers.
+    pub lp_fee_y: TokenAmount,
+    /// Cumulative swap fees in token x accrued to the protocol.
+    pub protocol_fee_x: TokenAmount,
+    /// Cumulative swap fees in token y accrued to the protocol.
+    pub protocol_fee_y: TokenAmount,
+    pub swap_count: u64,
+}
+
+impl PoolStats {
+    // Counters wrap on overflow so statistics never make a swap fail,
+    // windowed values are the wrapping difference of two snapshots
+    pub fn record_swap(
+        &mut self,
+        x_to_y: bool,
+        amount_in: TokenAmount,
+        amount_out: TokenAmount,
+        fee: TokenAmount,
+        protocol_fee: TokenAmount,
+    ) {
+        let lp_fee = fee.checked_sub(protocol_fee).unwrap_or(TokenAmount(0));
+
+        let (volume_in, volume_out, lp_fee_in, protocol_fee_in) = if x_to_y {
+            (
+                &mut self.volume_x,
+                &mut self.volume_y,
+                &mut self.lp_fee_x,
+                &mut self.protocol_fee_x,
+            )
+        } else {
+            (
+                &mut self.volume_y,
+                &mut self.volume_x,
+                &mut self.lp_fee_y,
+                &mut self.protocol_fee_y,
+            )
+        };
+
+        *volume_in = TokenAmount(volume_in.get().wrapping_add(amount_in.get()));
+        *volume_out = TokenAmount(volume_out.get().wrapping_add(amount_out.get()));
+        *lp_fee_in = TokenAmount(lp_fee_in.get().wrapping_add(lp_fee.get()));
+        *protocol_fee_in = TokenAmount(protocol_fee_in.get().wrapping_add(protocol_fee.get()));
+        self.swap_count = self.swap_count.wrapping_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_swap() {
+        let mut stats = PoolStats::default();
+
+        stats.record_swap(
+            true,
+            TokenAmount(100),
+            TokenAmount(90),
+            TokenAmount(10),
+            TokenAmount(1),
+        );
+        stats.record_swap(
+            false,
+            TokenAmount(50),
+            TokenAmount(40),
+            TokenAmount(5),
+            TokenAmount(0),
+        );
+        assert_eq!(
+            stats,
+            PoolStats {
+                volume_x: TokenAmount(140),
+                volume_y: TokenAmount(140),
+                lp_fee_x: TokenAmount(9),
+                lp_fee_y: TokenAmount(5),
+                protocol_fee_x: TokenAmount(1),
+                protocol_fee_y: TokenAmount(0),
+                swap_count: 2,
+            }
+        );
+
+        stats.record_swap(
+            true,
+            TokenAmount(u128::MAX),
+            TokenAmount(0),
+            TokenAmount(0),
+            TokenAmount(0),
+        );
+        assert_eq!(stats.volume_x, TokenAmount(139));
+        assert_eq!(stats.swap_count, 3);
+    }
+}
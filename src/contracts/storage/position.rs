@@ -13,13 +13,15 @@ use crate::{
     },
 };
 use decimal::*;
+use ink::scale::{Decode, Error, Input};
 use traceable_result::*;
 
-pub const POSITION_SIZE: usize = POOL_KEY_SIZE + 128 + 32 + 32 + 128 + 128 + 64 + 128 + 128;
+pub const POSITION_SIZE: usize =
+    POOL_KEY_SIZE + 128 + 32 + 32 + 128 + 128 + 64 + 128 + 128 + 6 * 128 + 64;
 pub const MAX_POSITIONS_RETURNED: u32 = (MAX_RESULT_SIZE / POSITION_SIZE) as u32;
 
 #[derive(PartialEq, Default, Debug, Copy, Clone)]
-#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[ink::scale_derive(Encode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
 pub struct Position {
     pub pool_key: PoolKey,
@@ -33,6 +35,55 @@ pub struct Position {
     pub tokens_owed_y: TokenAmount,
     pub created_at: u64,
     pub seconds_per_liquidity_inside: SecondsPerLiquidity,
+    pub deposited_x: TokenAmount,
+    pub deposited_y: TokenAmount,
+    pub withdrawn_x: TokenAmount,
+    pub withdrawn_y: TokenAmount,
+    pub claimed_fee_x: TokenAmount,
+    pub claimed_fee_y: TokenAmount,
+    // timestamp in seconds until which the liquidity cannot be withdrawn or transferred, 0 when never locked
+    pub locked_until: u64,
+}
+
+impl Decode for Position {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut position = Position {
+            pool_key: Decode::decode(input)?,
+            liquidity: Decode::decode(input)?,
+            lower_tick_index: Decode::decode(input)?,
+            upper_tick_index: Decode::decode(input)?,
+            fee_growth_inside_x: Decode::decode(input)?,
+            fee_growth_inside_y: Decode::decode(input)?,
+            last_block_number: Decode::decode(input)?,
+            tokens_owed_x: Decode::decode(input)?,
+            tokens_owed_y: Decode::decode(input)?,
+            created_at: Decode::decode(input)?,
+            seconds_per_liquidity_inside: Decode::decode(input)?,
+            ..Default::default()
+        };
+
+        // positions stored before `set_code` to a version with lifetime accounting end here,
+        // their totals start from zero and are written back on the next update
+        if input.remaining_len()? == Some(0) {
+            return Ok(position);
+        }
+
+        position.deposited_x = Decode::decode(input)?;
+        position.deposited_y = Decode::decode(input)?;
+        position.withdrawn_x = Decode::decode(input)?;
+        position.withdrawn_y = Decode::decode(input)?;
+        position.claimed_fee_x = Decode::decode(input)?;
+        position.claimed_fee_y = Decode::decode(input)?;
+
+        // positions stored before time locks are unlocked
+        if input.remaining_len()? == Some(0) {
+            return Ok(position);
+        }
+
+        position.locked_until = Decode::decode(input)?;
+
+        Ok(position)
+    }
 }
 
 impl Position {
@@ -81,12 +132,56 @@ impl Position {
         )?;
 
         // calculate tokens amounts and update pool liquidity
-        ok_or_mark_trace!(pool.update_liquidity(
+        let (x, y) = ok_or_mark_trace!(pool.update_liquidity(
             liquidity_delta,
             add,
             upper_tick.index,
             lower_tick.index
-        ))
+        ))?;
+
+        ok_or_mark_trace!(self.record_liquidity_change(x, y, add))?;
+
+        Ok((x, y))
+    }
+
+    fn record_liquidity_change(
+        &mut self,
+        x: TokenAmount,
+        y: TokenAmount,
+        add: bool,
+    ) -> TrackableResult<()> {
+        if add {
+            self.deposited_x = self
+                .deposited_x
+                .checked_add(x)
+                .map_err(|_| err!(TrackableError::ADD))?;
+            self.deposited_y = self
+                .deposited_y
+                .checked_add(y)
+                .map_err(|_| err!(TrackableError::ADD))?;
+        } else {
+            self.withdrawn_x = self
+                .withdrawn_x
+                .checked_add(x)
+                .map_err(|_| err!(TrackableError::ADD))?;
+            self.withdrawn_y = self
+                .withdrawn_y
+                .checked_add(y)
+                .map_err(|_| err!(TrackableError::ADD))?;
+        }
+        Ok(())
+    }
+
+    fn record_claimed_fee(&mut self) -> TrackableResult<()> {
+        self.claimed_fee_x = self
+            .claimed_fee_x
+            .checked_add(self.tokens_owed_x)
+            .map_err(|_| err!(TrackableError::ADD))?;
+        self.claimed_fee_y = self
+            .claimed_fee_y
+            .checked_add(self.tokens_owed_y)
+            .map_err(|_| err!(TrackableError::ADD))?;
+        Ok(())
     }
 
     pub fn update(
@@ -115,11 +210,11 @@ impl Position {
         self.tokens_owed_x = self
             .tokens_owed_x
             .checked_add(tokens_owed_x)
-            .map_err(|_| err!("Overflow while calculating tokens owed X"))?;
+            .map_err(|_| err!(TrackableError::ADD))?;
         self.tokens_owed_y = self
             .tokens_owed_y
             .checked_add(tokens_owed_y)
-            .map_err(|_| err!("Overflow while calculating tokens owed Y"))?;
+            .map_err(|_| err!(TrackableError::ADD))?;
         Ok(())
     }
 
@@ -137,11 +232,11 @@ impl Position {
             true => self
                 .liquidity
                 .checked_add(liquidity_delta)
-                .map_err(|_| err!("position add liquidity overflow")),
+                .map_err(|_| err!(TrackableError::ADD)),
             false => self
                 .liquidity
                 .checked_sub(liquidity_delta)
-                .map_err(|_| err!("position sub liquidity underflow")),
+                .map_err(|_| err!(TrackableError::SUB)),
         }
     }
 
@@ -151,16 +246,17 @@ impl Position {
         upper_tick: &mut Tick,
         lower_tick: &mut Tick,
         current_timestamp: u64,
-    ) -> (TokenAmount, TokenAmount) {
-        unwrap!(self.modify(
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        self.modify(
             pool,
             upper_tick,
             lower_tick,
             Liquidity::new(0),
             true,
             current_timestamp,
-            self.pool_key.fee_tier.tick_spacing
-        ));
+            self.pool_key.fee_tier.tick_spacing,
+        )?;
+        self.record_claimed_fee()?;
 
         let tokens_owed_x = self.tokens_owed_x;
         let tokens_owed_y = self.tokens_owed_y;
@@ -168,7 +264,7 @@ impl Position {
         self.tokens_owed_x = TokenAmount(0);
         self.tokens_owed_y = TokenAmount(0);
 
-        (tokens_owed_x, tokens_owed_y)
+        Ok((tokens_owed_x, tokens_owed_y))
     }
     #[allow(clippy::too_many_arguments)]
     pub fn create(
@@ -200,18 +296,25 @@ impl Position {
             tokens_owed_y: TokenAmount::new(0),
             created_at: current_timestamp_in_milliseconds,
             seconds_per_liquidity_inside: SecondsPerLiquidity::new(0),
+            deposited_x: TokenAmount::new(0),
+            deposited_y: TokenAmount::new(0),
+            withdrawn_x: TokenAmount::new(0),
+            withdrawn_y: TokenAmount::new(0),
+            claimed_fee_x: TokenAmount::new(0),
+            claimed_fee_y: TokenAmount::new(0),
+            locked_until: 0,
         };
 
         let current_timestamp_in_seconds = current_timestamp_in_milliseconds / 1000;
-        let (required_x, required_y) = unwrap!(position.modify(
+        let (required_x, required_y) = position.modify(
             pool,
             upper_tick,
             lower_tick,
             liquidity_delta,
             true,
             current_timestamp_in_seconds,
-            tick_spacing
-        ));
+            tick_spacing,
+        )?;
 
         Ok((position, required_x, required_y))
     }
@@ -223,30 +326,177 @@ impl Position {
         lower_tick: &mut Tick,
         upper_tick: &mut Tick,
         tick_spacing: u16,
-    ) -> (TokenAmount, TokenAmount, bool, bool) {
+    ) -> Result<(TokenAmount, TokenAmount, bool, bool), InvariantError> {
         let liquidity_delta = self.liquidity;
-        let (mut amount_x, mut amount_y) = unwrap!(self.modify(
+        let (mut amount_x, mut amount_y) = self.modify(
             pool,
             upper_tick,
             lower_tick,
             liquidity_delta,
             false,
             current_timestamp,
-            tick_spacing
-        ));
+            tick_spacing,
+        )?;
 
-        amount_x = amount_x.checked_add(self.tokens_owed_x).unwrap();
-        amount_y = amount_y.checked_add(self.tokens_owed_y).unwrap();
+        amount_x = amount_x
+            .checked_add(self.tokens_owed_x)
+            .map_err(|_| InvariantError::AddOverflow(amount_x.get(), self.tokens_owed_x.get()))?;
+        amount_y = amount_y
+            .checked_add(self.tokens_owed_y)
+            .map_err(|_| InvariantError::AddOverflow(amount_y.get(), self.tokens_owed_y.get()))?;
+        self.record_claimed_fee()?;
+        self.tokens_owed_x = TokenAmount(0);
+        self.tokens_owed_y = TokenAmount(0);
 
         let deinitialize_lower_tick = lower_tick.liquidity_gross.is_zero();
         let deinitialize_upper_tick = upper_tick.liquidity_gross.is_zero();
 
-        (
+        Ok((
             amount_x,
             amount_y,
             deinitialize_lower_tick,
             deinitialize_upper_tick,
-        )
+        ))
+    }
+
+    // accrues fees and seconds per liquidity up to now without changing the liquidity,
+    // so the position can be split or merged against up to date checkpoints
+    pub fn checkpoint(
+        &mut self,
+        pool: &mut Pool,
+        lower_tick: &Tick,
+        upper_tick: &Tick,
+        current_timestamp: u64,
+        current_block_number: u64,
+    ) -> TrackableResult<()> {
+        self.seconds_per_liquidity_inside = ok_or_mark_trace!(pool
+            .update_seconds_per_liquidity_inside(
+                lower_tick.index,
+                lower_tick.seconds_per_liquidity_outside,
+                upper_tick.index,
+                upper_tick.seconds_per_liquidity_outside,
+                current_timestamp,
+            ))?;
+        self.last_block_number = current_block_number;
+
+        let (fee_growth_inside_x, fee_growth_inside_y) = calculate_fee_growth_inside(
+            lower_tick.index,
+            lower_tick.fee_growth_outside_x,
+            lower_tick.fee_growth_outside_y,
+            upper_tick.index,
+            upper_tick.fee_growth_outside_x,
+            upper_tick.fee_growth_outside_y,
+            pool.current_tick_index,
+            pool.fee_growth_global_x,
+            pool.fee_growth_global_y,
+        );
+
+        ok_or_mark_trace!(self.update(
+            true,
+            Liquidity::new(0),
+            fee_growth_inside_x,
+            fee_growth_inside_y,
+        ))
+    }
+
+    // carves a position with the same range out of a checkpointed one, the new position takes
+    // a share of the owed tokens proportional to its liquidity and starts its lifetime totals from zero
+    pub fn split(
+        &mut self,
+        liquidity: Liquidity,
+        current_timestamp_in_milliseconds: u64,
+    ) -> TrackableResult<Position> {
+        if liquidity.is_zero() || liquidity >= self.liquidity {
+            return Err(err!("InvalidSplitLiquidity"));
+        }
+
+        let share = |amount: TokenAmount| -> TrackableResult<TokenAmount> {
+            let amount = U256::from(amount.get())
+                .checked_mul(U256::from(liquidity.get()))
+                .ok_or_else(|| err!(TrackableError::MUL))?
+                .checked_div(U256::from(self.liquidity.get()))
+                .ok_or_else(|| err!(TrackableError::DIV))?;
+            Ok(TokenAmount::new(amount.try_into().map_err(|_| {
+                err!(&TrackableError::cast::<TokenAmount>())
+            })?))
+        };
+        let tokens_owed_x = share(self.tokens_owed_x)?;
+        let tokens_owed_y = share(self.tokens_owed_y)?;
+
+        self.liquidity = self
+            .liquidity
+            .checked_sub(liquidity)
+            .map_err(|_| err!(TrackableError::SUB))?;
+        self.tokens_owed_x = self
+            .tokens_owed_x
+            .checked_sub(tokens_owed_x)
+            .map_err(|_| err!(TrackableError::SUB))?;
+        self.tokens_owed_y = self
+            .tokens_owed_y
+            .checked_sub(tokens_owed_y)
+            .map_err(|_| err!(TrackableError::SUB))?;
+
+        Ok(Position {
+            liquidity,
+            tokens_owed_x,
+            tokens_owed_y,
+            created_at: current_timestamp_in_milliseconds,
+            deposited_x: TokenAmount::new(0),
+            deposited_y: TokenAmount::new(0),
+            withdrawn_x: TokenAmount::new(0),
+            withdrawn_y: TokenAmount::new(0),
+            claimed_fee_x: TokenAmount::new(0),
+            claimed_fee_y: TokenAmount::new(0),
+            ..*self
+        })
+    }
+
+    // folds another checkpointed position with the same range into this one
+    pub fn merge(&mut self, other: &Position) -> TrackableResult<()> {
+        if self.pool_key != other.pool_key
+            || self.lower_tick_index != other.lower_tick_index
+            || self.upper_tick_index != other.upper_tick_index
+        {
+            return Err(err!("PositionsNotMergeable"));
+        }
+
+        let add = |a: TokenAmount, b: TokenAmount| -> TrackableResult<TokenAmount> {
+            a.checked_add(b).map_err(|_| err!(TrackableError::ADD))
+        };
+
+        self.liquidity = self
+            .liquidity
+            .checked_add(other.liquidity)
+            .map_err(|_| err!(TrackableError::ADD))?;
+        self.tokens_owed_x = add(self.tokens_owed_x, other.tokens_owed_x)?;
+        self.tokens_owed_y = add(self.tokens_owed_y, other.tokens_owed_y)?;
+        self.deposited_x = add(self.deposited_x, other.deposited_x)?;
+        self.deposited_y = add(self.deposited_y, other.deposited_y)?;
+        self.withdrawn_x = add(self.withdrawn_x, other.withdrawn_x)?;
+        self.withdrawn_y = add(self.withdrawn_y, other.withdrawn_y)?;
+        self.claimed_fee_x = add(self.claimed_fee_x, other.claimed_fee_x)?;
+        self.claimed_fee_y = add(self.claimed_fee_y, other.claimed_fee_y)?;
+        self.created_at = self.created_at.min(other.created_at);
+        self.locked_until = self.locked_until.max(other.locked_until);
+        self.last_block_number = self.last_block_number.max(other.last_block_number);
+        Ok(())
+    }
+
+    pub fn is_locked(&self, current_timestamp: u64) -> bool {
+        current_timestamp < self.locked_until
+    }
+
+    pub fn lock(
+        &mut self,
+        locked_until: u64,
+        current_timestamp: u64,
+    ) -> Result<(), InvariantError> {
+        if locked_until <= current_timestamp || locked_until <= self.locked_until {
+            return Err(InvariantError::InvalidLockTime);
+        }
+
+        self.locked_until = locked_until;
+        Ok(())
     }
 
     pub fn update_seconds_per_liquidity(
@@ -608,4 +858,268 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_lifetime_accounting() {
+        let current_timestamp = 100;
+        let mut pool = Pool {
+            current_tick_index: 0,
+            sqrt_price: SqrtPrice::from_tick(0).unwrap(),
+            ..Default::default()
+        };
+        let mut upper_tick = Tick {
+            index: 10,
+            ..Default::default()
+        };
+        let mut lower_tick = Tick {
+            index: -10,
+            ..Default::default()
+        };
+        let sqrt_price = pool.sqrt_price;
+        let (mut position, x, y) = Position::create(
+            &mut pool,
+            PoolKey::default(),
+            &mut lower_tick,
+            &mut upper_tick,
+            current_timestamp * 1000,
+            Liquidity::from_integer(1000),
+            sqrt_price,
+            sqrt_price,
+            0,
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(position.deposited_x, x);
+        assert_eq!(position.deposited_y, y);
+        assert_eq!(position.withdrawn_x, TokenAmount(0));
+        assert_eq!(position.withdrawn_y, TokenAmount(0));
+
+        let (withdrawn_x, withdrawn_y) = position
+            .modify(
+                &mut pool,
+                &mut upper_tick,
+                &mut lower_tick,
+                Liquidity::from_integer(500),
+                false,
+                current_timestamp,
+                1,
+            )
+            .unwrap();
+
+        assert_eq!(position.deposited_x, x);
+        assert_eq!(position.deposited_y, y);
+        assert_eq!(position.withdrawn_x, withdrawn_x);
+        assert_eq!(position.withdrawn_y, withdrawn_y);
+
+        position.tokens_owed_x = TokenAmount(10);
+        position.tokens_owed_y = TokenAmount(20);
+        let (claimed_x, claimed_y) = position
+            .claim_fee(
+                &mut pool,
+                &mut upper_tick,
+                &mut lower_tick,
+                current_timestamp,
+            )
+            .unwrap();
+
+        assert_eq!(claimed_x, TokenAmount(10));
+        assert_eq!(claimed_y, TokenAmount(20));
+        assert_eq!(position.claimed_fee_x, TokenAmount(10));
+        assert_eq!(position.claimed_fee_y, TokenAmount(20));
+
+        position.tokens_owed_x = TokenAmount(1);
+        let (amount_x, amount_y, _, _) = position
+            .remove(
+                &mut pool,
+                current_timestamp,
+                &mut lower_tick,
+                &mut upper_tick,
+                1,
+            )
+            .unwrap();
+
+        assert_eq!(position.claimed_fee_x, TokenAmount(11));
+        assert_eq!(position.claimed_fee_y, TokenAmount(20));
+        assert_eq!(
+            position.withdrawn_x,
+            withdrawn_x + amount_x - TokenAmount(1)
+        );
+        assert_eq!(position.withdrawn_y, withdrawn_y + amount_y);
+    }
+
+    #[test]
+    fn test_split_and_merge() {
+        let current_timestamp = 100;
+        let mut pool = Pool {
+            current_tick_index: 0,
+            sqrt_price: SqrtPrice::from_tick(0).unwrap(),
+            ..Default::default()
+        };
+        let mut upper_tick = Tick {
+            index: 10,
+            ..Default::default()
+        };
+        let mut lower_tick = Tick {
+            index: -10,
+            ..Default::default()
+        };
+        let sqrt_price = pool.sqrt_price;
+        let (mut position, _, _) = Position::create(
+            &mut pool,
+            PoolKey::default(),
+            &mut lower_tick,
+            &mut upper_tick,
+            current_timestamp * 1000,
+            Liquidity::from_integer(1000),
+            sqrt_price,
+            sqrt_price,
+            0,
+            1,
+        )
+        .unwrap();
+
+        // 3 tokens x and 10 tokens y of fees per unit of liquidity accrued in range
+        pool.fee_growth_global_x = FeeGrowth::from_integer(3);
+        pool.fee_growth_global_y = FeeGrowth::from_integer(10);
+        position
+            .checkpoint(
+                &mut pool,
+                &lower_tick,
+                &upper_tick,
+                current_timestamp + 10,
+                1,
+            )
+            .unwrap();
+        assert_eq!(position.tokens_owed_x, TokenAmount(3000));
+        assert_eq!(position.tokens_owed_y, TokenAmount(10000));
+        assert_eq!(position.fee_growth_inside_x, FeeGrowth::from_integer(3));
+        assert_eq!(position.last_block_number, 1);
+
+        let original = position;
+        let mut split = position
+            .split(
+                Liquidity::from_integer(250),
+                (current_timestamp + 10) * 1000,
+            )
+            .unwrap();
+        assert_eq!(position.liquidity, Liquidity::from_integer(750));
+        assert_eq!(split.liquidity, Liquidity::from_integer(250));
+        assert_eq!(position.tokens_owed_x, TokenAmount(2250));
+        assert_eq!(split.tokens_owed_x, TokenAmount(750));
+        assert_eq!(position.tokens_owed_y, TokenAmount(7500));
+        assert_eq!(split.tokens_owed_y, TokenAmount(2500));
+        assert_eq!(split.fee_growth_inside_x, position.fee_growth_inside_x);
+        assert_eq!(split.fee_growth_inside_y, position.fee_growth_inside_y);
+        assert_eq!(
+            split.seconds_per_liquidity_inside,
+            position.seconds_per_liquidity_inside
+        );
+        assert_eq!(split.lower_tick_index, position.lower_tick_index);
+        assert_eq!(split.upper_tick_index, position.upper_tick_index);
+        assert_eq!(split.created_at, (current_timestamp + 10) * 1000);
+        assert_eq!(split.deposited_x, TokenAmount(0));
+        assert_eq!(position.deposited_x, original.deposited_x);
+
+        // owed tokens are rounded down for the carved position
+        let odd = split
+            .split(Liquidity::from_integer(1), current_timestamp * 1000)
+            .unwrap();
+        assert_eq!(odd.tokens_owed_x, TokenAmount(3));
+        assert_eq!(split.tokens_owed_x, TokenAmount(747));
+        split.merge(&odd).unwrap();
+        assert_eq!(split.liquidity, Liquidity::from_integer(250));
+        assert_eq!(split.tokens_owed_x, TokenAmount(750));
+
+        position.merge(&split).unwrap();
+        assert_eq!(position, original);
+
+        let result = position.split(Liquidity::new(0), 0);
+        assert!(result.is_err());
+        let result = position.split(position.liquidity, 0);
+        assert!(result.is_err());
+
+        let other_range = Position {
+            upper_tick_index: 20,
+            ..position
+        };
+        let result = position.merge(&other_range);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lock() {
+        let mut position = Position::default();
+        assert!(!position.is_locked(0));
+
+        assert_eq!(
+            position.lock(100, 100),
+            Err(InvariantError::InvalidLockTime)
+        );
+        position.lock(200, 100).unwrap();
+        assert!(position.is_locked(199));
+        assert!(!position.is_locked(200));
+
+        // only extendable
+        assert_eq!(
+            position.lock(150, 100),
+            Err(InvariantError::InvalidLockTime)
+        );
+        assert_eq!(
+            position.lock(200, 100),
+            Err(InvariantError::InvalidLockTime)
+        );
+        position.lock(300, 100).unwrap();
+        assert_eq!(position.locked_until, 300);
+
+        // merging keeps the later lock, whichever position holds it
+        let mut unlocked = Position::default();
+        unlocked.merge(&position).unwrap();
+        assert_eq!(unlocked.locked_until, 300);
+        position.merge(&Position::default()).unwrap();
+        assert_eq!(position.locked_until, 300);
+    }
+
+    #[test]
+    fn test_decode_legacy_position() {
+        let position = Position {
+            liquidity: Liquidity::from_integer(1),
+            lower_tick_index: -10,
+            upper_tick_index: 10,
+            tokens_owed_x: TokenAmount(5),
+            created_at: 1000,
+            deposited_x: TokenAmount(100),
+            claimed_fee_y: TokenAmount(3),
+            locked_until: 500,
+            ..Default::default()
+        };
+        let encoded = ink::scale::Encode::encode(&position);
+
+        let decoded = Position::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, position);
+
+        // layout without the time lock
+        let legacy = &encoded[..encoded.len() - 8];
+        let decoded = Position::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(
+            decoded,
+            Position {
+                locked_until: 0,
+                ..position
+            }
+        );
+
+        // layout without lifetime accounting fields
+        let legacy = &encoded[..encoded.len() - 6 * 16 - 8];
+        let decoded = Position::decode(&mut &legacy[..]).unwrap();
+        assert_eq!(
+            decoded,
+            Position {
+                deposited_x: TokenAmount(0),
+                claimed_fee_y: TokenAmount(0),
+                locked_until: 0,
+                ..position
+            }
+        );
+    }
 }
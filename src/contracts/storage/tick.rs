@@ -99,7 +99,7 @@ impl Tick {
 
         let seconds_passed: u64 = current_timestamp
             .checked_sub(pool.start_timestamp)
-            .ok_or_else(|| err!("current_timestamp - pool.start_timestamp underflow"))?;
+            .ok_or_else(|| err!(TrackableError::SUB))?;
         self.seconds_outside = seconds_passed.wrapping_sub(self.seconds_outside);
 
         if !pool.liquidity.is_zero() {
@@ -117,12 +117,12 @@ impl Tick {
             pool.liquidity = pool
                 .liquidity
                 .checked_add(self.liquidity_change)
-                .map_err(|_| err!("pool.liquidity + tick.liquidity_change overflow"))?;
+                .map_err(|_| err!(TrackableError::ADD))?;
         } else {
             pool.liquidity = pool
                 .liquidity
                 .checked_sub(self.liquidity_change)
-                .map_err(|_| err!("pool.liquidity - tick.liquidity_change underflow"))?
+                .map_err(|_| err!(TrackableError::SUB))?
         }
 
         Ok(())
@@ -155,18 +155,18 @@ impl Tick {
                 self.liquidity_change = self
                     .liquidity_change
                     .checked_sub(liquidity_delta)
-                    .map_err(|_| err!("Underflow while calculating liquidity change"))?;
+                    .map_err(|_| err!(TrackableError::SUB))?;
             } else {
                 self.liquidity_change = liquidity_delta
                     .checked_sub(self.liquidity_change)
-                    .map_err(|_| err!("Underflow while calculating liquidity change"))?;
+                    .map_err(|_| err!(TrackableError::SUB))?;
                 self.sign = !self.sign;
             }
         } else {
             self.liquidity_change = self
                 .liquidity_change
                 .checked_add(liquidity_delta)
-                .map_err(|_| err!("Overflow while calculating liquidity change"))?;
+                .map_err(|_| err!(TrackableError::ADD))?;
         }
 
         Ok(())
@@ -186,11 +186,11 @@ impl Tick {
             true => self
                 .liquidity_gross
                 .checked_add(liquidity_delta)
-                .map_err(|_| err!("tick add liquidity overflow")),
+                .map_err(|_| err!(TrackableError::ADD)),
             false => self
                 .liquidity_gross
                 .checked_sub(liquidity_delta)
-                .map_err(|_| err!("tick sun liquidity overflow")),
+                .map_err(|_| err!(TrackableError::SUB)),
         }?;
         // validate in increase liquidity case
         if sign && new_liquidity >= max_liquidity_per_tick {
@@ -5,6 +5,7 @@ use crate::math::{
     types::sqrt_price::{calculate_sqrt_price, SqrtPrice},
     MAX_TICK,
 };
+use alloc::vec::Vec;
 use ink::storage::Mapping;
 
 pub const TICK_SEARCH_RANGE: i32 = 256;
@@ -114,158 +115,246 @@ pub fn get_search_limit(tick: i32, tick_spacing: u16, up: bool) -> i32 {
     limit.checked_mul(tick_spacing as i32).unwrap()
 }
 
-impl Tickmap {
-    pub fn next_initialized(&self, tick: i32, tick_spacing: u16, pool_key: PoolKey) -> Option<i32> {
-        let limit = get_search_limit(tick, tick_spacing, true);
-
-        if tick.checked_add(tick_spacing as i32).unwrap() > MAX_TICK {
-            return None;
-        }
+fn next_initialized_in(
+    get_chunk: &mut impl FnMut(u16) -> u64,
+    tick: i32,
+    tick_spacing: u16,
+) -> Option<i32> {
+    let limit = get_search_limit(tick, tick_spacing, true);
 
-        // add 1 to not check current tick
-        let (mut chunk, mut bit) =
-            tick_to_position(tick.checked_add(tick_spacing as i32)?, tick_spacing);
-        let (limiting_chunk, limiting_bit) = tick_to_position(limit, tick_spacing);
+    if tick.checked_add(tick_spacing as i32).unwrap() > MAX_TICK {
+        return None;
+    }
 
-        while chunk < limiting_chunk || (chunk == limiting_chunk && bit <= limiting_bit) {
-            let mut shifted = self.bitmap.get((chunk, pool_key)).unwrap_or(0) >> bit;
+    // add 1 to not check current tick
+    let (mut chunk, mut bit) =
+        tick_to_position(tick.checked_add(tick_spacing as i32)?, tick_spacing);
+    let (limiting_chunk, limiting_bit) = tick_to_position(limit, tick_spacing);
 
-            if shifted != 0 {
-                while shifted.checked_rem(2)? == 0 {
-                    shifted >>= 1;
-                    bit = bit.checked_add(1)?;
-                }
+    while chunk < limiting_chunk || (chunk == limiting_chunk && bit <= limiting_bit) {
+        let mut shifted = get_chunk(chunk) >> bit;
 
-                return if chunk < limiting_chunk || (chunk == limiting_chunk && bit <= limiting_bit)
-                {
-                    // no possibility of overflow
-                    let index: i32 = (chunk as i32)
-                        .checked_mul(CHUNK_SIZE)
-                        .unwrap()
-                        .checked_add(bit as i32)
-                        .unwrap();
-
-                    Some(
-                        index
-                            .checked_sub(MAX_TICK.checked_div(tick_spacing as i32).unwrap())?
-                            .checked_mul(tick_spacing.into())?,
-                    )
-                } else {
-                    None
-                };
+        if shifted != 0 {
+            while shifted.checked_rem(2)? == 0 {
+                shifted >>= 1;
+                bit = bit.checked_add(1)?;
             }
 
-            // go to the text chunk
-            // if let value = chunk.checked_add(1)? {
-            if let Some(value) = chunk.checked_add(1) {
-                chunk = value;
+            return if chunk < limiting_chunk || (chunk == limiting_chunk && bit <= limiting_bit) {
+                // no possibility of overflow
+                let index: i32 = (chunk as i32)
+                    .checked_mul(CHUNK_SIZE)
+                    .unwrap()
+                    .checked_add(bit as i32)
+                    .unwrap();
+
+                Some(
+                    index
+                        .checked_sub(MAX_TICK.checked_div(tick_spacing as i32).unwrap())?
+                        .checked_mul(tick_spacing.into())?,
+                )
             } else {
-                return None;
+                None
+            };
+        }
+
+        // go to the text chunk
+        // if let value = chunk.checked_add(1)? {
+        if let Some(value) = chunk.checked_add(1) {
+            chunk = value;
+        } else {
+            return None;
+        }
+        bit = 0;
+    }
+
+    None
+}
+
+// tick_spacing - spacing already scaled by tick_spacing
+fn prev_initialized_in(
+    get_chunk: &mut impl FnMut(u16) -> u64,
+    tick: i32,
+    tick_spacing: u16,
+) -> Option<i32> {
+    // don't subtract 1 to check the current tick
+    let limit = get_search_limit(tick, tick_spacing, false); // limit scaled by tick_spacing
+    let (mut chunk, mut bit) = tick_to_position(tick, tick_spacing);
+    let (limiting_chunk, limiting_bit) = tick_to_position(limit, tick_spacing);
+
+    while chunk > limiting_chunk || (chunk == limiting_chunk && bit >= limiting_bit) {
+        // always safe due to limitated domain of bit variable
+        let mut mask = 1u128 << bit; // left = MSB direction (increase value)
+        let value = get_chunk(chunk) as u128;
+
+        // enter if some of previous bits are initialized in current chunk
+        if value.checked_rem(mask.checked_shl(1)?)? > 0 {
+            // skip uninitalized ticks
+            while value & mask == 0 {
+                mask >>= 1;
+                bit = bit.checked_sub(1)?;
             }
-            bit = 0;
+
+            // return first initalized tick if limiit is not exceeded, otherswise return None
+            return if chunk > limiting_chunk || (chunk == limiting_chunk && bit >= limiting_bit) {
+                // no possibility to overflow
+                let index: i32 = (chunk as i32)
+                    .checked_mul(CHUNK_SIZE)
+                    .unwrap()
+                    .checked_add(bit as i32)
+                    .unwrap();
+
+                Some(
+                    index
+                        .checked_sub(MAX_TICK.checked_div(tick_spacing as i32).unwrap())?
+                        .checked_mul(tick_spacing.into())?,
+                )
+            } else {
+                None
+            };
         }
 
-        None
+        // go to the next chunk
+        // if let value = chunk.checked_sub(1)? {
+        if let Some(value) = chunk.checked_sub(1) {
+            chunk = value;
+        } else {
+            return None;
+        }
+        bit = (CHUNK_SIZE as u8).checked_sub(1).unwrap();
     }
 
-    // tick_spacing - spacing already scaled by tick_spacing
-    pub fn prev_initialized(&self, tick: i32, tick_spacing: u16, pool_key: PoolKey) -> Option<i32> {
-        // don't subtract 1 to check the current tick
-        let limit = get_search_limit(tick, tick_spacing, false); // limit scaled by tick_spacing
-        let (mut chunk, mut bit) = tick_to_position(tick, tick_spacing);
-        let (limiting_chunk, limiting_bit) = tick_to_position(limit, tick_spacing);
-
-        while chunk > limiting_chunk || (chunk == limiting_chunk && bit >= limiting_bit) {
-            // always safe due to limitated domain of bit variable
-            let mut mask = 1u128 << bit; // left = MSB direction (increase value)
-            let value = self.bitmap.get((chunk, pool_key)).unwrap_or(0) as u128;
-
-            // enter if some of previous bits are initialized in current chunk
-            if value.checked_rem(mask.checked_shl(1)?)? > 0 {
-                // skip uninitalized ticks
-                while value & mask == 0 {
-                    mask >>= 1;
-                    bit = bit.checked_sub(1)?;
-                }
+    None
+}
 
-                // return first initalized tick if limiit is not exceeded, otherswise return None
-                return if chunk > limiting_chunk || (chunk == limiting_chunk && bit >= limiting_bit)
-                {
-                    // no possibility to overflow
-                    let index: i32 = (chunk as i32)
-                        .checked_mul(CHUNK_SIZE)
-                        .unwrap()
-                        .checked_add(bit as i32)
-                        .unwrap();
-
-                    Some(
-                        index
-                            .checked_sub(MAX_TICK.checked_div(tick_spacing as i32).unwrap())?
-                            .checked_mul(tick_spacing.into())?,
-                    )
-                } else {
-                    None
-                };
+// Finds closes initialized tick in direction of trade
+// and compares its sqrt_price to the sqrt_price limit of the trade
+fn get_closer_limit_in(
+    get_chunk: &mut impl FnMut(u16) -> u64,
+    sqrt_price_limit: SqrtPrice,
+    x_to_y: bool,
+    current_tick: i32,
+    tick_spacing: u16,
+) -> Result<(SqrtPrice, Option<(i32, bool)>), InvariantError> {
+    let closes_tick_index = if x_to_y {
+        prev_initialized_in(get_chunk, current_tick, tick_spacing)
+    } else {
+        next_initialized_in(get_chunk, current_tick, tick_spacing)
+    };
+
+    match closes_tick_index {
+        Some(index) => {
+            let sqrt_price = calculate_sqrt_price(index).unwrap();
+
+            if (x_to_y && sqrt_price > sqrt_price_limit)
+                || (!x_to_y && sqrt_price < sqrt_price_limit)
+            {
+                Ok((sqrt_price, Some((index, true))))
+            } else {
+                Ok((sqrt_price_limit, None))
+            }
+        }
+        None => {
+            let index = get_search_limit(current_tick, tick_spacing, !x_to_y);
+            let sqrt_price = calculate_sqrt_price(index).unwrap();
+
+            if current_tick == index {
+                return Err(InvariantError::TickLimitReached);
             }
 
-            // go to the next chunk
-            // if let value = chunk.checked_sub(1)? {
-            if let Some(value) = chunk.checked_sub(1) {
-                chunk = value;
+            if (x_to_y && sqrt_price > sqrt_price_limit)
+                || (!x_to_y && sqrt_price < sqrt_price_limit)
+            {
+                Ok((sqrt_price, Some((index, false))))
             } else {
-                return None;
+                Ok((sqrt_price_limit, None))
             }
-            bit = (CHUNK_SIZE as u8).checked_sub(1).unwrap();
         }
+    }
+}
+
+// Bitmap chunks of a single pool read during one swap, the bitmap does not change while
+// the swap is calculated, so every chunk is read from the storage at most once
+pub struct CachedTickmap<'a> {
+    tickmap: &'a Tickmap,
+    pool_key: PoolKey,
+    chunks: Vec<(u16, u64)>,
+}
 
-        None
+impl<'a> CachedTickmap<'a> {
+    pub fn new(tickmap: &'a Tickmap, pool_key: PoolKey) -> Self {
+        Self {
+            tickmap,
+            pool_key,
+            chunks: Vec::new(),
+        }
     }
 
-    // Finds closes initialized tick in direction of trade
-    // and compares its sqrt_price to the sqrt_price limit of the trade
     pub fn get_closer_limit(
-        &self,
+        &mut self,
         sqrt_price_limit: SqrtPrice,
         x_to_y: bool,
         current_tick: i32,
         tick_spacing: u16,
-        pool_key: PoolKey,
     ) -> Result<(SqrtPrice, Option<(i32, bool)>), InvariantError> {
-        let closes_tick_index = if x_to_y {
-            self.prev_initialized(current_tick, tick_spacing, pool_key)
-        } else {
-            self.next_initialized(current_tick, tick_spacing, pool_key)
-        };
+        get_closer_limit_in(
+            &mut |chunk| self.get_chunk(chunk),
+            sqrt_price_limit,
+            x_to_y,
+            current_tick,
+            tick_spacing,
+        )
+    }
 
-        match closes_tick_index {
-            Some(index) => {
-                let sqrt_price = calculate_sqrt_price(index).unwrap();
+    fn get_chunk(&mut self, chunk: u16) -> u64 {
+        if let Some((_, value)) = self.chunks.iter().find(|(index, _)| *index == chunk) {
+            return *value;
+        }
 
-                if (x_to_y && sqrt_price > sqrt_price_limit)
-                    || (!x_to_y && sqrt_price < sqrt_price_limit)
-                {
-                    Ok((sqrt_price, Some((index, true))))
-                } else {
-                    Ok((sqrt_price_limit, None))
-                }
-            }
-            None => {
-                let index = get_search_limit(current_tick, tick_spacing, !x_to_y);
-                let sqrt_price = calculate_sqrt_price(index).unwrap();
+        let value = self.tickmap.bitmap.get((chunk, self.pool_key)).unwrap_or(0);
+        self.chunks.push((chunk, value));
+        value
+    }
+}
 
-                if current_tick == index {
-                    return Err(InvariantError::TickLimitReached);
-                }
+impl Tickmap {
+    #[cfg(test)]
+    pub fn next_initialized(&self, tick: i32, tick_spacing: u16, pool_key: PoolKey) -> Option<i32> {
+        next_initialized_in(
+            &mut |chunk| self.bitmap.get((chunk, pool_key)).unwrap_or(0),
+            tick,
+            tick_spacing,
+        )
+    }
 
-                if (x_to_y && sqrt_price > sqrt_price_limit)
-                    || (!x_to_y && sqrt_price < sqrt_price_limit)
-                {
-                    Ok((sqrt_price, Some((index, false))))
-                } else {
-                    Ok((sqrt_price_limit, None))
-                }
-            }
-        }
+    // tick_spacing - spacing already scaled by tick_spacing
+    #[cfg(test)]
+    pub fn prev_initialized(&self, tick: i32, tick_spacing: u16, pool_key: PoolKey) -> Option<i32> {
+        prev_initialized_in(
+            &mut |chunk| self.bitmap.get((chunk, pool_key)).unwrap_or(0),
+            tick,
+            tick_spacing,
+        )
+    }
+
+    // Finds closes initialized tick in direction of trade
+    // and compares its sqrt_price to the sqrt_price limit of the trade
+    #[cfg(test)]
+    pub fn get_closer_limit(
+        &self,
+        sqrt_price_limit: SqrtPrice,
+        x_to_y: bool,
+        current_tick: i32,
+        tick_spacing: u16,
+        pool_key: PoolKey,
+    ) -> Result<(SqrtPrice, Option<(i32, bool)>), InvariantError> {
+        get_closer_limit_in(
+            &mut |chunk| self.bitmap.get((chunk, pool_key)).unwrap_or(0),
+            sqrt_price_limit,
+            x_to_y,
+            current_tick,
+            tick_spacing,
+        )
     }
 
     pub fn get(&self, tick: i32, tick_spacing: u16, pool_key: PoolKey) -> bool {
@@ -382,6 +471,46 @@ mod tests {
         }
     }
 
+    #[ink::test]
+    fn test_cached_get_closer_limit() {
+        let token_0: AccountId = AccountId::from([0x01; 32]);
+        let token_1: AccountId = AccountId::from([0x02; 32]);
+        let fee_tier: FeeTier = FeeTier {
+            fee: Percentage::new(1),
+            tick_spacing: 1,
+        };
+        let pool_key: PoolKey = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+
+        let tickmap = &mut Tickmap::default();
+        for tick in [-200, -70, 0, 63, 64, 130] {
+            tickmap.flip(true, tick, 1, pool_key);
+        }
+
+        let cached = &mut CachedTickmap::new(tickmap, pool_key);
+        for (current_tick, x_to_y, sqrt_price_limit) in [
+            (150, true, SqrtPrice::from_scale(1, 1)),
+            (130, true, SqrtPrice::from_scale(1, 1)),
+            (63, true, SqrtPrice::from_scale(1, 1)),
+            (-1, true, SqrtPrice::from_scale(1, 1)),
+            (-300, false, SqrtPrice::from_integer(2)),
+            (-70, false, SqrtPrice::from_integer(2)),
+            (64, false, SqrtPrice::from_integer(2)),
+            (0, true, SqrtPrice::from_integer(2)),
+        ] {
+            assert_eq!(
+                cached.get_closer_limit(sqrt_price_limit, x_to_y, current_tick, 1),
+                tickmap.get_closer_limit(sqrt_price_limit, x_to_y, current_tick, 1, pool_key)
+            );
+        }
+
+        // every chunk is read once
+        let mut chunks: Vec<u16> = cached.chunks.iter().map(|(chunk, _)| *chunk).collect();
+        let chunks_count = chunks.len();
+        chunks.sort();
+        chunks.dedup();
+        assert_eq!(chunks.len(), chunks_count);
+    }
+
     #[ink::test]
     fn test_flip() {
         let token_0: AccountId = AccountId::from([0x01; 32]);
@@ -0,0 +1,12 @@
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum TokenStatus {
+    /// Token can be used in permissionlessly created pools.
+    #[default]
+    Unlisted,
+    /// Token is verified, pools where both tokens are allowed are curated on creation.
+    Allowed,
+    /// Token cannot be used to create new pools.
+    Denied,
+}
@@ -1,8 +1,9 @@
 use crate::{
     contracts::{Pool, PoolKey, Tick},
-    math::types::{sqrt_price::SqrtPrice, token_amount::TokenAmount},
+    math::types::{percentage::Percentage, sqrt_price::SqrtPrice, token_amount::TokenAmount},
 };
 use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
 
 #[derive(Default, Clone, Debug, PartialEq)]
 #[ink::scale_derive(Encode, Decode, TypeInfo)]
@@ -14,6 +15,8 @@ pub struct CalculateSwapResult {
     pub fee: TokenAmount,
     pub pool: Pool,
     pub ticks: Vec<Tick>,
+    pub remaining_amount: TokenAmount,
+    pub max_swap_steps_reached: bool,
 }
 
 #[derive(Default, Debug)]
@@ -23,6 +26,21 @@ pub struct QuoteResult {
     pub amount_out: TokenAmount,
     pub target_sqrt_price: SqrtPrice,
     pub ticks: Vec<Tick>,
+    pub remaining_amount: TokenAmount,
+    pub max_swap_steps_reached: bool,
+}
+
+impl From<CalculateSwapResult> for QuoteResult {
+    fn from(result: CalculateSwapResult) -> Self {
+        Self {
+            amount_in: result.amount_in,
+            amount_out: result.amount_out,
+            target_sqrt_price: result.pool.sqrt_price,
+            ticks: result.ticks,
+            remaining_amount: result.remaining_amount,
+            max_swap_steps_reached: result.max_swap_steps_reached,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -31,3 +49,39 @@ pub struct SwapHop {
     pub pool_key: PoolKey,
     pub x_to_y: bool,
 }
+
+impl SwapHop {
+    pub fn token_in(&self) -> AccountId {
+        if self.x_to_y {
+            self.pool_key.token_x
+        } else {
+            self.pool_key.token_y
+        }
+    }
+
+    pub fn token_out(&self) -> AccountId {
+        if self.x_to_y {
+            self.pool_key.token_y
+        } else {
+            self.pool_key.token_x
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct SplitRoute {
+    pub amount_in: TokenAmount,
+    pub swaps: Vec<SwapHop>,
+}
+
+// 1%
+pub const MAX_REFERRAL_FEE: Percentage = Percentage(10_000_000_000);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct Referral {
+    pub referrer: AccountId,
+    // part of the output (or of the input for swaps by amount out) accrued to the referrer
+    pub fee: Percentage,
+}
@@ -46,7 +46,7 @@ pub mod e2e_tests {
         );
         assert!(exist);
 
-        let fee_tiers = get_fee_tiers!(client, dex);
+        let fee_tiers = get_fee_tiers!(client, dex).unwrap();
         assert_eq!(fee_tiers.len(), 3);
         assert_eq!(fee_tiers[0], first_fee_tier);
         assert_eq!(fee_tiers[1], second_fee_tier);
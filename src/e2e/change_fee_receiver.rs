@@ -11,7 +11,8 @@ pub mod e2e_tests {
     use decimal::*;
     use ink_e2e::ContractsBackend;
     use test_helpers::{
-        add_fee_tier, address_of, change_fee_receiver, create_dex, create_pool, create_tokens,
+        add_fee_tier, address_of, change_default_fee_receiver, change_fee_receiver,
+        change_fee_receivers, create_dex, create_pool, create_tokens, get_default_fee_receiver,
         get_pool,
     };
     use token::TokenRef;
@@ -94,4 +95,81 @@ pub mod e2e_tests {
         assert_eq!(result, Err(InvariantError::NotAdmin));
         Ok(())
     }
+
+    #[ink_e2e::test]
+    async fn test_default_fee_receiver(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let dex = create_dex!(client, Percentage::new(0));
+        let (token_x, token_y) = create_tokens!(client, 500, 500);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(5, 1), 1).unwrap();
+        let other_fee_tier = FeeTier::new(Percentage::from_scale(5, 1), 2).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+
+        let admin = ink_e2e::alice();
+        let user = ink_e2e::bob();
+
+        add_fee_tier!(client, dex, fee_tier, admin).unwrap();
+        add_fee_tier!(client, dex, other_fee_tier, admin).unwrap();
+        assert_eq!(get_default_fee_receiver!(client, dex), address_of!(Alice));
+
+        let result = change_default_fee_receiver!(client, dex, address_of!(Bob), user);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+        change_default_fee_receiver!(client, dex, address_of!(Bob), admin).unwrap();
+        assert_eq!(get_default_fee_receiver!(client, dex), address_of!(Bob));
+
+        create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            admin
+        )
+        .unwrap();
+        create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            other_fee_tier,
+            init_sqrt_price,
+            0,
+            admin
+        )
+        .unwrap();
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.fee_receiver, address_of!(Bob));
+
+        let pool_keys = vec![
+            PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap(),
+            PoolKey::new(token_x.account_id, token_y.account_id, other_fee_tier).unwrap(),
+        ];
+        let result =
+            change_fee_receivers!(client, dex, pool_keys.clone(), address_of!(Charlie), user);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+        change_fee_receivers!(client, dex, pool_keys, address_of!(Charlie), admin).unwrap();
+
+        for fee_tier in [fee_tier, other_fee_tier] {
+            let pool = get_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                fee_tier
+            )
+            .unwrap();
+            assert_eq!(pool.fee_receiver, address_of!(Charlie));
+        }
+
+        Ok(())
+    }
 }
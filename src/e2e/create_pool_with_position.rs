@@ -0,0 +1,93 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError},
+        invariant::InvariantRef,
+        math::types::{
+            liquidity::Liquidity, percentage::Percentage, sqrt_price::calculate_sqrt_price,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, approve, balance_of, create_dex, create_pool_with_position, create_tokens,
+        get_pool, get_position,
+    };
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_create_pool_with_position(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let dex = create_dex!(client, Percentage::new(0));
+        let mint_amount = 10u128.pow(10);
+        let (token_x, token_y) = create_tokens!(client, mint_amount, mint_amount);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let alice = ink_e2e::alice();
+
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        // price between ticks 10 and 20, the init tick is aligned down to the tick spacing
+        let init_sqrt_price = calculate_sqrt_price(15).unwrap();
+        let liquidity = Liquidity::from_integer(1000000);
+
+        let position = create_pool_with_position!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            -20,
+            30,
+            liquidity,
+            alice
+        )
+        .unwrap();
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.sqrt_price, init_sqrt_price);
+        assert_eq!(pool.current_tick_index, 10);
+        assert_eq!(pool.liquidity, liquidity);
+
+        assert_eq!(position.liquidity, liquidity);
+        assert_eq!(get_position!(client, dex, 0, alice).unwrap(), position);
+
+        let dex_x = balance_of!(client, token_x, dex.account_id);
+        let dex_y = balance_of!(client, token_y, dex.account_id);
+        assert!(dex_x > 0);
+        assert!(dex_y > 0);
+        assert_eq!(dex_x, position.deposited_x.get());
+        assert_eq!(dex_y, position.deposited_y.get());
+
+        let result = create_pool_with_position!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            -20,
+            30,
+            liquidity,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::PoolAlreadyExist));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey},
+        invariant::InvariantRef,
+        math::types::{
+            fee_growth::FeeGrowth, liquidity::Liquidity, percentage::Percentage,
+            sqrt_price::calculate_sqrt_price, token_amount::TokenAmount,
+        },
+    };
+    use decimal::*;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, claim_fee, create_dex, create_pool,
+        create_position, create_tokens, donate, get_pool, init_basic_pool, init_basic_position,
+        init_dex_and_tokens, mint,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_donate(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+
+        let amount_x = TokenAmount(1000);
+        let amount_y = TokenAmount(500);
+        mint!(client, token_x, address_of!(Bob), amount_x.get(), bob).unwrap();
+        mint!(client, token_y, address_of!(Bob), amount_y.get(), bob).unwrap();
+        approve!(client, token_x, dex.account_id, amount_x.get(), bob).unwrap();
+        approve!(client, token_y, dex.account_id, amount_y.get(), bob).unwrap();
+
+        let pool_before = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        let dex_x_before = balance_of!(client, token_x, dex.account_id);
+        let dex_y_before = balance_of!(client, token_y, dex.account_id);
+
+        donate!(client, dex, pool_key, amount_x, amount_y, bob).unwrap();
+
+        let pool_after = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(
+            pool_after.fee_growth_global_x,
+            FeeGrowth::from_fee(pool_before.liquidity, amount_x).unwrap()
+        );
+        assert_eq!(
+            pool_after.fee_growth_global_y,
+            FeeGrowth::from_fee(pool_before.liquidity, amount_y).unwrap()
+        );
+        assert_eq!(pool_after.fee_protocol_token_x, TokenAmount(0));
+        assert_eq!(pool_after.fee_protocol_token_y, TokenAmount(0));
+        assert_eq!(
+            balance_of!(client, token_x, dex.account_id),
+            dex_x_before + amount_x.get()
+        );
+        assert_eq!(
+            balance_of!(client, token_y, dex.account_id),
+            dex_y_before + amount_y.get()
+        );
+        assert_eq!(balance_of!(client, token_x, address_of!(Bob)), 0);
+        assert_eq!(balance_of!(client, token_y, address_of!(Bob)), 0);
+
+        let (claimed_x, claimed_y) = claim_fee!(client, dex, 0, alice).unwrap();
+        assert!(claimed_x <= amount_x && claimed_x + TokenAmount(1) >= amount_x);
+        assert!(claimed_y <= amount_y && claimed_y + TokenAmount(1) >= amount_y);
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_donate_validation(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        approve!(client, token_x, dex.account_id, 1000, alice).unwrap();
+
+        let result = donate!(client, dex, pool_key, TokenAmount(0), TokenAmount(0), alice);
+        assert_eq!(result, Err(InvariantError::AmountIsZero));
+
+        let result = donate!(
+            client,
+            dex,
+            pool_key,
+            TokenAmount(1000),
+            TokenAmount(0),
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::ZeroLiquidity));
+
+        let other_fee_tier = FeeTier::new(Percentage::from_scale(1, 2), 10).unwrap();
+        let other_pool_key =
+            PoolKey::new(token_x.account_id, token_y.account_id, other_fee_tier).unwrap();
+        let result = donate!(
+            client,
+            dex,
+            other_pool_key,
+            TokenAmount(1000),
+            TokenAmount(0),
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::PoolNotFound));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, CreatePositionEvent, FeeTier, PoolKey, SwapEvent,
+        },
+        invariant::InvariantRef,
+        math::{
+            types::{
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::scale::Decode;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, create_dex, create_pool, create_tokens, get_pool,
+        init_basic_pool, init_dex_and_tokens, mint,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_position_and_swap_events(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+
+        let mut call_builder = dex.call_builder::<Invariant>();
+        let call = call_builder.create_position(
+            pool_key,
+            -20,
+            10,
+            Liquidity::from_integer(1000000),
+            pool.sqrt_price,
+            pool.sqrt_price,
+        );
+        let result = client
+            .call(&alice, &call)
+            .extra_gas_portion(1000)
+            .submit()
+            .await
+            .unwrap();
+
+        let events = result.contract_emitted_events()?;
+        let event = events
+            .iter()
+            .find(|event| event.event.contract == dex.account_id)
+            .unwrap();
+        let create_position_event = CreatePositionEvent::decode(&mut &event.event.data[..])?;
+
+        assert_eq!(event.topics.len(), 4);
+        assert_eq!(
+            event.topics[2].0,
+            *AsRef::<[u8; 32]>::as_ref(&address_of!(Alice))
+        );
+        assert_eq!(create_position_event.address, address_of!(Alice));
+        assert_eq!(create_position_event.pool, pool_key);
+        assert_eq!(create_position_event.index, 0);
+        assert_eq!(create_position_event.amount_x, TokenAmount(500));
+        assert_eq!(create_position_event.amount_y, TokenAmount(1000));
+
+        let amount = 1000;
+        mint!(client, token_x, address_of!(Bob), amount, bob).unwrap();
+        approve!(client, token_x, dex.account_id, amount, bob).unwrap();
+
+        let call = call_builder.swap(
+            pool_key,
+            true,
+            TokenAmount(amount),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+        );
+        let result = client
+            .call(&bob, &call)
+            .extra_gas_portion(1000)
+            .submit()
+            .await
+            .unwrap();
+
+        let events = result.contract_emitted_events()?;
+        let event = events
+            .iter()
+            .filter(|event| event.event.contract == dex.account_id)
+            .last()
+            .unwrap();
+        let swap_event = SwapEvent::decode(&mut &event.event.data[..])?;
+
+        assert_eq!(event.topics.len(), 4);
+        assert_eq!(
+            event.topics[2].0,
+            *AsRef::<[u8; 32]>::as_ref(&address_of!(Bob))
+        );
+        assert_eq!(swap_event.pool, pool_key);
+        assert_eq!(swap_event.amount_in, TokenAmount(amount));
+        assert_eq!(swap_event.liquidity, Liquidity::from_integer(1000000));
+        assert_eq!(swap_event.current_tick_index, -20);
+
+        Ok(())
+    }
+}
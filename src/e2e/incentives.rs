@@ -0,0 +1,276 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey},
+        invariant::InvariantRef,
+        math::types::{
+            liquidity::Liquidity, percentage::Percentage, sqrt_price::calculate_sqrt_price,
+            token_amount::TokenAmount,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, claim_incentive_reward, create_dex,
+        create_incentive, create_pool, create_position, create_tokens, end_incentive,
+        force_unstake_position, get_incentive, get_pool, get_position, get_stake, init_basic_pool,
+        init_basic_position, init_dex_and_tokens, stake_position, unstake_position,
+    };
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_incentive_stake_claim_unstake(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let position = get_position!(client, dex, 0, alice).unwrap();
+
+        let reward = TokenAmount(1000000);
+        let start_timestamp = position.created_at;
+        let end_timestamp = start_timestamp + 3600;
+        approve!(client, token_x, dex.account_id, reward.get(), alice).unwrap();
+        let funder_balance_before = balance_of!(client, token_x, address_of!(Alice));
+
+        let incentive_id = create_incentive!(
+            client,
+            dex,
+            pool_key,
+            token_x.account_id,
+            reward,
+            start_timestamp,
+            end_timestamp,
+            alice
+        )
+        .unwrap();
+        assert_eq!(incentive_id, 0);
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            funder_balance_before - reward.get()
+        );
+
+        let stake_index = stake_position!(client, dex, 0, incentive_id, alice).unwrap();
+        assert_eq!(stake_index, 0);
+        let result = get_position!(client, dex, 0, alice);
+        assert_eq!(result, Err(InvariantError::PositionNotFound));
+
+        let stake = get_stake!(client, dex, address_of!(Alice), stake_index).unwrap();
+        assert_eq!(stake.incentive_id, incentive_id);
+        assert_eq!(stake.position.liquidity, position.liquidity);
+        let incentive = get_incentive!(client, dex, incentive_id).unwrap();
+        assert_eq!(incentive.number_of_stakes, 1);
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let balance_before_claim = balance_of!(client, token_x, address_of!(Alice));
+        let claimed = claim_incentive_reward!(client, dex, stake_index, alice).unwrap();
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            balance_before_claim + claimed.get()
+        );
+        let incentive = get_incentive!(client, dex, incentive_id).unwrap();
+        assert_eq!(incentive.total_reward_unclaimed, reward - claimed);
+
+        let balance_before_unstake = balance_of!(client, token_x, address_of!(Alice));
+        let unstake_reward = unstake_position!(client, dex, stake_index, alice).unwrap();
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            balance_before_unstake + unstake_reward.get()
+        );
+        let incentive = get_incentive!(client, dex, incentive_id).unwrap();
+        assert_eq!(incentive.number_of_stakes, 0);
+        assert_eq!(
+            incentive.total_reward_unclaimed,
+            reward - claimed - unstake_reward
+        );
+        assert!(claimed + unstake_reward < reward);
+
+        let result = get_stake!(client, dex, address_of!(Alice), stake_index);
+        assert_eq!(result, Err(InvariantError::StakeNotFound));
+        let returned_position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(returned_position.liquidity, position.liquidity);
+        assert_eq!(
+            returned_position.lower_tick_index,
+            position.lower_tick_index
+        );
+        assert_eq!(
+            returned_position.upper_tick_index,
+            position.upper_tick_index
+        );
+
+        let result = end_incentive!(client, dex, incentive_id, alice);
+        assert_eq!(result, Err(InvariantError::IncentiveNotEnded));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_end_incentive_requires_no_stakes(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let position = get_position!(client, dex, 0, alice).unwrap();
+
+        let reward = TokenAmount(1000000);
+        approve!(client, token_x, dex.account_id, reward.get(), alice).unwrap();
+        let incentive_id = create_incentive!(
+            client,
+            dex,
+            pool_key,
+            token_x.account_id,
+            reward,
+            position.created_at,
+            position.created_at + 10,
+            alice
+        )
+        .unwrap();
+        let stake_index = stake_position!(client, dex, 0, incentive_id, alice).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_secs(12));
+
+        let result = end_incentive!(client, dex, incentive_id, alice);
+        assert_eq!(result, Err(InvariantError::IncentiveHasStakes));
+
+        let unstake_reward = unstake_position!(client, dex, stake_index, alice).unwrap();
+        assert!(!unstake_reward.is_zero());
+
+        let funder_balance_before = balance_of!(client, token_x, address_of!(Alice));
+        let refund = end_incentive!(client, dex, incentive_id, alice).unwrap();
+        assert_eq!(refund, reward - unstake_reward);
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            funder_balance_before + refund.get()
+        );
+        let incentive = get_incentive!(client, dex, incentive_id).unwrap();
+        assert_eq!(incentive.total_reward_unclaimed, TokenAmount(0));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_end_incentive_with_stake_never_unstaked(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let position = get_position!(client, dex, 0, alice).unwrap();
+
+        let reward = TokenAmount(1000000);
+        approve!(client, token_x, dex.account_id, reward.get(), alice).unwrap();
+        let incentive_id = create_incentive!(
+            client,
+            dex,
+            pool_key,
+            token_x.account_id,
+            reward,
+            position.created_at,
+            position.created_at + 10,
+            alice
+        )
+        .unwrap();
+        let stake_index = stake_position!(client, dex, 0, incentive_id, alice).unwrap();
+
+        let result = force_unstake_position!(client, dex, address_of!(Alice), stake_index, bob);
+        assert_eq!(result, Err(InvariantError::IncentiveNotEnded));
+
+        std::thread::sleep(std::time::Duration::from_secs(12));
+
+        // the staker never unstakes, anyone can release the stake once the program ended
+        let result = end_incentive!(client, dex, incentive_id, bob);
+        assert_eq!(result, Err(InvariantError::IncentiveHasStakes));
+
+        let staker_balance_before = balance_of!(client, token_x, address_of!(Alice));
+        let unstake_reward =
+            force_unstake_position!(client, dex, address_of!(Alice), stake_index, bob).unwrap();
+        assert!(!unstake_reward.is_zero());
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            staker_balance_before + unstake_reward.get()
+        );
+        let result = get_stake!(client, dex, address_of!(Alice), stake_index);
+        assert_eq!(result, Err(InvariantError::StakeNotFound));
+        let returned_position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(returned_position.liquidity, position.liquidity);
+
+        let funder_balance_before = balance_of!(client, token_x, address_of!(Alice));
+        let refund = end_incentive!(client, dex, incentive_id, bob).unwrap();
+        assert_eq!(refund, reward - unstake_reward);
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            funder_balance_before + refund.get()
+        );
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_stake_position_validation(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let position = get_position!(client, dex, 0, alice).unwrap();
+
+        let reward = TokenAmount(1000000);
+        approve!(client, token_x, dex.account_id, reward.get(), alice).unwrap();
+
+        let result = create_incentive!(
+            client,
+            dex,
+            pool_key,
+            token_x.account_id,
+            reward,
+            position.created_at,
+            position.created_at,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::InvalidIncentiveTime));
+
+        let incentive_id = create_incentive!(
+            client,
+            dex,
+            pool_key,
+            token_x.account_id,
+            reward,
+            position.created_at + 3600,
+            position.created_at + 7200,
+            alice
+        )
+        .unwrap();
+
+        let result = stake_position!(client, dex, 0, incentive_id, alice);
+        assert_eq!(result, Err(InvariantError::IncentiveNotActive));
+        let result = stake_position!(client, dex, 0, incentive_id, bob);
+        assert_eq!(result, Err(InvariantError::PositionNotFound));
+        let result = stake_position!(client, dex, 0, incentive_id + 1, alice);
+        assert_eq!(result, Err(InvariantError::IncentiveNotFound));
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 pub mod e2e_tests {
     use crate::invariant::Invariant;
     use crate::{
-        contracts::{entrypoints::InvariantEntrypoints, FeeTier, PoolKey},
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey},
         invariant::InvariantRef,
         math::{
             log::get_tick_at_sqrt_price,
@@ -12,14 +12,15 @@ pub mod e2e_tests {
                 sqrt_price::{calculate_sqrt_price, SqrtPrice},
                 token_amount::TokenAmount,
             },
-            MIN_SQRT_PRICE,
+            MAX_SWAP_STEPS, MIN_SQRT_PRICE,
         },
     };
     use decimal::*;
     use ink_e2e::ContractsBackend;
     use test_helpers::{
-        add_fee_tier, address_of, approve, balance_of, create_dex, create_pool, create_position,
-        create_tokens, get_pool, init_basic_pool, init_dex_and_tokens, mint, quote, swap,
+        add_fee_tier, address_of, approve, balance_of, change_max_swap_steps, create_dex,
+        create_pool, create_position, create_tokens, get_max_swap_steps, get_pool, init_basic_pool,
+        init_dex_and_tokens, mint, quote, swap, swap_partial,
     };
     use token::PSP22Mintable;
     use token::Token;
@@ -145,4 +146,192 @@ pub mod e2e_tests {
 
         Ok(())
     }
+
+    #[ink_e2e::test]
+    async fn max_swap_steps_reached(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+
+        let mint_amount = u128::MAX;
+        let alice = ink_e2e::alice();
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let liquidity = Liquidity::from_integer(10000000);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+
+        for i in (-200..20).step_by(10) {
+            let pool = get_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                fee_tier
+            )
+            .unwrap();
+
+            let slippage_limit_lower = pool.sqrt_price;
+            let slippage_limit_upper = pool.sqrt_price;
+
+            create_position!(
+                client,
+                dex,
+                pool_key,
+                i,
+                i + 10,
+                liquidity,
+                slippage_limit_lower,
+                slippage_limit_upper,
+                alice
+            )
+            .unwrap();
+        }
+
+        assert_eq!(get_max_swap_steps!(client, dex), MAX_SWAP_STEPS);
+
+        let bob = ink_e2e::bob();
+        let result = change_max_swap_steps!(client, dex, 5, bob);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+        change_max_swap_steps!(client, dex, 5, alice).unwrap();
+        assert_eq!(get_max_swap_steps!(client, dex), 5);
+
+        let amount = 100_000;
+        mint!(client, token_x, address_of!(Bob), amount, bob).unwrap();
+        approve!(client, token_x, dex.account_id, amount, bob).unwrap();
+
+        let swap_amount = TokenAmount::new(amount);
+        let slippage = SqrtPrice::new(MIN_SQRT_PRICE);
+        let quote_result =
+            quote!(client, dex, pool_key, true, swap_amount, true, slippage).unwrap();
+        assert!(quote_result.max_swap_steps_reached);
+        assert!(quote_result.ticks.len() <= 5);
+        assert!(!quote_result.remaining_amount.is_zero());
+        assert_eq!(
+            quote_result.amount_in + quote_result.remaining_amount,
+            swap_amount
+        );
+
+        let result = swap!(
+            client,
+            dex,
+            pool_key,
+            true,
+            swap_amount,
+            true,
+            slippage,
+            bob
+        );
+        assert_eq!(result, Err(InvariantError::MaxSwapStepsReached));
+
+        let swap_result = swap_partial!(
+            client,
+            dex,
+            pool_key,
+            true,
+            swap_amount,
+            true,
+            slippage,
+            bob
+        )
+        .unwrap();
+        assert!(swap_result.max_swap_steps_reached);
+        assert_eq!(swap_result.amount_in, quote_result.amount_in);
+        assert_eq!(swap_result.amount_out, quote_result.amount_out);
+        assert_eq!(swap_result.remaining_amount, quote_result.remaining_amount);
+
+        let pool_after = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            pool_key.fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool_after.sqrt_price, quote_result.target_sqrt_price);
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn max_ticks_cross_gas(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+
+        let mint_amount = u128::MAX;
+        let alice = ink_e2e::alice();
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let liquidity = Liquidity::from_integer(10000000);
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+
+        for i in (-2560..20).step_by(10) {
+            let pool = get_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                fee_tier
+            )
+            .unwrap();
+
+            create_position!(
+                client,
+                dex,
+                pool_key,
+                i,
+                i + 10,
+                liquidity,
+                pool.sqrt_price,
+                pool.sqrt_price,
+                alice
+            )
+            .unwrap();
+        }
+
+        let bob = ink_e2e::bob();
+        let amount = 187_500;
+        mint!(client, token_x, address_of!(Bob), amount, bob).unwrap();
+        approve!(client, token_x, dex.account_id, amount, bob).unwrap();
+
+        // a swap within the current tick is the baseline for the cost of crossing ticks
+        let slippage = SqrtPrice::new(MIN_SQRT_PRICE);
+        let mut gas = vec![];
+        for amount in [1000, amount] {
+            let swap_amount = TokenAmount::new(amount);
+            let quote_result =
+                quote!(client, dex, pool_key, true, swap_amount, true, slippage).unwrap();
+
+            let mut call_builder = dex.call_builder::<Invariant>();
+            let call = call_builder.swap(pool_key, true, swap_amount, true, slippage);
+            let dry_run = client.call(&bob, &call).dry_run().await?;
+            let gas_consumed = dry_run.exec_result.gas_consumed;
+            assert!(dry_run.return_value().is_ok());
+
+            gas.push((quote_result.ticks.len(), gas_consumed));
+        }
+
+        let (base_crosses, base_gas) = gas[0];
+        let (crosses, cross_gas) = gas[1];
+        assert_eq!(base_crosses, 0);
+        assert_eq!(crosses, 37);
+        assert!(cross_gas.all_gt(base_gas));
+
+        // at this budget a swap crossing MAX_SWAP_STEPS ticks takes under half of the 2s block
+        // ref time and under 2MB of the 5MB proof size
+        const REF_TIME_PER_CROSS: u64 = 5_000_000_000;
+        const PROOF_SIZE_PER_CROSS: u64 = 16 * 1024;
+
+        let crosses = crosses as u64;
+        let ref_time_per_cross = (cross_gas.ref_time() - base_gas.ref_time()) / crosses;
+        let proof_size_per_cross = (cross_gas.proof_size() - base_gas.proof_size()) / crosses;
+        assert!(ref_time_per_cross <= REF_TIME_PER_CROSS);
+        assert!(proof_size_per_cross <= PROOF_SIZE_PER_CROSS);
+
+        Ok(())
+    }
 }
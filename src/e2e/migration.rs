@@ -0,0 +1,268 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey, PoolStatus,
+            STORAGE_VERSION,
+        },
+        math::types::{
+            fee_growth::FeeGrowth, liquidity::Liquidity, percentage::Percentage,
+            seconds_per_liquidity::SecondsPerLiquidity, sqrt_price::calculate_sqrt_price,
+            token_amount::TokenAmount,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::{AccountId, KeyComposer};
+    use ink::scale::Encode;
+    use ink_e2e::ContractsBackend;
+    use legacy_storage::legacy_storage::LegacyStorage;
+    use legacy_storage::legacy_storage::LegacyStorageRef;
+    use test_helpers::{
+        address_of, approve, create_legacy_dex, create_position, create_tokens, fee_tier_exist,
+        get_admin, get_fee_tiers, get_pool, get_pool_keys_with_status, get_position,
+        get_storage_version, migrate,
+    };
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    fn key(struct_name: &str, field_name: &str) -> u32 {
+        KeyComposer::compute_key(struct_name, "", field_name).unwrap()
+    }
+
+    // root cell, pools and pool keys in the layout before storage versioning,
+    // pools without a status, curation and a hook
+    fn legacy_entries(
+        fee_tiers: &[FeeTier],
+        pool_keys: &[PoolKey],
+        admin: AccountId,
+    ) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut entries = vec![(
+            0u32.encode(),
+            (
+                fee_tiers.to_vec(),
+                pool_keys.len() as u16,
+                admin,
+                Percentage::from_scale(1, 2),
+            )
+                .encode(),
+        )];
+
+        for (index, pool_key) in pool_keys.iter().enumerate() {
+            let index = index as u16;
+            let legacy_pool = (
+                Liquidity::new(0),
+                calculate_sqrt_price(0).unwrap(),
+                0i32,
+                FeeGrowth::default(),
+                FeeGrowth::default(),
+                TokenAmount(0),
+                TokenAmount(0),
+                0u64,
+                0u64,
+                admin,
+                SecondsPerLiquidity::new(0),
+            );
+            entries.push((
+                (key("Pools", "pools"), pool_key).encode(),
+                legacy_pool.encode(),
+            ));
+            entries.push((
+                (key("PoolKeys", "pool_keys"), pool_key).encode(),
+                index.encode(),
+            ));
+            entries.push((
+                (key("PoolKeys", "pool_keys_by_index"), index).encode(),
+                pool_key.encode(),
+            ));
+        }
+
+        entries
+    }
+
+    #[ink_e2e::test]
+    async fn test_migrate_legacy_storage(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (token_x, token_y) = create_tokens!(client, 10u128.pow(10), 10u128.pow(10));
+
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let admin = address_of!(Alice);
+        let fee_tiers = vec![
+            FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap(),
+            FeeTier::new(Percentage::from_scale(1, 2), 10).unwrap(),
+        ];
+        let pool_keys: Vec<PoolKey> = fee_tiers
+            .iter()
+            .map(|fee_tier| {
+                PoolKey::new(token_x.account_id, token_y.account_id, *fee_tier).unwrap()
+            })
+            .collect();
+
+        let mut entries = legacy_entries(&fee_tiers, &pool_keys, admin);
+        entries.push((
+            (key("Positions", "positions"), (admin, 0u32)).encode(),
+            (
+                pool_keys[0],
+                Liquidity::new(0),
+                -10i32,
+                10i32,
+                FeeGrowth::default(),
+                FeeGrowth::default(),
+                0u64,
+                TokenAmount(5),
+                TokenAmount(7),
+                0u64,
+                SecondsPerLiquidity::new(0),
+            )
+                .encode(),
+        ));
+        entries.push((
+            (key("Positions", "positions_length"), admin).encode(),
+            1u32.encode(),
+        ));
+        let dex = create_legacy_dex!(client, entries);
+
+        // legacy records are readable before the migration
+        assert_eq!(get_storage_version!(client, dex), 0);
+        assert_eq!(get_admin!(client, dex), admin);
+        assert_eq!(get_fee_tiers!(client, dex).unwrap(), fee_tiers);
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tiers[0]
+        )
+        .unwrap();
+        assert_eq!(pool.status, PoolStatus::Active);
+        assert_eq!(pool.fee_receiver, admin);
+        assert_eq!(pool.hook, None);
+        let position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(position.pool_key, pool_keys[0]);
+        assert_eq!(position.tokens_owed_x, TokenAmount(5));
+        assert_eq!(position.tokens_owed_y, TokenAmount(7));
+        assert_eq!(position.locked_until, 0);
+
+        let result = migrate!(client, dex, 1, bob);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+
+        // one pool per call
+        assert_eq!(migrate!(client, dex, 1, alice), Ok(false));
+        assert_eq!(get_storage_version!(client, dex), 0);
+        assert_eq!(migrate!(client, dex, 1, alice), Ok(false));
+        assert_eq!(get_storage_version!(client, dex), 1);
+
+        // one fee tier per call
+        assert_eq!(migrate!(client, dex, 1, alice), Ok(false));
+        assert_eq!(get_fee_tiers!(client, dex).unwrap(), fee_tiers);
+        assert_eq!(migrate!(client, dex, 1, alice), Ok(true));
+        assert_eq!(get_storage_version!(client, dex), STORAGE_VERSION);
+        assert_eq!(get_fee_tiers!(client, dex).unwrap(), fee_tiers);
+
+        let result = migrate!(client, dex, 1, alice);
+        assert_eq!(result, Err(InvariantError::AlreadyMigrated));
+
+        // the migrated state is usable with the current code
+        let pool_keys_with_status = get_pool_keys_with_status!(client, dex, 2, 0).unwrap();
+        assert_eq!(
+            pool_keys_with_status.0,
+            pool_keys
+                .iter()
+                .map(|pool_key| (*pool_key, PoolStatus::Active))
+                .collect::<Vec<_>>()
+        );
+
+        approve!(client, token_x, dex.account_id, 10u128.pow(10), alice).unwrap();
+        approve!(client, token_y, dex.account_id, 10u128.pow(10), alice).unwrap();
+        let sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_position!(
+            client,
+            dex,
+            pool_keys[1],
+            -10,
+            10,
+            Liquidity::from_integer(1000),
+            sqrt_price,
+            sqrt_price,
+            alice
+        )
+        .unwrap();
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tiers[1]
+        )
+        .unwrap();
+        assert_eq!(pool.liquidity, Liquidity::from_integer(1000));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_fee_tiers_gas_after_migration(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let alice = ink_e2e::alice();
+        let fee_tiers: Vec<FeeTier> = (1..=32)
+            .map(|tick_spacing| {
+                FeeTier::new(Percentage::from_scale(tick_spacing, 4), tick_spacing as u16).unwrap()
+            })
+            .collect();
+        let last_fee_tier = *fee_tiers.last().unwrap();
+
+        let dex = create_legacy_dex!(client, legacy_entries(&fee_tiers, &[], address_of!(Alice)));
+
+        // every call decodes the root cell, before the migration it holds all fee tiers
+        let call_builder = dex.call_builder::<Invariant>();
+        let get_protocol_fee = call_builder.get_protocol_fee();
+        let fee_tier_exist = call_builder.fee_tier_exist(last_fee_tier);
+
+        let get_protocol_fee_before = client
+            .call(&alice, &get_protocol_fee)
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        let fee_tier_exist_before = client
+            .call(&alice, &fee_tier_exist)
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        assert!(fee_tier_exist!(client, dex, last_fee_tier));
+
+        assert_eq!(migrate!(client, dex, 32, alice), Ok(false));
+        assert_eq!(migrate!(client, dex, 32, alice), Ok(true));
+        assert_eq!(get_fee_tiers!(client, dex).unwrap(), fee_tiers);
+
+        let get_protocol_fee_after = client
+            .call(&alice, &get_protocol_fee)
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        let fee_tier_exist_after = client
+            .call(&alice, &fee_tier_exist)
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        assert!(fee_tier_exist!(client, dex, last_fee_tier));
+
+        // the root cell no longer holds the encoded fee tiers, an empty vector takes a single byte
+        let moved_bytes = fee_tiers.encode().len() as u64 - 1;
+        assert!(
+            get_protocol_fee_after.proof_size() + moved_bytes
+                <= get_protocol_fee_before.proof_size()
+        );
+        assert!(get_protocol_fee_after.ref_time() < get_protocol_fee_before.ref_time());
+        assert!(fee_tier_exist_after.proof_size() < fee_tier_exist_before.proof_size());
+
+        Ok(())
+    }
+}
@@ -6,22 +6,41 @@ pub mod change_protocol_fee;
 pub mod claim;
 pub mod constructor;
 pub mod create_pool;
+pub mod create_pool_with_position;
 pub mod cross;
 pub mod cross_both_side;
+pub mod donate;
+pub mod events;
 pub mod get_position_with_associates;
 pub mod get_positions;
 pub mod get_tickmap;
+pub mod incentives;
 pub mod interaction_with_pool_on_removed_fee_tier;
 pub mod limits;
 pub mod liquidity_gap;
 pub mod max_tick_cross;
+pub mod migration;
+pub mod multicall;
 pub mod multiple_swap;
+pub mod pool_creation_fee;
+pub mod pool_curation;
+pub mod pool_hooks;
+pub mod pool_stats;
+pub mod pool_status;
 pub mod position;
+pub mod position_by_amounts;
+pub mod position_history;
 pub mod position_list;
+pub mod position_lock;
 pub mod position_slippage;
 pub mod protocol_fee;
+pub mod referral;
 pub mod remove_fee_tier;
 pub mod set_code;
 pub mod slippage;
+pub mod split_merge_positions;
 pub mod swap;
+pub mod swap_best;
+pub mod swap_partial;
 pub mod swap_route;
+pub mod swap_split;
@@ -0,0 +1,109 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantCall, InvariantCallResult,
+            InvariantError, PoolKey,
+        },
+        invariant::InvariantRef,
+        math::{
+            types::{
+                fee_growth::FeeGrowth,
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MAX_SQRT_PRICE, MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, create_dex, create_pool, create_position,
+        create_tokens, get_pool, get_position, init_basic_pool, init_basic_position,
+        init_basic_swap, init_dex_and_tokens, mint, multicall, swap,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_multicall(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+        init_basic_swap!(client, dex, token_x, token_y);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let alice = ink_e2e::alice();
+
+        let balance_x_before = balance_of!(client, token_x, address_of!(Alice));
+        let result = multicall!(
+            client,
+            dex,
+            vec![
+                InvariantCall::ClaimFee { index: 0 },
+                InvariantCall::RemovePosition { index: 1 },
+            ],
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::PositionNotFound));
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            balance_x_before
+        );
+
+        let swap_amount = TokenAmount::new(10);
+        let results = multicall!(
+            client,
+            dex,
+            vec![
+                InvariantCall::ClaimFee { index: 0 },
+                InvariantCall::Swap {
+                    pool_key,
+                    x_to_y: false,
+                    amount: swap_amount,
+                    by_amount_in: true,
+                    sqrt_price_limit: SqrtPrice::new(MAX_SQRT_PRICE),
+                },
+                InvariantCall::RemovePosition { index: 0 },
+            ],
+            alice
+        )
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0],
+            InvariantCallResult::Amounts(TokenAmount::new(5), TokenAmount::new(0))
+        );
+        match &results[1] {
+            InvariantCallResult::Swap(swap_result) => {
+                assert_eq!(swap_result.amount_in, swap_amount)
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert!(matches!(results[2], InvariantCallResult::Amounts(_, _)));
+
+        let result = get_position!(client, dex, 0, alice);
+        assert_eq!(result, Err(InvariantError::PositionNotFound));
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.liquidity, Liquidity::new(0));
+
+        Ok(())
+    }
+}
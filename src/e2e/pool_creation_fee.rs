@@ -0,0 +1,162 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeAsset, FeeTier, InvariantError, PoolCreationFee,
+        },
+        invariant::InvariantRef,
+        math::types::{
+            percentage::Percentage, sqrt_price::calculate_sqrt_price, token_amount::TokenAmount,
+        },
+    };
+    use decimal::*;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, change_pool_creation_fee, create_dex,
+        create_pool, create_tokens, get_collected_pool_creation_fee, get_pool,
+        get_pool_creation_fee, mint, withdraw_pool_creation_fee,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_pool_creation_fee_in_token(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let dex = create_dex!(client, Percentage::new(0));
+        let (token_x, token_y) = create_tokens!(client, 10u128.pow(10), 10u128.pow(10));
+
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let pool_creation_fee = PoolCreationFee {
+            asset: FeeAsset::Token(token_x.account_id),
+            amount: TokenAmount(500),
+        };
+        let result = change_pool_creation_fee!(client, dex, Some(pool_creation_fee), bob);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+        change_pool_creation_fee!(client, dex, Some(pool_creation_fee), alice).unwrap();
+        assert_eq!(get_pool_creation_fee!(client, dex), Some(pool_creation_fee));
+
+        let result = create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            bob
+        );
+        assert_eq!(result, Err(InvariantError::TransferError));
+
+        mint!(client, token_x, address_of!(Bob), 500, bob).unwrap();
+        approve!(client, token_x, dex.account_id, 500, bob).unwrap();
+        create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            bob
+        )
+        .unwrap();
+        get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(balance_of!(client, token_x, address_of!(Bob)), 0);
+        assert_eq!(
+            get_collected_pool_creation_fee!(client, dex, pool_creation_fee.asset),
+            TokenAmount(500)
+        );
+
+        let result = withdraw_pool_creation_fee!(client, dex, pool_creation_fee.asset, bob);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+
+        let admin_balance = balance_of!(client, token_x, address_of!(Alice));
+        let amount =
+            withdraw_pool_creation_fee!(client, dex, pool_creation_fee.asset, alice).unwrap();
+        assert_eq!(amount, TokenAmount(500));
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Alice)),
+            admin_balance + 500
+        );
+        assert_eq!(
+            get_collected_pool_creation_fee!(client, dex, pool_creation_fee.asset),
+            TokenAmount(0)
+        );
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_pool_creation_fee_native(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let dex = create_dex!(client, Percentage::new(0));
+        let (token_x, token_y) = create_tokens!(client, 10u128.pow(10), 10u128.pow(10));
+
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let pool_creation_fee = PoolCreationFee {
+            asset: FeeAsset::Native,
+            amount: TokenAmount(1000),
+        };
+        change_pool_creation_fee!(client, dex, Some(pool_creation_fee), alice).unwrap();
+
+        let result = create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            bob
+        );
+        assert_eq!(result, Err(InvariantError::InvalidPoolCreationFee));
+
+        create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            bob,
+            1000
+        )
+        .unwrap();
+        assert_eq!(
+            get_collected_pool_creation_fee!(client, dex, FeeAsset::Native),
+            TokenAmount(1000)
+        );
+
+        let amount = withdraw_pool_creation_fee!(client, dex, FeeAsset::Native, alice).unwrap();
+        assert_eq!(amount, TokenAmount(1000));
+        assert_eq!(
+            get_collected_pool_creation_fee!(client, dex, FeeAsset::Native),
+            TokenAmount(0)
+        );
+
+        change_pool_creation_fee!(client, dex, None, alice).unwrap();
+        assert_eq!(get_pool_creation_fee!(client, dex), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey, PoolStatus,
+            TokenStatus,
+        },
+        invariant::InvariantRef,
+        math::types::{percentage::Percentage, sqrt_price::calculate_sqrt_price},
+    };
+    use decimal::*;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, change_pool_curation, change_token_status, create_3_tokens, create_dex,
+        create_pool, get_pool, get_pool_keys_with_status, init_dex_and_3_tokens,
+    };
+    use token::TokenRef;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_pool_curation(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y, token_z) = init_dex_and_3_tokens!(client);
+
+        let admin = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(5, 1), 1).unwrap();
+        let other_fee_tier = FeeTier::new(Percentage::from_scale(5, 1), 2).unwrap();
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+
+        add_fee_tier!(client, dex, fee_tier, admin).unwrap();
+        add_fee_tier!(client, dex, other_fee_tier, admin).unwrap();
+
+        // Denied token
+        {
+            change_token_status!(client, dex, token_z.account_id, TokenStatus::Denied, admin)
+                .unwrap();
+
+            let result = create_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_z.account_id,
+                fee_tier,
+                init_sqrt_price,
+                init_tick,
+                admin
+            );
+            assert_eq!(result, Err(InvariantError::TokenDenied));
+        }
+        // Pool of allowed tokens is curated on creation
+        {
+            change_token_status!(client, dex, token_x.account_id, TokenStatus::Allowed, admin)
+                .unwrap();
+            change_token_status!(client, dex, token_y.account_id, TokenStatus::Allowed, admin)
+                .unwrap();
+
+            create_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                fee_tier,
+                init_sqrt_price,
+                init_tick,
+                admin
+            )
+            .unwrap();
+
+            let pool = get_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                fee_tier
+            )
+            .unwrap();
+            assert!(pool.curated);
+        }
+        // Permissionless pool is not curated
+        {
+            change_token_status!(
+                client,
+                dex,
+                token_y.account_id,
+                TokenStatus::Unlisted,
+                admin
+            )
+            .unwrap();
+
+            let user = ink_e2e::bob();
+            create_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                other_fee_tier,
+                init_sqrt_price,
+                init_tick,
+                user
+            )
+            .unwrap();
+
+            let pool = get_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                other_fee_tier
+            )
+            .unwrap();
+            assert!(!pool.curated);
+        }
+
+        let curated_pool_key =
+            PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let other_pool_key =
+            PoolKey::new(token_x.account_id, token_y.account_id, other_fee_tier).unwrap();
+
+        let pool_keys = get_pool_keys_with_status!(client, dex, 10, 0).unwrap();
+        assert_eq!(pool_keys.0.len(), 2);
+        assert_eq!(pool_keys.1, 2);
+
+        let pool_keys = get_pool_keys_with_status!(client, dex, 10, 0, Some(true)).unwrap();
+        assert_eq!(pool_keys.0, vec![(curated_pool_key, PoolStatus::Active)]);
+        assert_eq!(pool_keys.1, 1);
+
+        let pool_keys = get_pool_keys_with_status!(client, dex, 10, 0, Some(false)).unwrap();
+        assert_eq!(pool_keys.0, vec![(other_pool_key, PoolStatus::Active)]);
+
+        change_pool_curation!(client, dex, other_pool_key, true, admin).unwrap();
+
+        let pool_keys = get_pool_keys_with_status!(client, dex, 10, 0, Some(true)).unwrap();
+        assert_eq!(pool_keys.0.len(), 2);
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_not_admin_change_token_status(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let (dex, token_x, _, _) = init_dex_and_3_tokens!(client);
+
+        let user = ink_e2e::bob();
+        let result =
+            change_token_status!(client, dex, token_x.account_id, TokenStatus::Denied, user);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+
+        Ok(())
+    }
+}
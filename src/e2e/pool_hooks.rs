@@ -0,0 +1,582 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolHook, PoolKey,
+        },
+        invariant::InvariantRef,
+        math::{
+            types::{
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::scale::Encode;
+    use ink_e2e::ContractsBackend;
+    use mock_hooks::mock_hooks::{MockHooks, MockHooksRef};
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, change_liquidity, create_dex,
+        create_mock_hooks, create_pool_with_hook, create_position, create_tokens, get_hook_calls,
+        get_pool, init_dex_and_tokens, merge_positions, remove_pool_hook, remove_position,
+        set_hook_burn_gas, set_hook_fail, set_hook_veto_swaps, split_position, swap,
+    };
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_pool_hook_flags(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        // the hook address is not a contract, so every enabled callback fails
+        let hook = PoolHook::new(address_of!(Charlie), PoolHook::BEFORE_SWAP).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_pool_with_hook!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            hook,
+            alice
+        )
+        .unwrap();
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.hook, Some(hook));
+
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        // position callbacks are disabled
+        create_position!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            Liquidity::from_integer(1000000),
+            pool.sqrt_price,
+            pool.sqrt_price,
+            alice
+        )
+        .unwrap();
+
+        let result = swap!(
+            client,
+            dex,
+            pool_key,
+            true,
+            TokenAmount(1000),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_pool_hook_modify_position(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        let result = create_pool_with_hook!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            PoolHook {
+                address: address_of!(Charlie),
+                flags: 0,
+            },
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::InvalidHookFlags));
+
+        let hook = PoolHook::new(address_of!(Charlie), PoolHook::BEFORE_MODIFY_POSITION).unwrap();
+        create_pool_with_hook!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            hook,
+            alice
+        )
+        .unwrap();
+
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let result = create_position!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            Liquidity::from_integer(1000000),
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_pool_hook_callbacks(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        let hooks = create_mock_hooks!(client);
+        let alice = ink_e2e::alice();
+        let caller = address_of!(Alice);
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let hook = PoolHook::new(hooks.account_id, PoolHook::ALL).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_pool_with_hook!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            hook,
+            alice
+        )
+        .unwrap();
+
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let liquidity = Liquidity::from_integer(1000000);
+        create_position!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            liquidity,
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        )
+        .unwrap();
+        let amount_x = TokenAmount(balance_of!(client, token_x, dex.account_id));
+        let amount_y = TokenAmount(balance_of!(client, token_y, dex.account_id));
+
+        let swap_result = swap!(
+            client,
+            dex,
+            pool_key,
+            true,
+            TokenAmount(1000),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+            alice
+        )
+        .unwrap();
+
+        let (withdrawn_x, withdrawn_y) = remove_position!(client, dex, 0, alice).unwrap();
+
+        let calls = get_hook_calls!(client, hooks);
+        assert_eq!(
+            calls,
+            vec![
+                (
+                    PoolHook::BEFORE_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, liquidity, true).encode()
+                ),
+                (
+                    PoolHook::AFTER_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, liquidity, true, amount_x, amount_y).encode()
+                ),
+                (
+                    PoolHook::BEFORE_SWAP,
+                    (
+                        caller,
+                        pool_key,
+                        true,
+                        TokenAmount(1000),
+                        true,
+                        SqrtPrice::new(MIN_SQRT_PRICE)
+                    )
+                        .encode()
+                ),
+                (
+                    PoolHook::AFTER_SWAP,
+                    (
+                        caller,
+                        pool_key,
+                        true,
+                        swap_result.amount_in,
+                        swap_result.amount_out,
+                        swap_result.fee,
+                        swap_result.target_sqrt_price
+                    )
+                        .encode()
+                ),
+                (
+                    PoolHook::BEFORE_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, liquidity, false).encode()
+                ),
+                (
+                    PoolHook::AFTER_MODIFY_POSITION,
+                    (
+                        caller,
+                        pool_key,
+                        -20i32,
+                        10i32,
+                        liquidity,
+                        false,
+                        withdrawn_x,
+                        withdrawn_y
+                    )
+                        .encode()
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_pool_hook_veto_and_failures(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        let hooks = create_mock_hooks!(client);
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let hook = PoolHook::new(hooks.account_id, PoolHook::ALL).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_pool_with_hook!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            hook,
+            alice
+        )
+        .unwrap();
+
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let liquidity = Liquidity::from_integer(1000000);
+        for _ in 0..2 {
+            create_position!(
+                client,
+                dex,
+                pool_key,
+                -20,
+                10,
+                liquidity,
+                init_sqrt_price,
+                init_sqrt_price,
+                alice
+            )
+            .unwrap();
+        }
+        assert_eq!(get_hook_calls!(client, hooks).len(), 4);
+
+        set_hook_veto_swaps!(client, hooks, true);
+        let result = swap!(
+            client,
+            dex,
+            pool_key,
+            true,
+            TokenAmount(1000),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::SwapVetoedByHook));
+        set_hook_veto_swaps!(client, hooks, false);
+
+        // a failing hook blocks swaps and deposits
+        set_hook_fail!(client, hooks, true);
+        let result = swap!(
+            client,
+            dex,
+            pool_key,
+            true,
+            TokenAmount(1000),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+        let result = create_position!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            liquidity,
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+        let result = change_liquidity!(
+            client,
+            dex,
+            0,
+            Liquidity::from_integer(1),
+            true,
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+        let result = merge_positions!(client, dex, 0, 1, alice);
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+
+        // but not withdrawals
+        change_liquidity!(
+            client,
+            dex,
+            0,
+            Liquidity::from_integer(1),
+            false,
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        )
+        .unwrap();
+        remove_position!(client, dex, 1, alice).unwrap();
+        assert_eq!(get_hook_calls!(client, hooks).len(), 4);
+
+        let result = remove_pool_hook!(client, dex, pool_key, bob);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+        remove_pool_hook!(client, dex, pool_key, alice).unwrap();
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.hook, None);
+        swap!(
+            client,
+            dex,
+            pool_key,
+            true,
+            TokenAmount(1000),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+            alice
+        )
+        .unwrap();
+        assert_eq!(get_hook_calls!(client, hooks).len(), 4);
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_pool_hook_split_and_merge(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        let hooks = create_mock_hooks!(client);
+        let alice = ink_e2e::alice();
+        let caller = address_of!(Alice);
+        let receiver = address_of!(Bob);
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let hook = PoolHook::new(hooks.account_id, PoolHook::ALL).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_pool_with_hook!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            hook,
+            alice
+        )
+        .unwrap();
+
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let liquidity = Liquidity::from_integer(1000000);
+        for _ in 0..2 {
+            create_position!(
+                client,
+                dex,
+                pool_key,
+                -20,
+                10,
+                liquidity,
+                init_sqrt_price,
+                init_sqrt_price,
+                alice
+            )
+            .unwrap();
+        }
+
+        let moved = Liquidity::from_integer(1000);
+        split_position!(client, dex, 0, moved, receiver, alice).unwrap();
+        merge_positions!(client, dex, 0, 1, alice).unwrap();
+
+        // the split moves liquidity to the receiver, the merge from the second position to the first
+        let zero = TokenAmount(0);
+        let calls = get_hook_calls!(client, hooks);
+        assert_eq!(
+            calls[4..],
+            vec![
+                (
+                    PoolHook::BEFORE_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, moved, false).encode()
+                ),
+                (
+                    PoolHook::BEFORE_MODIFY_POSITION,
+                    (receiver, pool_key, -20i32, 10i32, moved, true).encode()
+                ),
+                (
+                    PoolHook::AFTER_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, moved, false, zero, zero).encode()
+                ),
+                (
+                    PoolHook::AFTER_MODIFY_POSITION,
+                    (receiver, pool_key, -20i32, 10i32, moved, true, zero, zero).encode()
+                ),
+                (
+                    PoolHook::BEFORE_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, liquidity, false).encode()
+                ),
+                (
+                    PoolHook::BEFORE_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, liquidity, true).encode()
+                ),
+                (
+                    PoolHook::AFTER_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, liquidity, false, zero, zero).encode()
+                ),
+                (
+                    PoolHook::AFTER_MODIFY_POSITION,
+                    (caller, pool_key, -20i32, 10i32, liquidity, true, zero, zero).encode()
+                ),
+            ][..]
+        );
+
+        // the receiver of a split is checked as a depositor
+        set_hook_fail!(client, hooks, true);
+        let result = split_position!(client, dex, 0, moved, receiver, alice);
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_pool_hook_gas_limit(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        let hooks = create_mock_hooks!(client);
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let hook = PoolHook::new(hooks.account_id, PoolHook::ALL).unwrap();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_pool_with_hook!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            hook,
+            alice
+        )
+        .unwrap();
+
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let liquidity = Liquidity::from_integer(1000000);
+        create_position!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            liquidity,
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        )
+        .unwrap();
+
+        // a hook running out of its gas limit fails the callback but not the withdrawal
+        set_hook_burn_gas!(client, hooks, true);
+        let result = create_position!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            liquidity,
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::HookCallFailed));
+        remove_position!(client, dex, 0, alice).unwrap();
+
+        Ok(())
+    }
+}
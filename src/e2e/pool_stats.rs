@@ -0,0 +1,91 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey, PoolStats,
+        },
+        invariant::InvariantRef,
+        math::{
+            types::{
+                fee_growth::FeeGrowth, liquidity::Liquidity, percentage::Percentage,
+                sqrt_price::calculate_sqrt_price, sqrt_price::SqrtPrice, token_amount::TokenAmount,
+            },
+            MAX_SQRT_PRICE, MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, create_dex, create_pool, create_position,
+        create_tokens, get_pool, get_pool_stats, init_basic_pool, init_basic_position,
+        init_basic_swap, init_dex_and_tokens, mint, swap,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_pool_stats(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        assert_eq!(
+            get_pool_stats!(client, dex, pool_key).unwrap(),
+            PoolStats::default()
+        );
+
+        init_basic_swap!(client, dex, token_x, token_y);
+
+        let stats = get_pool_stats!(client, dex, pool_key).unwrap();
+        assert_eq!(
+            stats,
+            PoolStats {
+                volume_x: TokenAmount(1000),
+                volume_y: TokenAmount(993),
+                lp_fee_x: TokenAmount(5),
+                lp_fee_y: TokenAmount(0),
+                protocol_fee_x: TokenAmount(1),
+                protocol_fee_y: TokenAmount(0),
+                swap_count: 1,
+            }
+        );
+
+        let bob = ink_e2e::bob();
+        approve!(client, token_y, dex.account_id, 993, bob).unwrap();
+        let result = swap!(
+            client,
+            dex,
+            pool_key,
+            false,
+            TokenAmount(500),
+            true,
+            SqrtPrice::new(MAX_SQRT_PRICE),
+            bob
+        )
+        .unwrap();
+
+        let stats_after = get_pool_stats!(client, dex, pool_key).unwrap();
+        assert_eq!(stats_after.swap_count, 2);
+        assert_eq!(stats_after.volume_y, stats.volume_y + result.amount_in);
+        assert_eq!(stats_after.volume_x, stats.volume_x + result.amount_out);
+        assert_eq!(
+            stats_after.lp_fee_y + stats_after.protocol_fee_y,
+            result.fee
+        );
+        assert_eq!(stats_after.lp_fee_x, stats.lp_fee_x);
+
+        let other_fee_tier = FeeTier::new(Percentage::from_scale(1, 2), 10).unwrap();
+        let other_pool_key =
+            PoolKey::new(token_x.account_id, token_y.account_id, other_fee_tier).unwrap();
+        let result = get_pool_stats!(client, dex, other_pool_key);
+        assert_eq!(result, Err(InvariantError::PoolNotFound));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey, PoolStatus,
+            Position,
+        },
+        invariant::InvariantRef,
+        math::{
+            types::{
+                fee_growth::FeeGrowth,
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, change_liquidity, change_pool_status,
+        claim_fee, create_dex, create_pool, create_position, create_tokens, get_all_positions,
+        get_pool, get_pool_keys_with_status, init_basic_pool, init_basic_position, init_basic_swap,
+        init_dex_and_tokens, lock_position, merge_positions, mint, remove_fee_tier,
+        remove_position, split_position, swap, transfer_position,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_wind_down_pool_on_removed_fee_tier(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+        init_basic_swap!(client, dex, token_x, token_y);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let admin = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+
+        remove_fee_tier!(client, dex, fee_tier, admin).unwrap();
+
+        let pool_keys = get_pool_keys_with_status!(client, dex, 1, 0).unwrap();
+        assert_eq!(pool_keys.0, vec![(pool_key, PoolStatus::Active)]);
+
+        // Withdraw only
+        {
+            change_pool_status!(client, dex, pool_key, PoolStatus::WithdrawOnly, admin).unwrap();
+
+            let pool = get_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                fee_tier
+            )
+            .unwrap();
+            assert_eq!(pool.status, PoolStatus::WithdrawOnly);
+
+            let pool_keys = get_pool_keys_with_status!(client, dex, 1, 0).unwrap();
+            assert_eq!(pool_keys.0, vec![(pool_key, PoolStatus::WithdrawOnly)]);
+
+            let amount = 1000;
+            mint!(client, token_x, address_of!(Bob), amount, bob).unwrap();
+            approve!(client, token_x, dex.account_id, amount, bob).unwrap();
+            let result = swap!(
+                client,
+                dex,
+                pool_key,
+                true,
+                TokenAmount(amount),
+                true,
+                SqrtPrice::new(MIN_SQRT_PRICE),
+                bob
+            );
+            assert_eq!(result, Err(InvariantError::PoolNotActive));
+
+            let result = create_position!(
+                client,
+                dex,
+                pool_key,
+                -20,
+                10,
+                Liquidity::from_integer(1000000),
+                pool.sqrt_price,
+                pool.sqrt_price,
+                admin
+            );
+            assert_eq!(result, Err(InvariantError::PoolNotActive));
+
+            let result = change_liquidity!(
+                client,
+                dex,
+                0,
+                Liquidity::from_integer(1000),
+                true,
+                pool.sqrt_price,
+                pool.sqrt_price,
+                admin
+            );
+            assert_eq!(result, Err(InvariantError::PoolNotActive));
+
+            change_liquidity!(
+                client,
+                dex,
+                0,
+                Liquidity::from_integer(1000),
+                false,
+                pool.sqrt_price,
+                pool.sqrt_price,
+                admin
+            )
+            .unwrap();
+
+            let (claimed_x, claimed_y) = claim_fee!(client, dex, 0, admin).unwrap();
+            assert_eq!(claimed_x, TokenAmount(5));
+            assert_eq!(claimed_y, TokenAmount(0));
+        }
+        // Closed
+        {
+            change_pool_status!(client, dex, pool_key, PoolStatus::Closed, admin).unwrap();
+
+            let result = claim_fee!(client, dex, 0, admin);
+            assert_eq!(result, Err(InvariantError::PoolClosed));
+
+            let pool = get_pool!(
+                client,
+                dex,
+                token_x.account_id,
+                token_y.account_id,
+                fee_tier
+            )
+            .unwrap();
+            let result = change_liquidity!(
+                client,
+                dex,
+                0,
+                Liquidity::from_integer(1000),
+                false,
+                pool.sqrt_price,
+                pool.sqrt_price,
+                admin
+            );
+            assert_eq!(result, Err(InvariantError::PoolClosed));
+
+            remove_position!(client, dex, 0, admin).unwrap();
+        }
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_position_operations_on_withdraw_only_and_closed_pool(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let admin = ink_e2e::alice();
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        for _ in 0..2 {
+            create_position!(
+                client,
+                dex,
+                pool_key,
+                -20,
+                10,
+                Liquidity::from_integer(1000000),
+                pool.sqrt_price,
+                pool.sqrt_price,
+                admin
+            )
+            .unwrap();
+        }
+
+        let amount = 1000;
+        let bob = ink_e2e::bob();
+        mint!(client, token_x, address_of!(Bob), amount, bob).unwrap();
+        approve!(client, token_x, dex.account_id, amount, bob).unwrap();
+        swap!(
+            client,
+            dex,
+            pool_key,
+            true,
+            TokenAmount(amount),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+            bob
+        )
+        .unwrap();
+
+        // Withdraw only
+        {
+            change_pool_status!(client, dex, pool_key, PoolStatus::WithdrawOnly, admin).unwrap();
+
+            let (claimed_x, _) = claim_fee!(client, dex, 2, admin).unwrap();
+            assert!(claimed_x.get() > 0);
+
+            let balance_x_before = balance_of!(client, token_x, address_of!(Alice));
+            let (amount_x, _) = remove_position!(client, dex, 2, admin).unwrap();
+            assert!(amount_x.get() > 0);
+            assert_eq!(
+                balance_of!(client, token_x, address_of!(Alice)),
+                balance_x_before + amount_x.get()
+            );
+            assert_eq!(get_all_positions!(client, dex, admin).len(), 2);
+        }
+        // Closed
+        {
+            change_pool_status!(client, dex, pool_key, PoolStatus::Closed, admin).unwrap();
+
+            let result = claim_fee!(client, dex, 0, admin);
+            assert_eq!(result, Err(InvariantError::PoolClosed));
+
+            let result = split_position!(
+                client,
+                dex,
+                0,
+                Liquidity::from_integer(1),
+                address_of!(Bob),
+                admin
+            );
+            assert_eq!(result, Err(InvariantError::PoolClosed));
+
+            let result = merge_positions!(client, dex, 0, 1, admin);
+            assert_eq!(result, Err(InvariantError::PoolClosed));
+
+            let result = lock_position!(client, dex, 0, u64::MAX, admin);
+            assert_eq!(result, Err(InvariantError::PoolClosed));
+
+            let result = transfer_position!(client, dex, 0, address_of!(Bob), admin);
+            assert_eq!(result, Err(InvariantError::PoolClosed));
+
+            let balance_x_before = balance_of!(client, token_x, address_of!(Alice));
+            let (amount_x, _) = remove_position!(client, dex, 1, admin).unwrap();
+            assert!(amount_x.get() > 0);
+            assert_eq!(
+                balance_of!(client, token_x, address_of!(Alice)),
+                balance_x_before + amount_x.get()
+            );
+            remove_position!(client, dex, 0, admin).unwrap();
+            assert_eq!(get_all_positions!(client, dex, admin).len(), 0);
+        }
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_not_admin_change_pool_status(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let user = ink_e2e::bob();
+
+        let result = change_pool_status!(client, dex, pool_key, PoolStatus::Closed, user);
+        assert_eq!(result, Err(InvariantError::NotAdmin));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey},
+        invariant::InvariantRef,
+        math::types::{
+            liquidity::Liquidity, percentage::Percentage, sqrt_price::calculate_sqrt_price,
+            token_amount::TokenAmount,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, approve, balance_of, create_dex, create_pool, create_position_by_amounts,
+        create_tokens, get_pool, get_position, init_basic_pool, init_dex_and_tokens,
+    };
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_create_position_by_amounts(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let alice = ink_e2e::alice();
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+
+        let mint_amount = 10u128.pow(10);
+        approve!(client, token_x, dex.account_id, mint_amount, alice).unwrap();
+        approve!(client, token_y, dex.account_id, mint_amount, alice).unwrap();
+
+        let result = create_position_by_amounts!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            TokenAmount(500),
+            TokenAmount(1000),
+            TokenAmount(501),
+            TokenAmount(0),
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::AmountUnderMinimumDeposit));
+
+        let position = create_position_by_amounts!(
+            client,
+            dex,
+            pool_key,
+            -20,
+            10,
+            TokenAmount(500),
+            TokenAmount(1000),
+            TokenAmount(500),
+            TokenAmount(1000),
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        )
+        .unwrap();
+        assert_eq!(position.liquidity, Liquidity::new(1000300019998));
+        assert_eq!(position.deposited_x, TokenAmount(500));
+        assert_eq!(position.deposited_y, TokenAmount(1000));
+        assert_eq!(get_position!(client, dex, 0, alice).unwrap(), position);
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.liquidity, position.liquidity);
+
+        let dex_x = balance_of!(client, token_x, dex.account_id);
+        let dex_y = balance_of!(client, token_y, dex.account_id);
+        assert_eq!(dex_x, 500);
+        assert_eq!(dex_y, 1000);
+
+        Ok(())
+    }
+}
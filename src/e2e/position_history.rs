@@ -0,0 +1,100 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, PoolKey},
+        invariant::InvariantRef,
+        math::{
+            types::{
+                fee_growth::FeeGrowth,
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, change_liquidity, claim_fee, create_dex,
+        create_pool, create_position, create_tokens, get_pool, get_position, init_basic_pool,
+        init_basic_position, init_basic_swap, init_dex_and_tokens, mint, swap,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_position_history(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+
+        let position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(position.deposited_x, TokenAmount(500));
+        assert_eq!(position.deposited_y, TokenAmount(1000));
+        assert_eq!(position.withdrawn_x, TokenAmount(0));
+        assert_eq!(position.withdrawn_y, TokenAmount(0));
+        assert_eq!(position.claimed_fee_x, TokenAmount(0));
+        assert_eq!(position.claimed_fee_y, TokenAmount(0));
+
+        init_basic_swap!(client, dex, token_x, token_y);
+
+        claim_fee!(client, dex, 0, alice).unwrap();
+
+        let position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(position.claimed_fee_x, TokenAmount(5));
+        assert_eq!(position.claimed_fee_y, TokenAmount(0));
+
+        let user_amount_x_before = balance_of!(client, token_x, address_of!(Alice));
+        let user_amount_y_before = balance_of!(client, token_y, address_of!(Alice));
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        change_liquidity!(
+            client,
+            dex,
+            0,
+            Liquidity::from_integer(500000),
+            false,
+            pool.sqrt_price,
+            pool.sqrt_price,
+            alice
+        )
+        .unwrap();
+
+        let user_amount_x_after = balance_of!(client, token_x, address_of!(Alice));
+        let user_amount_y_after = balance_of!(client, token_y, address_of!(Alice));
+
+        let position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(position.deposited_x, TokenAmount(500));
+        assert_eq!(position.deposited_y, TokenAmount(1000));
+        assert_eq!(
+            position.withdrawn_x,
+            TokenAmount(user_amount_x_after - user_amount_x_before)
+        );
+        assert_eq!(
+            position.withdrawn_y,
+            TokenAmount(user_amount_y_after - user_amount_y_before)
+        );
+        assert_eq!(position.claimed_fee_x, TokenAmount(5));
+
+        Ok(())
+    }
+}
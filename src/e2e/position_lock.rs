@@ -0,0 +1,135 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey},
+        invariant::InvariantRef,
+        math::{
+            types::{
+                fee_growth::FeeGrowth,
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, change_liquidity, claim_fee, create_dex,
+        create_pool, create_position, create_tokens, get_locked_positions, get_pool, get_position,
+        init_basic_pool, init_basic_position, init_basic_swap, init_dex_and_tokens, lock_position,
+        mint, remove_position, split_position, swap, transfer_position,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_locked_position(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+        init_basic_swap!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let position = get_position!(client, dex, 0, alice).unwrap();
+        let created_at = position.created_at / 1000;
+
+        let result = lock_position!(client, dex, 0, created_at, alice);
+        assert_eq!(result, Err(InvariantError::InvalidLockTime));
+
+        lock_position!(client, dex, 0, created_at + 3600, alice).unwrap();
+        let result = lock_position!(client, dex, 0, created_at + 1800, alice);
+        assert_eq!(result, Err(InvariantError::InvalidLockTime));
+        lock_position!(client, dex, 0, created_at + 7200, alice).unwrap();
+
+        let position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(position.locked_until, created_at + 7200);
+
+        let result = remove_position!(client, dex, 0, alice);
+        assert_eq!(result, Err(InvariantError::PositionLocked));
+        let result = transfer_position!(client, dex, 0, address_of!(Bob), alice);
+        assert_eq!(result, Err(InvariantError::PositionLocked));
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        let result = change_liquidity!(
+            client,
+            dex,
+            0,
+            Liquidity::from_integer(1000),
+            false,
+            pool.sqrt_price,
+            pool.sqrt_price,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::PositionLocked));
+        change_liquidity!(
+            client,
+            dex,
+            0,
+            Liquidity::from_integer(1000),
+            true,
+            pool.sqrt_price,
+            pool.sqrt_price,
+            alice
+        )
+        .unwrap();
+
+        let (claimed_x, _) = claim_fee!(client, dex, 0, alice).unwrap();
+        assert_eq!(claimed_x, TokenAmount(5));
+
+        let locked = get_locked_positions!(client, dex, pool_key, 10, 0);
+        assert_eq!(locked.len(), 1);
+        assert_eq!(locked[0].0, address_of!(Alice));
+        assert_eq!(locked[0].1, 0);
+        assert_eq!(locked[0].2.locked_until, created_at + 7200);
+
+        let liquidity = get_position!(client, dex, 0, alice).unwrap().liquidity;
+        let result = split_position!(
+            client,
+            dex,
+            0,
+            Liquidity::from_integer(1000),
+            address_of!(Bob),
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::PositionLocked));
+        let result = split_position!(
+            client,
+            dex,
+            0,
+            Liquidity::from_integer(1000),
+            address_of!(Alice),
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::PositionLocked));
+
+        let position = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(position.liquidity, liquidity);
+        let bob = ink_e2e::bob();
+        assert_eq!(
+            get_position!(client, dex, 0, bob),
+            Err(InvariantError::PositionNotFound)
+        );
+        let locked = get_locked_positions!(client, dex, pool_key, 10, 0);
+        assert_eq!(locked.len(), 1);
+
+        Ok(())
+    }
+}
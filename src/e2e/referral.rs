@@ -0,0 +1,193 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey, Referral,
+            SwapEvent, MAX_REFERRAL_FEE,
+        },
+        invariant::InvariantRef,
+        math::{
+            types::{
+                liquidity::Liquidity, percentage::Percentage, sqrt_price::calculate_sqrt_price,
+                sqrt_price::SqrtPrice, token_amount::TokenAmount,
+            },
+            MAX_SQRT_PRICE, MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::scale::Decode;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, claim_referral_fee, create_dex, create_pool,
+        create_position, create_tokens, get_pool, get_pool_stats, get_referral_fee, init_basic_pool,
+        init_basic_position, init_dex_and_tokens, mint, quote, swap_with_referral,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_referral_fee(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let bob = ink_e2e::bob();
+        let charlie = ink_e2e::charlie();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let referral = Referral {
+            referrer: address_of!(Charlie),
+            fee: MAX_REFERRAL_FEE,
+        };
+
+        mint!(client, token_x, address_of!(Bob), 1000, bob).unwrap();
+        mint!(client, token_y, address_of!(Bob), 1000, bob).unwrap();
+        approve!(client, token_x, dex.account_id, 1000, bob).unwrap();
+        approve!(client, token_y, dex.account_id, 1000, bob).unwrap();
+
+        // by amount in: the referral fee is taken from the amount out
+        let amount = TokenAmount(1000);
+        let sqrt_price_limit = SqrtPrice::new(MIN_SQRT_PRICE);
+        let quote = quote!(client, dex, pool_key, true, amount, true, sqrt_price_limit).unwrap();
+        let referral_fee = quote.amount_out.big_mul(MAX_REFERRAL_FEE);
+        assert!(!referral_fee.is_zero());
+        let stats_before = get_pool_stats!(client, dex, pool_key).unwrap();
+
+        let mut call_builder = dex.call_builder::<Invariant>();
+        let call = call_builder.swap_with_referral(
+            pool_key,
+            true,
+            amount,
+            true,
+            sqrt_price_limit,
+            referral,
+        );
+        let submitted = client
+            .call(&bob, &call)
+            .extra_gas_portion(1000)
+            .submit()
+            .await
+            .unwrap();
+        let events = submitted.contract_emitted_events()?;
+        let result = submitted.return_value().unwrap();
+        assert_eq!(result.amount_in, amount);
+        assert_eq!(result.amount_out, quote.amount_out - referral_fee);
+
+        // the swap event and the pool stats report the amounts after the referral fee
+        let event = events
+            .iter()
+            .filter(|event| event.event.contract == dex.account_id)
+            .last()
+            .unwrap();
+        let swap_event = SwapEvent::decode(&mut &event.event.data[..])?;
+        assert_eq!(swap_event.amount_in, result.amount_in);
+        assert_eq!(swap_event.amount_out, result.amount_out);
+        let stats = get_pool_stats!(client, dex, pool_key).unwrap();
+        assert_eq!(stats.volume_x, stats_before.volume_x + result.amount_in);
+        assert_eq!(stats.volume_y, stats_before.volume_y + result.amount_out);
+        assert_eq!(balance_of!(client, token_x, address_of!(Bob)), 0);
+        assert_eq!(
+            balance_of!(client, token_y, address_of!(Bob)),
+            1000 + result.amount_out.get()
+        );
+        assert_eq!(
+            get_referral_fee!(client, dex, address_of!(Charlie), token_y.account_id),
+            referral_fee
+        );
+
+        // by amount out: the referral fee is added to the amount in
+        let amount = TokenAmount(500);
+        let sqrt_price_limit = SqrtPrice::new(MAX_SQRT_PRICE);
+        let quote = quote!(
+            client,
+            dex,
+            pool_key,
+            false,
+            amount,
+            false,
+            sqrt_price_limit
+        )
+        .unwrap();
+        let referral_fee_in = quote.amount_in.big_mul_up(MAX_REFERRAL_FEE);
+        assert!(!referral_fee_in.is_zero());
+
+        let balance_y_before = balance_of!(client, token_y, address_of!(Bob));
+        let result = swap_with_referral!(
+            client,
+            dex,
+            pool_key,
+            false,
+            amount,
+            false,
+            sqrt_price_limit,
+            referral,
+            bob
+        )
+        .unwrap();
+        assert_eq!(result.amount_out, amount);
+        assert_eq!(result.amount_in, quote.amount_in + referral_fee_in);
+        let stats_after = get_pool_stats!(client, dex, pool_key).unwrap();
+        assert_eq!(stats_after.volume_y, stats.volume_y + result.amount_in);
+        assert_eq!(stats_after.volume_x, stats.volume_x + result.amount_out);
+        assert_eq!(
+            balance_of!(client, token_y, address_of!(Bob)),
+            balance_y_before - result.amount_in.get()
+        );
+        assert_eq!(balance_of!(client, token_x, address_of!(Bob)), amount.get());
+        assert_eq!(
+            get_referral_fee!(client, dex, address_of!(Charlie), token_y.account_id),
+            referral_fee + referral_fee_in
+        );
+
+        let claimed = claim_referral_fee!(client, dex, token_y.account_id, charlie).unwrap();
+        assert_eq!(claimed, referral_fee + referral_fee_in);
+        assert_eq!(
+            balance_of!(client, token_y, address_of!(Charlie)),
+            claimed.get()
+        );
+        assert_eq!(
+            get_referral_fee!(client, dex, address_of!(Charlie), token_y.account_id),
+            TokenAmount(0)
+        );
+
+        let result = claim_referral_fee!(client, dex, token_y.account_id, charlie);
+        assert_eq!(result, Err(InvariantError::AmountIsZero));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_referral_fee_above_limit(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let referral = Referral {
+            referrer: address_of!(Charlie),
+            fee: MAX_REFERRAL_FEE + Percentage::new(1),
+        };
+
+        approve!(client, token_x, dex.account_id, 1000, alice).unwrap();
+        let result = swap_with_referral!(
+            client,
+            dex,
+            pool_key,
+            true,
+            TokenAmount(1000),
+            true,
+            SqrtPrice::new(MIN_SQRT_PRICE),
+            referral,
+            alice
+        );
+        assert_eq!(result, Err(InvariantError::InvalidReferralFee));
+
+        Ok(())
+    }
+}
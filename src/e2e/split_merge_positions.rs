@@ -0,0 +1,175 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey, Position,
+        },
+        invariant::InvariantRef,
+        math::{
+            types::{
+                fee_growth::FeeGrowth,
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink::primitives::AccountId;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, claim_fee, create_dex, create_pool,
+        create_position, create_tokens, get_all_positions, get_pool, get_position, init_basic_pool,
+        init_basic_position, init_basic_swap, init_dex_and_tokens, merge_positions, mint,
+        split_position, swap,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::TokenRef;
+    use token::PSP22;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_split_position(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+        init_basic_swap!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let bob = ink_e2e::bob();
+        let position = get_position!(client, dex, 0, alice).unwrap();
+
+        let result = split_position!(client, dex, 0, position.liquidity, address_of!(Bob), alice);
+        assert_eq!(result, Err(InvariantError::InvalidSplitLiquidity));
+        let result = split_position!(client, dex, 0, Liquidity::new(0), address_of!(Bob), alice);
+        assert_eq!(result, Err(InvariantError::InvalidSplitLiquidity));
+
+        let split_liquidity = Liquidity::from_integer(250000);
+        let receiver_index =
+            split_position!(client, dex, 0, split_liquidity, address_of!(Bob), alice).unwrap();
+        assert_eq!(receiver_index, 0);
+
+        let alice_position = get_position!(client, dex, 0, alice).unwrap();
+        let bob_position = get_position!(client, dex, 0, bob).unwrap();
+        assert_eq!(
+            alice_position.liquidity,
+            position.liquidity - split_liquidity
+        );
+        assert_eq!(bob_position.liquidity, split_liquidity);
+        assert_eq!(bob_position.pool_key, position.pool_key);
+        assert_eq!(bob_position.lower_tick_index, position.lower_tick_index);
+        assert_eq!(bob_position.upper_tick_index, position.upper_tick_index);
+        assert_eq!(
+            bob_position.fee_growth_inside_x,
+            alice_position.fee_growth_inside_x
+        );
+        // 5 tokens of fee accrued by the position are shared proportionally to liquidity
+        assert_eq!(alice_position.tokens_owed_x, TokenAmount(4));
+        assert_eq!(bob_position.tokens_owed_x, TokenAmount(1));
+
+        let bob_balance_before = balance_of!(client, token_x, address_of!(Bob));
+        let (claimed_x, _) = claim_fee!(client, dex, 0, bob).unwrap();
+        assert_eq!(claimed_x, TokenAmount(1));
+        assert_eq!(
+            balance_of!(client, token_x, address_of!(Bob)),
+            bob_balance_before + 1
+        );
+
+        let (claimed_x, _) = claim_fee!(client, dex, 0, alice).unwrap();
+        assert_eq!(claimed_x, TokenAmount(4));
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_merge_positions(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        let first = get_position!(client, dex, 0, alice).unwrap();
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+
+        create_position!(
+            client,
+            dex,
+            pool_key,
+            first.lower_tick_index,
+            first.upper_tick_index,
+            Liquidity::from_integer(500000),
+            pool.sqrt_price,
+            pool.sqrt_price,
+            alice
+        )
+        .unwrap();
+        create_position!(
+            client,
+            dex,
+            pool_key,
+            first.lower_tick_index,
+            first.upper_tick_index + 10,
+            Liquidity::from_integer(500000),
+            pool.sqrt_price,
+            pool.sqrt_price,
+            alice
+        )
+        .unwrap();
+        let second = get_position!(client, dex, 1, alice).unwrap();
+
+        let result = merge_positions!(client, dex, 0, 0, alice);
+        assert_eq!(result, Err(InvariantError::PositionsNotMergeable));
+        let result = merge_positions!(client, dex, 0, 2, alice);
+        assert_eq!(result, Err(InvariantError::PositionsNotMergeable));
+        let result = merge_positions!(client, dex, 0, 3, alice);
+        assert_eq!(result, Err(InvariantError::PositionNotFound));
+
+        let index = merge_positions!(client, dex, 0, 1, alice).unwrap();
+        assert_eq!(index, 0);
+
+        let positions = get_all_positions!(client, dex, alice);
+        assert_eq!(positions.len(), 2);
+        let merged = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(merged.liquidity, first.liquidity + second.liquidity);
+        assert_eq!(merged.deposited_x, first.deposited_x + second.deposited_x);
+        assert_eq!(merged.deposited_y, first.deposited_y + second.deposited_y);
+        assert_eq!(merged.created_at, first.created_at);
+
+        // merging into the last position keeps it under the freed index
+        create_position!(
+            client,
+            dex,
+            pool_key,
+            first.lower_tick_index,
+            first.upper_tick_index,
+            Liquidity::from_integer(500000),
+            pool.sqrt_price,
+            pool.sqrt_price,
+            alice
+        )
+        .unwrap();
+        let index = merge_positions!(client, dex, 2, 0, alice).unwrap();
+        assert_eq!(index, 0);
+        let merged = get_position!(client, dex, 0, alice).unwrap();
+        assert_eq!(
+            merged.liquidity,
+            first.liquidity + second.liquidity + Liquidity::from_integer(500000)
+        );
+
+        Ok(())
+    }
+}
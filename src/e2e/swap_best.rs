@@ -0,0 +1,185 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey},
+        invariant::InvariantRef,
+        math::{
+            types::{
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MIN_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, create_dex, create_pool, create_position,
+        create_tokens, get_pool, init_basic_pool, init_basic_position, init_dex_and_tokens, mint,
+        quote_best, swap_best,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_swap_best(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(1, 3), 1).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            alice
+        )
+        .unwrap();
+
+        let best_pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        create_position!(
+            client,
+            dex,
+            best_pool_key,
+            -10,
+            10,
+            Liquidity::from_integer(10000000),
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        )
+        .unwrap();
+
+        let amount = 1000;
+        let swap_amount = TokenAmount::new(amount);
+        let (pool_key, quote_result) = quote_best!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            swap_amount,
+            true
+        )
+        .unwrap();
+        assert_eq!(pool_key, best_pool_key);
+
+        let bob = ink_e2e::bob();
+        mint!(client, token_x, address_of!(Bob), amount, bob).unwrap();
+        approve!(client, token_x, dex.account_id, amount, bob).unwrap();
+
+        let result = swap_best!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            swap_amount,
+            true,
+            quote_result.amount_out + TokenAmount::new(1),
+            bob
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            InvariantError::AmountUnderMinimumAmountOut
+        );
+
+        // the result calculated while picking the pool is settled without calculating it again
+        let mut call_builder = dex.call_builder::<Invariant>();
+        let quote_best_gas = client
+            .call(
+                &bob,
+                &call_builder.quote_best(token_x.account_id, token_y.account_id, swap_amount, true),
+            )
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        let swap_gas = client
+            .call(
+                &bob,
+                &call_builder.swap(
+                    best_pool_key,
+                    true,
+                    swap_amount,
+                    true,
+                    SqrtPrice::new(MIN_SQRT_PRICE),
+                ),
+            )
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        let swap_best_gas = client
+            .call(
+                &bob,
+                &call_builder.swap_best(
+                    token_x.account_id,
+                    token_y.account_id,
+                    swap_amount,
+                    true,
+                    quote_result.amount_out,
+                ),
+            )
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        // every dry run pays for the call itself, a query without work offsets the one in the sum
+        let base_gas = client
+            .call(&bob, &call_builder.get_protocol_fee())
+            .dry_run()
+            .await?
+            .exec_result
+            .gas_consumed;
+        assert!(
+            swap_best_gas.ref_time() + base_gas.ref_time()
+                < quote_best_gas.ref_time() + swap_gas.ref_time()
+        );
+
+        let (pool_key, swap_result) = swap_best!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            swap_amount,
+            true,
+            quote_result.amount_out,
+            bob
+        )
+        .unwrap();
+        assert_eq!(pool_key, best_pool_key);
+        assert_eq!(swap_result.amount_in, swap_amount);
+        assert_eq!(swap_result.amount_out, quote_result.amount_out);
+
+        assert_eq!(balance_of!(client, token_x, address_of!(Bob)), 0);
+        assert_eq!(
+            balance_of!(client, token_y, address_of!(Bob)),
+            quote_result.amount_out.get()
+        );
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.sqrt_price, quote_result.target_sqrt_price);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey},
+        invariant::InvariantRef,
+        math::{
+            types::{
+                liquidity::Liquidity,
+                percentage::Percentage,
+                sqrt_price::{calculate_sqrt_price, SqrtPrice},
+                token_amount::TokenAmount,
+            },
+            MAX_SQRT_PRICE,
+        },
+    };
+    use decimal::*;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, create_dex, create_pool, create_position,
+        create_tokens, get_pool, init_slippage_dex_and_tokens, init_slippage_pool_with_liquidity,
+        quote, quote_partial, swap_partial,
+    };
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_swap_partial_stops_at_limit(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let alice = ink_e2e::alice();
+        let (dex, token_x, token_y) = init_slippage_dex_and_tokens!(client);
+        let pool_key = init_slippage_pool_with_liquidity!(client, dex, token_x, token_y);
+        let amount = 10u128.pow(8);
+        let swap_amount = TokenAmount::new(amount);
+        approve!(client, token_y, dex.account_id, amount, alice).unwrap();
+
+        let full_target_sqrt_price = quote!(
+            client,
+            dex,
+            pool_key,
+            false,
+            swap_amount,
+            true,
+            SqrtPrice::new(MAX_SQRT_PRICE)
+        )
+        .unwrap()
+        .target_sqrt_price;
+        let sqrt_price_limit = SqrtPrice::new(
+            (full_target_sqrt_price.get() + calculate_sqrt_price(0).unwrap().get()) / 2,
+        );
+
+        let result = quote!(
+            client,
+            dex,
+            pool_key,
+            false,
+            swap_amount,
+            true,
+            sqrt_price_limit
+        );
+        assert_eq!(result.unwrap_err(), InvariantError::PriceLimitReached);
+
+        let quote_result = quote_partial!(
+            client,
+            dex,
+            pool_key,
+            false,
+            swap_amount,
+            true,
+            sqrt_price_limit
+        )
+        .unwrap();
+        assert_eq!(quote_result.target_sqrt_price, sqrt_price_limit);
+        assert!(!quote_result.remaining_amount.is_zero());
+        assert_eq!(
+            quote_result.amount_in + quote_result.remaining_amount,
+            swap_amount
+        );
+
+        let balance_y_before = balance_of!(client, token_y, address_of!(Alice));
+        let balance_x_before = balance_of!(client, token_x, address_of!(Alice));
+
+        let swap_result = swap_partial!(
+            client,
+            dex,
+            pool_key,
+            false,
+            swap_amount,
+            true,
+            sqrt_price_limit,
+            alice
+        )
+        .unwrap();
+        assert_eq!(swap_result.amount_in, quote_result.amount_in);
+        assert_eq!(swap_result.amount_out, quote_result.amount_out);
+        assert_eq!(swap_result.remaining_amount, quote_result.remaining_amount);
+
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            pool_key.fee_tier
+        )
+        .unwrap();
+        assert_eq!(pool.sqrt_price, sqrt_price_limit);
+
+        let balance_y_after = balance_of!(client, token_y, address_of!(Alice));
+        let balance_x_after = balance_of!(client, token_x, address_of!(Alice));
+        assert_eq!(
+            balance_y_before - balance_y_after,
+            swap_result.amount_in.get()
+        );
+        assert_eq!(
+            balance_x_after - balance_x_before,
+            swap_result.amount_out.get()
+        );
+
+        Ok(())
+    }
+
+    #[ink_e2e::test]
+    async fn test_swap_partial_fills_whole_amount(
+        mut client: ink_e2e::Client<C, E>,
+    ) -> E2EResult<()> {
+        let alice = ink_e2e::alice();
+        let (dex, token_x, token_y) = init_slippage_dex_and_tokens!(client);
+        let pool_key = init_slippage_pool_with_liquidity!(client, dex, token_x, token_y);
+        let amount = 10u128.pow(8);
+        let swap_amount = TokenAmount::new(amount);
+        approve!(client, token_y, dex.account_id, amount, alice).unwrap();
+
+        let sqrt_price_limit = SqrtPrice::new(MAX_SQRT_PRICE);
+        let quote_result = quote!(
+            client,
+            dex,
+            pool_key,
+            false,
+            swap_amount,
+            true,
+            sqrt_price_limit
+        )
+        .unwrap();
+
+        let swap_result = swap_partial!(
+            client,
+            dex,
+            pool_key,
+            false,
+            swap_amount,
+            true,
+            sqrt_price_limit,
+            alice
+        )
+        .unwrap();
+        assert_eq!(swap_result.amount_in, swap_amount);
+        assert_eq!(swap_result.amount_out, quote_result.amount_out);
+        assert_eq!(swap_result.remaining_amount, TokenAmount::new(0));
+
+        Ok(())
+    }
+}
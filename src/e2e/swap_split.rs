@@ -0,0 +1,145 @@
+#[cfg(test)]
+pub mod e2e_tests {
+    use crate::invariant::Invariant;
+    use crate::{
+        contracts::{
+            entrypoints::InvariantEntrypoints, FeeTier, InvariantError, PoolKey, SplitRoute,
+            SwapHop,
+        },
+        invariant::InvariantRef,
+        math::types::{
+            liquidity::Liquidity, percentage::Percentage, sqrt_price::calculate_sqrt_price,
+            token_amount::TokenAmount,
+        },
+    };
+    use decimal::*;
+    use ink_e2e::ContractsBackend;
+    use test_helpers::{
+        add_fee_tier, address_of, approve, balance_of, create_dex, create_pool, create_position,
+        create_tokens, get_pool, init_basic_pool, init_basic_position, init_dex_and_tokens, mint,
+        quote_split, swap_split,
+    };
+    use token::PSP22Mintable;
+    use token::Token;
+    use token::{TokenRef, PSP22};
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[ink_e2e::test]
+    async fn test_swap_split(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        let (dex, token_x, token_y) = init_dex_and_tokens!(client);
+        init_basic_pool!(client, dex, token_x, token_y);
+        init_basic_position!(client, dex, token_x, token_y);
+
+        let alice = ink_e2e::alice();
+        let fee_tier = FeeTier::new(Percentage::from_scale(1, 3), 1).unwrap();
+        add_fee_tier!(client, dex, fee_tier, alice).unwrap();
+
+        let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+        create_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier,
+            init_sqrt_price,
+            0,
+            alice
+        )
+        .unwrap();
+
+        let basic_pool_key = PoolKey::new(
+            token_x.account_id,
+            token_y.account_id,
+            FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap(),
+        )
+        .unwrap();
+        let pool_key = PoolKey::new(token_x.account_id, token_y.account_id, fee_tier).unwrap();
+        create_position!(
+            client,
+            dex,
+            pool_key,
+            -10,
+            10,
+            Liquidity::from_integer(10000000),
+            init_sqrt_price,
+            init_sqrt_price,
+            alice
+        )
+        .unwrap();
+
+        let amount = 1000;
+        let routes = vec![
+            SplitRoute {
+                amount_in: TokenAmount::new(300),
+                swaps: vec![SwapHop {
+                    pool_key: basic_pool_key,
+                    x_to_y: true,
+                }],
+            },
+            SplitRoute {
+                amount_in: TokenAmount::new(700),
+                swaps: vec![SwapHop {
+                    pool_key,
+                    x_to_y: true,
+                }],
+            },
+        ];
+
+        let amounts_out = quote_split!(client, dex, routes.clone()).unwrap();
+        assert_eq!(amounts_out.len(), 2);
+        let expected_amount_out = amounts_out[0] + amounts_out[1];
+
+        let bob = ink_e2e::bob();
+        mint!(client, token_x, address_of!(Bob), amount, bob).unwrap();
+        approve!(client, token_x, dex.account_id, amount, bob).unwrap();
+
+        let result = swap_split!(
+            client,
+            dex,
+            routes.clone(),
+            expected_amount_out + TokenAmount::new(1),
+            Percentage::new(0),
+            bob
+        );
+        assert_eq!(result, Err(InvariantError::AmountUnderMinimumAmountOut));
+
+        let result = swap_split!(
+            client,
+            dex,
+            routes,
+            expected_amount_out,
+            Percentage::new(0),
+            bob
+        )
+        .unwrap();
+        assert_eq!(result, amounts_out);
+
+        assert_eq!(balance_of!(client, token_x, address_of!(Bob)), 0);
+        assert_eq!(
+            balance_of!(client, token_y, address_of!(Bob)),
+            expected_amount_out.get()
+        );
+
+        let basic_pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            basic_pool_key.fee_tier
+        )
+        .unwrap();
+        let pool = get_pool!(
+            client,
+            dex,
+            token_x.account_id,
+            token_y.account_id,
+            fee_tier
+        )
+        .unwrap();
+        assert!(basic_pool.sqrt_price < init_sqrt_price);
+        assert!(pool.sqrt_price < init_sqrt_price);
+
+        Ok(())
+    }
+}
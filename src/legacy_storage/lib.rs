@@ -0,0 +1,53 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Test fixture writing storage in the layout of an older contract version
+/// and upgrading itself with `set_code`, used by the migration e2e tests.
+#[ink::contract]
+pub mod legacy_storage {
+    use ink::prelude::vec::Vec;
+    use ink::scale::{Decode, Encode, Error, Input, Output};
+
+    /// Bytes encoded as they are, without a length prefix.
+    pub struct RawBytes(pub Vec<u8>);
+
+    impl Encode for RawBytes {
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+
+        fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(&self.0);
+        }
+    }
+
+    impl Decode for RawBytes {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+            let mut bytes = ink::prelude::vec![0; input.remaining_len()?.unwrap_or_default()];
+            input.read(&mut bytes)?;
+            Ok(Self(bytes))
+        }
+    }
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct LegacyStorage {}
+
+    impl LegacyStorage {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Writes raw `(key, value)` storage entries and changes the code of the contract.
+        /// The message does not write its own storage back, so the root cell can be overwritten.
+        #[ink(message)]
+        pub fn set_code_with_storage(&self, entries: Vec<(Vec<u8>, Vec<u8>)>, code_hash: Hash) {
+            for (key, value) in entries {
+                ink::env::set_contract_storage(&RawBytes(key), &RawBytes(value));
+            }
+
+            ink::env::set_code_hash::<ink::env::DefaultEnvironment>(&code_hash)
+                .expect("code hash does not exist");
+        }
+    }
+}
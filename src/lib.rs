@@ -7,17 +7,32 @@ pub mod math;
 
 #[ink::contract]
 pub mod invariant {
+    use crate::contracts::logic::math::get_liquidity;
     use crate::contracts::{
-        get_chunk_lookup_bit, get_chunk_lookup_index, tick_to_position, CalculateSwapResult,
-        ChangeLiquidityEvent, CreatePositionEvent, CrossTickEvent, FeeTier, FeeTiers,
-        InvariantConfig, InvariantEntrypoints, LiquidityTick, Pool, PoolKey, PoolKeys, Pools,
-        Position, Positions, QuoteResult, RemovePositionEvent, SwapEvent, SwapHop, Tick, Tickmap,
-        Ticks, UpdatePoolTick, CHUNK_LOOKUP_SIZE, CHUNK_SIZE, LIQUIDITY_TICK_LIMIT,
-        MAX_TICKMAP_QUERY_SIZE,
+        get_chunk_lookup_bit, get_chunk_lookup_index, tick_to_position, AddFeeTierEvent,
+        CachedTickmap, CalculateSwapResult, ChangeAdminEvent, ChangeDefaultFeeReceiverEvent,
+        ChangeFeeReceiverEvent, ChangeLiquidityEvent, ChangeMaxSwapStepsEvent,
+        ChangePoolCreationFeeEvent, ChangePoolCurationEvent, ChangePoolStatusEvent,
+        ChangeProtocolFeeEvent, ChangeTokenStatusEvent, ClaimFeeEvent, ClaimIncentiveRewardEvent,
+        ClaimReferralFeeEvent, CreateIncentiveEvent, CreatePoolEvent, CreatePositionEvent,
+        CrossTickEvent, DonateEvent, EndIncentiveEvent, FeeAsset, FeeTier, FeeTiers, Incentive,
+        Incentives, InvariantCall, InvariantCallResult, InvariantConfig, InvariantEntrypoints,
+        InvariantHooks, LiquidityTick, LockPositionEvent, LockedPositions, MergePositionsEvent,
+        MigrateEvent, Pool, PoolCreationFee, PoolCreationFees, PoolHook, PoolKey, PoolKeys,
+        PoolStatistics, PoolStats, PoolStatus, Pools, Position, Positions, QuoteResult, Referral,
+        ReferralFeeEvent, ReferralFees, RemoveFeeTierEvent, RemovePoolHookEvent,
+        RemovePositionEvent, SetCodeEvent, SplitPositionEvent, SplitRoute, Stake,
+        StakePositionEvent, Stakes, SwapEvent, SwapHop, Tick, Tickmap, Ticks, TokenStatus,
+        TokenStatuses, TransferPositionEvent, UnstakePositionEvent, UpdatePoolTick,
+        WithdrawPoolCreationFeeEvent, WithdrawProtocolFeeEvent, CHUNK_LOOKUP_SIZE, CHUNK_SIZE,
+        LIQUIDITY_TICK_LIMIT, MAX_POOL_KEYS_WITH_STATUS_RETURNED, MAX_POSITIONS_RETURNED,
+        MAX_REFERRAL_FEE, MAX_TICKMAP_QUERY_SIZE, STORAGE_VERSION,
     };
     use crate::math::calculate_min_amount_out;
     use crate::math::check_tick;
+    use crate::math::log::get_tick_at_sqrt_price;
     use crate::math::percentage::Percentage;
+    use crate::math::seconds_per_liquidity::SecondsPerLiquidity;
     use crate::math::sqrt_price::SqrtPrice;
     use crate::math::sqrt_price::{get_max_tick, get_min_tick};
     use crate::math::token_amount::TokenAmount;
@@ -34,10 +49,10 @@ pub mod invariant {
     use ink::prelude::vec;
     use ink::prelude::vec::Vec;
     use token::{PSP22Error, PSP22};
-    use traceable_result::unwrap;
 
     type PSP22Wrapper = contract_ref!(PSP22);
     type WrappedAZEROWrapper = contract_ref!(WrappedAZERO);
+    type InvariantHooksWrapper = contract_ref!(InvariantHooks);
 
     #[ink::trait_definition]
     pub trait WrappedAZERO {
@@ -56,6 +71,13 @@ pub mod invariant {
         ticks: Ticks,
         fee_tiers: FeeTiers,
         pool_keys: PoolKeys,
+        token_statuses: TokenStatuses,
+        incentives: Incentives,
+        stakes: Stakes,
+        locked_positions: LockedPositions,
+        referral_fees: ReferralFees,
+        pool_creation_fees: PoolCreationFees,
+        pool_statistics: PoolStatistics,
         config: InvariantConfig,
     }
 
@@ -66,6 +88,8 @@ pub mod invariant {
                 config: InvariantConfig {
                     admin: Self::env().caller(),
                     protocol_fee,
+                    default_fee_receiver: Self::env().caller(),
+                    ..InvariantConfig::default()
                 },
                 ..Self::default()
             }
@@ -95,6 +119,7 @@ pub mod invariant {
             amount: TokenAmount,
             by_amount_in: bool,
             sqrt_price_limit: SqrtPrice,
+            partial_fill: bool,
         ) -> Result<CalculateSwapResult, InvariantError> {
             let current_timestamp = self.get_timestamp();
 
@@ -102,9 +127,12 @@ pub mod invariant {
                 return Err(InvariantError::AmountIsZero);
             }
 
+            // crossed ticks in the order of crossing, written back to storage when the swap is settled
             let mut ticks: Vec<Tick> = vec![];
+            let mut tickmap = CachedTickmap::new(&self.tickmap, pool_key);
 
             let mut pool = self.pools.get(pool_key)?;
+            pool.ensure_active()?;
 
             if x_to_y {
                 if pool.sqrt_price <= sqrt_price_limit
@@ -132,23 +160,26 @@ pub mod invariant {
             let event_start_sqrt_price = pool.sqrt_price;
             let mut event_fee_amount = TokenAmount(0);
 
+            let mut swap_step_number = 0;
+            let mut max_swap_steps_reached = false;
+
             while !remaining_amount.is_zero() {
-                let (swap_limit, limiting_tick) = self.tickmap.get_closer_limit(
+                let (swap_limit, limiting_tick) = tickmap.get_closer_limit(
                     sqrt_price_limit,
                     x_to_y,
                     pool.current_tick_index,
                     pool_key.fee_tier.tick_spacing,
-                    pool_key,
                 )?;
 
-                let result = unwrap!(compute_swap_step(
+                let result = compute_swap_step(
                     pool.sqrt_price,
                     swap_limit,
                     pool.liquidity,
                     remaining_amount,
                     by_amount_in,
                     pool_key.fee_tier.fee,
-                ));
+                )?;
+                swap_step_number += 1;
 
                 // make remaining amount smaller
                 if by_amount_in {
@@ -178,7 +209,7 @@ pub mod invariant {
                             })?;
                 }
 
-                unwrap!(pool.add_fee(result.fee_amount, x_to_y, self.config.protocol_fee));
+                pool.add_fee(result.fee_amount, x_to_y, self.config.protocol_fee)?;
                 event_fee_amount =
                     event_fee_amount
                         .checked_add(result.fee_amount)
@@ -207,8 +238,10 @@ pub mod invariant {
                             )
                         })?;
 
-                // Fail if price would go over swap limit
-                if pool.sqrt_price == sqrt_price_limit && !remaining_amount.is_zero() {
+                // Fail if price would go over swap limit, unless a partial fill was requested
+                let reached_price_limit =
+                    pool.sqrt_price == sqrt_price_limit && !remaining_amount.is_zero();
+                if reached_price_limit && !partial_fill {
                     return Err(InvariantError::PriceLimitReached);
                 }
 
@@ -235,10 +268,12 @@ pub mod invariant {
                     current_timestamp,
                     self.config.protocol_fee,
                     pool_key.fee_tier,
-                );
+                )?;
 
                 remaining_amount = amount_after_tick_update;
-                total_amount_in = total_amount_in.checked_add(amount_to_add).unwrap();
+                total_amount_in = total_amount_in.checked_add(amount_to_add).map_err(|_| {
+                    InvariantError::AddOverflow(total_amount_in.get(), amount_to_add.get())
+                })?;
 
                 if let UpdatePoolTick::TickInitialized(tick) = tick_update {
                     if has_crossed {
@@ -246,6 +281,10 @@ pub mod invariant {
                     }
                 }
 
+                if reached_price_limit {
+                    break;
+                }
+
                 let reached_tick_limit: bool = match x_to_y {
                     true => pool.current_tick_index <= tick_limit,
                     false => pool.current_tick_index >= tick_limit,
@@ -254,6 +293,11 @@ pub mod invariant {
                 if reached_tick_limit {
                     return Err(InvariantError::TickLimitReached);
                 }
+
+                if swap_step_number >= self.config.max_swap_steps && !remaining_amount.is_zero() {
+                    max_swap_steps_reached = true;
+                    break;
+                }
             }
             if total_amount_out.get() == 0 {
                 return Err(InvariantError::NoGainSwap);
@@ -267,9 +311,389 @@ pub mod invariant {
                 fee: event_fee_amount,
                 pool,
                 ticks,
+                remaining_amount,
+                max_swap_steps_reached,
             })
         }
 
+        #[allow(clippy::too_many_arguments)]
+        fn execute_swap(
+            &mut self,
+            pool_key: PoolKey,
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+            partial_fill: bool,
+            referral: Option<Referral>,
+        ) -> Result<CalculateSwapResult, InvariantError> {
+            if let Some(referral) = referral {
+                if referral.fee > MAX_REFERRAL_FEE {
+                    return Err(InvariantError::InvalidReferralFee);
+                }
+            }
+
+            let calculate_swap_result = self.calculate_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                partial_fill,
+            )?;
+
+            self.settle_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                partial_fill,
+                referral,
+                calculate_swap_result,
+            )
+        }
+
+        // applies a swap calculated for the same arguments to the pool and moves the tokens
+        #[allow(clippy::too_many_arguments)]
+        fn settle_swap(
+            &mut self,
+            pool_key: PoolKey,
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+            partial_fill: bool,
+            referral: Option<Referral>,
+            mut calculate_swap_result: CalculateSwapResult,
+        ) -> Result<CalculateSwapResult, InvariantError> {
+            let caller = self.env().caller();
+            let contract = self.env().account_id();
+
+            if calculate_swap_result.max_swap_steps_reached && !partial_fill {
+                return Err(InvariantError::MaxSwapStepsReached);
+            }
+
+            self.call_before_swap_hook(
+                calculate_swap_result.pool.hook,
+                caller,
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+            )?;
+
+            self.ticks
+                .update_batch(pool_key, &calculate_swap_result.ticks);
+            let crossed_tick_indexes: Vec<i32> = calculate_swap_result
+                .ticks
+                .iter()
+                .map(|tick| tick.index)
+                .collect();
+
+            if !crossed_tick_indexes.is_empty() {
+                self.emit_cross_tick_event(caller, pool_key, crossed_tick_indexes);
+            }
+
+            let pool_before = self.pools.get(pool_key)?;
+            let (protocol_fee_before, protocol_fee_after) = if x_to_y {
+                (
+                    pool_before.fee_protocol_token_x,
+                    calculate_swap_result.pool.fee_protocol_token_x,
+                )
+            } else {
+                (
+                    pool_before.fee_protocol_token_y,
+                    calculate_swap_result.pool.fee_protocol_token_y,
+                )
+            };
+            let protocol_fee = protocol_fee_after
+                .checked_sub(protocol_fee_before)
+                .map_err(|_| {
+                    InvariantError::SubUnderflow(
+                        protocol_fee_after.get(),
+                        protocol_fee_before.get(),
+                    )
+                })?;
+
+            self.pools.update(pool_key, &calculate_swap_result.pool)?;
+
+            let (token_in, token_out) = if x_to_y {
+                (pool_key.token_x, pool_key.token_y)
+            } else {
+                (pool_key.token_y, pool_key.token_x)
+            };
+
+            // the referral fee is charged in the token of the amount that is not fixed by the user
+            if let Some(referral) = referral {
+                let (token, amount) = if by_amount_in {
+                    let amount = calculate_swap_result.amount_out.big_mul(referral.fee);
+                    calculate_swap_result.amount_out = calculate_swap_result
+                        .amount_out
+                        .checked_sub(amount)
+                        .map_err(|_| {
+                            InvariantError::SubUnderflow(
+                                calculate_swap_result.amount_out.get(),
+                                amount.get(),
+                            )
+                        })?;
+                    (token_out, amount)
+                } else {
+                    let amount = calculate_swap_result.amount_in.big_mul_up(referral.fee);
+                    calculate_swap_result.amount_in = calculate_swap_result
+                        .amount_in
+                        .checked_add(amount)
+                        .map_err(|_| {
+                            InvariantError::AddOverflow(
+                                calculate_swap_result.amount_in.get(),
+                                amount.get(),
+                            )
+                        })?;
+                    (token_in, amount)
+                };
+
+                if !amount.is_zero() {
+                    self.referral_fees.add(referral.referrer, token, amount)?;
+                    self.emit_referral_fee_event(
+                        caller,
+                        referral.referrer,
+                        pool_key,
+                        token,
+                        amount,
+                    );
+                }
+            }
+
+            // stats and the event report the amounts the caller actually pays and receives
+            let mut pool_stats = self.pool_statistics.get(pool_key);
+            pool_stats.record_swap(
+                x_to_y,
+                calculate_swap_result.amount_in,
+                calculate_swap_result.amount_out,
+                calculate_swap_result.fee,
+                protocol_fee,
+            );
+            self.pool_statistics.update(pool_key, &pool_stats);
+
+            self.emit_swap_event(
+                caller,
+                pool_key,
+                calculate_swap_result.amount_in,
+                calculate_swap_result.amount_out,
+                calculate_swap_result.fee,
+                calculate_swap_result.start_sqrt_price,
+                calculate_swap_result.target_sqrt_price,
+                x_to_y,
+                calculate_swap_result.pool.liquidity,
+                calculate_swap_result.pool.current_tick_index,
+            );
+
+            transfer_from_v1!(
+                token_in,
+                caller,
+                contract,
+                calculate_swap_result.amount_in.get()
+            );
+            transfer_v1!(token_out, caller, calculate_swap_result.amount_out.get());
+
+            self.call_after_swap_hook(
+                calculate_swap_result.pool.hook,
+                caller,
+                pool_key,
+                x_to_y,
+                &calculate_swap_result,
+            );
+
+            Ok(calculate_swap_result)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn call_before_swap_hook(
+            &self,
+            hook: Option<PoolHook>,
+            caller: AccountId,
+            pool_key: PoolKey,
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+        ) -> Result<(), InvariantError> {
+            let hook = match hook {
+                Some(hook) if hook.is_enabled(PoolHook::BEFORE_SWAP) => hook,
+                _ => return Ok(()),
+            };
+
+            let mut hooks: InvariantHooksWrapper = hook.address.into();
+            let allowed = hooks
+                .call_mut()
+                .before_swap(
+                    caller,
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                )
+                .call_v1()
+                .gas_limit(PoolHook::GAS_LIMIT)
+                .try_invoke()
+                .map_err(|_| InvariantError::HookCallFailed)?
+                .map_err(|_| InvariantError::HookCallFailed)?;
+
+            if !allowed {
+                return Err(InvariantError::SwapVetoedByHook);
+            }
+
+            Ok(())
+        }
+
+        fn call_after_swap_hook(
+            &self,
+            hook: Option<PoolHook>,
+            caller: AccountId,
+            pool_key: PoolKey,
+            x_to_y: bool,
+            result: &CalculateSwapResult,
+        ) {
+            let hook = match hook {
+                Some(hook) if hook.is_enabled(PoolHook::AFTER_SWAP) => hook,
+                _ => return,
+            };
+
+            // after callbacks cannot veto, a failing hook must not revert the swap
+            let mut hooks: InvariantHooksWrapper = hook.address.into();
+            let _ = hooks
+                .call_mut()
+                .after_swap(
+                    caller,
+                    pool_key,
+                    x_to_y,
+                    result.amount_in,
+                    result.amount_out,
+                    result.fee,
+                    result.target_sqrt_price,
+                )
+                .call_v1()
+                .gas_limit(PoolHook::GAS_LIMIT)
+                .try_invoke();
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn call_before_modify_position_hook(
+            &self,
+            hook: Option<PoolHook>,
+            caller: AccountId,
+            pool_key: PoolKey,
+            lower_tick: i32,
+            upper_tick: i32,
+            liquidity_delta: Liquidity,
+            add_liquidity: bool,
+        ) -> Result<(), InvariantError> {
+            let hook = match hook {
+                Some(hook) if hook.is_enabled(PoolHook::BEFORE_MODIFY_POSITION) => hook,
+                _ => return Ok(()),
+            };
+
+            let mut hooks: InvariantHooksWrapper = hook.address.into();
+            let result = hooks
+                .call_mut()
+                .before_modify_position(
+                    caller,
+                    pool_key,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                    add_liquidity,
+                )
+                .call_v1()
+                .gas_limit(PoolHook::GAS_LIMIT)
+                .try_invoke();
+
+            // only deposits can be blocked by the hook, withdrawals must always go through
+            match result {
+                Ok(Ok(())) => Ok(()),
+                _ if !add_liquidity => Ok(()),
+                _ => Err(InvariantError::HookCallFailed),
+            }
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn call_after_modify_position_hook(
+            &self,
+            hook: Option<PoolHook>,
+            caller: AccountId,
+            pool_key: PoolKey,
+            lower_tick: i32,
+            upper_tick: i32,
+            liquidity_delta: Liquidity,
+            add_liquidity: bool,
+            amounts: (TokenAmount, TokenAmount),
+        ) {
+            let hook = match hook {
+                Some(hook) if hook.is_enabled(PoolHook::AFTER_MODIFY_POSITION) => hook,
+                _ => return,
+            };
+
+            // after callbacks cannot veto, a failing hook must not revert the position change
+            let mut hooks: InvariantHooksWrapper = hook.address.into();
+            let _ = hooks
+                .call_mut()
+                .after_modify_position(
+                    caller,
+                    pool_key,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                    add_liquidity,
+                    amounts.0,
+                    amounts.1,
+                )
+                .call_v1()
+                .gas_limit(PoolHook::GAS_LIMIT)
+                .try_invoke();
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn call_before_move_liquidity_hooks(
+            &self,
+            hook: Option<PoolHook>,
+            from: AccountId,
+            to: AccountId,
+            pool_key: PoolKey,
+            lower_tick: i32,
+            upper_tick: i32,
+            liquidity: Liquidity,
+        ) -> Result<(), InvariantError> {
+            self.call_before_modify_position_hook(
+                hook, from, pool_key, lower_tick, upper_tick, liquidity, false,
+            )?;
+            self.call_before_modify_position_hook(
+                hook, to, pool_key, lower_tick, upper_tick, liquidity, true,
+            )
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn call_after_move_liquidity_hooks(
+            &self,
+            hook: Option<PoolHook>,
+            from: AccountId,
+            to: AccountId,
+            pool_key: PoolKey,
+            lower_tick: i32,
+            upper_tick: i32,
+            liquidity: Liquidity,
+        ) {
+            // no tokens are transferred when liquidity moves between positions
+            let amounts = (TokenAmount(0), TokenAmount(0));
+            self.call_after_modify_position_hook(
+                hook, from, pool_key, lower_tick, upper_tick, liquidity, false, amounts,
+            );
+            self.call_after_modify_position_hook(
+                hook, to, pool_key, lower_tick, upper_tick, liquidity, true, amounts,
+            );
+        }
+
         fn route(
             &self,
             amount_in: TokenAmount,
@@ -292,7 +716,13 @@ pub mod invariant {
                     next_swap_amount,
                     true,
                     sqrt_price_limit,
+                    false,
                 )?;
+
+                if result.max_swap_steps_reached {
+                    return Err(InvariantError::MaxSwapStepsReached);
+                }
+
                 next_swap_amount = result.amount_out;
             }
 
@@ -303,10 +733,12 @@ pub mod invariant {
             &mut self,
             amount_in: TokenAmount,
             swaps: Vec<SwapHop>,
+            referral: Option<Referral>,
         ) -> Result<TokenAmount, InvariantError> {
             let mut next_swap_amount = amount_in;
+            let last_hop = swaps.len().saturating_sub(1);
 
-            for swap in swaps.iter() {
+            for (hop, swap) in swaps.iter().enumerate() {
                 let SwapHop { pool_key, x_to_y } = *swap;
 
                 let sqrt_price_limit = if x_to_y {
@@ -315,581 +747,2424 @@ pub mod invariant {
                     SqrtPrice::new(MAX_SQRT_PRICE)
                 };
 
-                let result =
-                    self.swap(pool_key, x_to_y, next_swap_amount, true, sqrt_price_limit)?;
+                // the referral fee is taken from the final output only
+                let result = self.execute_swap(
+                    pool_key,
+                    x_to_y,
+                    next_swap_amount,
+                    true,
+                    sqrt_price_limit,
+                    false,
+                    if hop == last_hop { referral } else { None },
+                )?;
                 next_swap_amount = result.amount_out;
             }
 
             Ok(next_swap_amount)
         }
 
-        fn remove_tick(&mut self, key: PoolKey, tick: Tick) -> Result<(), InvariantError> {
-            if !tick.liquidity_gross.is_zero() {
-                return Err(InvariantError::NotEmptyTickDeinitialization);
+        fn execute_swap_route(
+            &mut self,
+            amount_in: TokenAmount,
+            expected_amount_out: TokenAmount,
+            slippage: Percentage,
+            swaps: Vec<SwapHop>,
+            referral: Option<Referral>,
+        ) -> Result<(), InvariantError> {
+            let amount_out = self.route_mut(amount_in, swaps, referral)?;
+
+            let min_amount_out = calculate_min_amount_out(expected_amount_out, slippage);
+
+            if amount_out < min_amount_out {
+                return Err(InvariantError::AmountUnderMinimumAmountOut);
             }
 
-            self.tickmap
-                .flip(false, tick.index, key.fee_tier.tick_spacing, key);
-            self.ticks.remove(key, tick.index)?;
             Ok(())
         }
 
-        #[allow(clippy::too_many_arguments)]
-        fn emit_swap_event(
-            &self,
-            address: AccountId,
-            pool: PoolKey,
-            amount_in: TokenAmount,
-            amount_out: TokenAmount,
-            fee: TokenAmount,
-            start_sqrt_price: SqrtPrice,
-            target_sqrt_price: SqrtPrice,
-            x_to_y: bool,
-        ) {
-            let timestamp = self.get_timestamp();
-            self.env().emit_event(SwapEvent {
-                timestamp,
-                address,
-                pool,
-                amount_in,
-                amount_out,
-                fee,
-                start_sqrt_price,
-                target_sqrt_price,
-                x_to_y,
-            });
+        fn position_seconds_per_liquidity_inside(
+            &mut self,
+            position: &Position,
+        ) -> Result<SecondsPerLiquidity, InvariantError> {
+            let current_timestamp = self.get_timestamp();
+            let pool_key = position.pool_key;
+
+            let lower_tick = self.ticks.get(pool_key, position.lower_tick_index)?;
+            let upper_tick = self.ticks.get(pool_key, position.upper_tick_index)?;
+            let mut pool = self.pools.get(pool_key)?;
+
+            let seconds_per_liquidity_inside = pool.update_seconds_per_liquidity_inside(
+                lower_tick.index,
+                lower_tick.seconds_per_liquidity_outside,
+                upper_tick.index,
+                upper_tick.seconds_per_liquidity_outside,
+                current_timestamp,
+            )?;
+            self.pools.update(pool_key, &pool)?;
+
+            Ok(seconds_per_liquidity_inside)
         }
 
-        fn emit_create_position_event(
+        fn claim_stake_reward(
+            &mut self,
+            owner: AccountId,
+            stake: &mut Stake,
+        ) -> Result<TokenAmount, InvariantError> {
+            let current_timestamp = self.get_timestamp();
+            let mut incentive = self.incentives.get(stake.incentive_id)?;
+
+            let seconds_per_liquidity_inside =
+                self.position_seconds_per_liquidity_inside(&stake.position)?;
+            let (reward, seconds_inside) = incentive.calculate_reward(
+                stake.position.liquidity,
+                stake.seconds_per_liquidity_inside_initial,
+                seconds_per_liquidity_inside,
+                current_timestamp,
+            )?;
+            incentive.claim(reward, seconds_inside)?;
+            stake.seconds_per_liquidity_inside_initial = seconds_per_liquidity_inside;
+            self.incentives.update(stake.incentive_id, &incentive)?;
+
+            if !reward.is_zero() {
+                transfer_v1!(incentive.reward_token, owner, reward.get());
+            }
+
+            Ok(reward)
+        }
+
+        fn unstake(&mut self, owner: AccountId, index: u32) -> Result<TokenAmount, InvariantError> {
+            let mut stake = self.stakes.remove(owner, index)?;
+            let reward = self.claim_stake_reward(owner, &mut stake)?;
+
+            let mut incentive = self.incentives.get(stake.incentive_id)?;
+            incentive.number_of_stakes =
+                incentive
+                    .number_of_stakes
+                    .checked_sub(1)
+                    .ok_or(InvariantError::SubUnderflow(
+                        incentive.number_of_stakes as u128,
+                        1,
+                    ))?;
+            self.incentives.update(stake.incentive_id, &incentive)?;
+
+            self.positions.add(owner, &stake.position);
+            let position_index = self.positions.get_length(owner) - 1;
+
+            self.emit_unstake_position_event(
+                owner,
+                stake.position.pool_key,
+                stake.incentive_id,
+                position_index,
+                reward,
+            );
+            Ok(reward)
+        }
+
+        fn dispatch(&mut self, call: InvariantCall) -> Result<InvariantCallResult, InvariantError> {
+            // multicall is not payable, so a native pool creation fee cannot be paid in it
+            if matches!(
+                call,
+                InvariantCall::CreatePool { .. }
+                    | InvariantCall::CreatePoolWithHook { .. }
+                    | InvariantCall::CreatePoolWithPosition { .. }
+            ) && matches!(
+                self.config.pool_creation_fee,
+                Some(PoolCreationFee {
+                    asset: FeeAsset::Native,
+                    ..
+                })
+            ) {
+                return Err(InvariantError::MulticallNotPayable);
+            }
+
+            let result = match call {
+                InvariantCall::WithdrawProtocolFee { pool_key } => {
+                    self.withdraw_protocol_fee(pool_key)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangeProtocolFee { protocol_fee } => {
+                    self.change_protocol_fee(protocol_fee)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangeMaxSwapSteps { max_swap_steps } => {
+                    self.change_max_swap_steps(max_swap_steps)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangePoolCreationFee { pool_creation_fee } => {
+                    self.change_pool_creation_fee(pool_creation_fee)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::WithdrawPoolCreationFee { asset } => {
+                    InvariantCallResult::Amount(self.withdraw_pool_creation_fee(asset)?)
+                }
+                InvariantCall::ChangeFeeReceiver {
+                    pool_key,
+                    fee_receiver,
+                } => {
+                    self.change_fee_receiver(pool_key, fee_receiver)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangeFeeReceivers {
+                    pool_keys,
+                    fee_receiver,
+                } => {
+                    self.change_fee_receivers(pool_keys, fee_receiver)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangeDefaultFeeReceiver { fee_receiver } => {
+                    self.change_default_fee_receiver(fee_receiver)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangePoolStatus { pool_key, status } => {
+                    self.change_pool_status(pool_key, status)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangePoolCuration { pool_key, curated } => {
+                    self.change_pool_curation(pool_key, curated)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangeTokenStatus { token, status } => {
+                    self.change_token_status(token, status)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::CreatePosition {
+                    pool_key,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                } => InvariantCallResult::Position(self.create_position(
+                    pool_key,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                )?),
+                InvariantCall::CreatePositionByAmounts {
+                    pool_key,
+                    lower_tick,
+                    upper_tick,
+                    max_x,
+                    max_y,
+                    min_x,
+                    min_y,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                } => InvariantCallResult::Position(self.create_position_by_amounts(
+                    pool_key,
+                    lower_tick,
+                    upper_tick,
+                    max_x,
+                    max_y,
+                    min_x,
+                    min_y,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                )?),
+                InvariantCall::ChangeLiquidity {
+                    index,
+                    delta_liquidity,
+                    add_liquidity,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                } => {
+                    self.change_liquidity(
+                        index,
+                        delta_liquidity,
+                        add_liquidity,
+                        slippage_limit_lower,
+                        slippage_limit_upper,
+                    )?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::Swap {
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                } => InvariantCallResult::Swap(self.swap(
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                )?),
+                InvariantCall::SwapPartial {
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                } => InvariantCallResult::Swap(self.swap_partial(
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                )?),
+                InvariantCall::SwapRoute {
+                    amount_in,
+                    expected_amount_out,
+                    slippage,
+                    swaps,
+                } => {
+                    self.swap_route(amount_in, expected_amount_out, slippage, swaps)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::SwapWithReferral {
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                    referral,
+                } => InvariantCallResult::Swap(self.swap_with_referral(
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                    referral,
+                )?),
+                InvariantCall::SwapRouteWithReferral {
+                    amount_in,
+                    expected_amount_out,
+                    slippage,
+                    swaps,
+                    referral,
+                } => {
+                    self.swap_route_with_referral(
+                        amount_in,
+                        expected_amount_out,
+                        slippage,
+                        swaps,
+                        referral,
+                    )?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ClaimReferralFee { token } => {
+                    InvariantCallResult::Amount(self.claim_referral_fee(token)?)
+                }
+                InvariantCall::SwapBest {
+                    token_in,
+                    token_out,
+                    amount,
+                    by_amount_in,
+                    amount_limit,
+                } => {
+                    let (pool_key, result) =
+                        self.swap_best(token_in, token_out, amount, by_amount_in, amount_limit)?;
+                    InvariantCallResult::SwapBest(pool_key, result)
+                }
+                InvariantCall::SwapSplit {
+                    routes,
+                    expected_amount_out,
+                    slippage,
+                } => InvariantCallResult::AmountsOut(self.swap_split(
+                    routes,
+                    expected_amount_out,
+                    slippage,
+                )?),
+                InvariantCall::TransferPosition { index, receiver } => {
+                    self.transfer_position(index, receiver)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::SplitPosition {
+                    index,
+                    liquidity,
+                    receiver,
+                } => InvariantCallResult::Index(self.split_position(index, liquidity, receiver)?),
+                InvariantCall::MergePositions { index_a, index_b } => {
+                    InvariantCallResult::Index(self.merge_positions(index_a, index_b)?)
+                }
+                InvariantCall::LockPosition {
+                    index,
+                    locked_until,
+                } => {
+                    self.lock_position(index, locked_until)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ClaimFee { index } => {
+                    let (amount_x, amount_y) = self.claim_fee(index)?;
+                    InvariantCallResult::Amounts(amount_x, amount_y)
+                }
+                InvariantCall::Donate {
+                    pool_key,
+                    amount_x,
+                    amount_y,
+                } => {
+                    self.donate(pool_key, amount_x, amount_y)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::RemovePosition { index } => {
+                    let (amount_x, amount_y) = self.remove_position(index)?;
+                    InvariantCallResult::Amounts(amount_x, amount_y)
+                }
+                InvariantCall::AddFeeTier { fee_tier } => {
+                    self.add_fee_tier(fee_tier)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::RemoveFeeTier { fee_tier } => {
+                    self.remove_fee_tier(fee_tier)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::CreatePool {
+                    token_0,
+                    token_1,
+                    fee_tier,
+                    init_sqrt_price,
+                    init_tick,
+                } => {
+                    self.create_pool(token_0, token_1, fee_tier, init_sqrt_price, init_tick)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::CreatePoolWithHook {
+                    token_0,
+                    token_1,
+                    fee_tier,
+                    init_sqrt_price,
+                    init_tick,
+                    hook,
+                } => {
+                    self.create_pool_with_hook(
+                        token_0,
+                        token_1,
+                        fee_tier,
+                        init_sqrt_price,
+                        init_tick,
+                        hook,
+                    )?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::CreatePoolWithPosition {
+                    token_0,
+                    token_1,
+                    fee_tier,
+                    init_sqrt_price,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                } => InvariantCallResult::Position(self.create_pool_with_position(
+                    token_0,
+                    token_1,
+                    fee_tier,
+                    init_sqrt_price,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                )?),
+                InvariantCall::CreateIncentive {
+                    pool_key,
+                    reward_token,
+                    reward,
+                    start_timestamp,
+                    end_timestamp,
+                } => InvariantCallResult::Index(self.create_incentive(
+                    pool_key,
+                    reward_token,
+                    reward,
+                    start_timestamp,
+                    end_timestamp,
+                )?),
+                InvariantCall::EndIncentive { incentive_id } => {
+                    InvariantCallResult::Amount(self.end_incentive(incentive_id)?)
+                }
+                InvariantCall::StakePosition {
+                    index,
+                    incentive_id,
+                } => InvariantCallResult::Index(self.stake_position(index, incentive_id)?),
+                InvariantCall::ClaimIncentiveReward { index } => {
+                    InvariantCallResult::Amount(self.claim_incentive_reward(index)?)
+                }
+                InvariantCall::UnstakePosition { index } => {
+                    InvariantCallResult::Amount(self.unstake_position(index)?)
+                }
+                InvariantCall::UpdatePositionSecondsPerLiquidity { index } => {
+                    self.update_position_seconds_per_liquidity(index)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ChangeAdmin { new_admin } => {
+                    self.change_admin(new_admin)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::RemovePoolHook { pool_key } => {
+                    self.remove_pool_hook(pool_key)?;
+                    InvariantCallResult::Empty
+                }
+                InvariantCall::ForceUnstakePosition { owner, index } => {
+                    InvariantCallResult::Amount(self.force_unstake_position(owner, index)?)
+                }
+            };
+
+            Ok(result)
+        }
+
+        fn validate_split_routes(routes: &[SplitRoute]) -> Result<(), InvariantError> {
+            let (first_hop, last_hop) = match routes.first() {
+                Some(route) => match (route.swaps.first(), route.swaps.last()) {
+                    (Some(first_hop), Some(last_hop)) => (first_hop, last_hop),
+                    _ => return Err(InvariantError::InvalidSplitRoutes),
+                },
+                None => return Err(InvariantError::InvalidSplitRoutes),
+            };
+            let (token_in, token_out) = (first_hop.token_in(), last_hop.token_out());
+
+            let mut pool_keys: Vec<PoolKey> = vec![];
+            for route in routes {
+                match (route.swaps.first(), route.swaps.last()) {
+                    (Some(first_hop), Some(last_hop))
+                        if first_hop.token_in() == token_in
+                            && last_hop.token_out() == token_out => {}
+                    _ => return Err(InvariantError::InvalidSplitRoutes),
+                }
+
+                for swap in route.swaps.iter() {
+                    if pool_keys.contains(&swap.pool_key) {
+                        return Err(InvariantError::InvalidSplitRoutes);
+                    }
+                    pool_keys.push(swap.pool_key);
+                }
+            }
+
+            Ok(())
+        }
+
+        fn find_best_pool(
             &self,
-            address: AccountId,
-            pool: PoolKey,
-            liquidity: Liquidity,
+            token_in: AccountId,
+            token_out: AccountId,
+            amount: TokenAmount,
+            by_amount_in: bool,
+        ) -> Result<(PoolKey, CalculateSwapResult), InvariantError> {
+            if token_in == token_out {
+                return Err(InvariantError::TokensAreSame);
+            }
+
+            let mut best: Option<(PoolKey, CalculateSwapResult)> = None;
+            let mut last_error = InvariantError::PoolNotFound;
+
+            for fee_tier in self.fee_tiers.get_all()? {
+                let pool_key = PoolKey::new(token_in, token_out, fee_tier)?;
+                if self.pools.get(pool_key).is_err() {
+                    continue;
+                }
+
+                let x_to_y = pool_key.token_x == token_in;
+                let sqrt_price_limit = if x_to_y {
+                    SqrtPrice::new(MIN_SQRT_PRICE)
+                } else {
+                    SqrtPrice::new(MAX_SQRT_PRICE)
+                };
+
+                let result = match self.calculate_swap(
+                    pool_key,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                    false,
+                ) {
+                    Ok(result) if result.max_swap_steps_reached => {
+                        last_error = InvariantError::MaxSwapStepsReached;
+                        continue;
+                    }
+                    Ok(result) => result,
+                    Err(err) => {
+                        last_error = err;
+                        continue;
+                    }
+                };
+
+                let is_better = match &best {
+                    None => true,
+                    Some((_, best_result)) if by_amount_in => {
+                        result.amount_out > best_result.amount_out
+                    }
+                    Some((_, best_result)) => result.amount_in < best_result.amount_in,
+                };
+
+                if is_better {
+                    best = Some((pool_key, result));
+                }
+            }
+
+            best.ok_or(last_error)
+        }
+
+        fn add_pool(
+            &mut self,
+            token_0: AccountId,
+            token_1: AccountId,
+            fee_tier: FeeTier,
+            init_sqrt_price: SqrtPrice,
+            init_tick: i32,
+            hook: Option<PoolHook>,
+        ) -> Result<PoolKey, InvariantError> {
+            let caller = self.env().caller();
+            let current_timestamp = self.get_timestamp();
+
+            if !self.fee_tiers.contains(fee_tier) {
+                return Err(InvariantError::FeeTierNotFound);
+            };
+
+            check_tick(init_tick, fee_tier.tick_spacing)
+                .map_err(|_| InvariantError::InvalidInitTick)?;
+
+            let pool_key = PoolKey::new(token_0, token_1, fee_tier)?;
+            if self.pools.get(pool_key).is_ok() {
+                return Err(InvariantError::PoolAlreadyExist);
+            };
+
+            let token_0_status = self.token_statuses.get(token_0);
+            let token_1_status = self.token_statuses.get(token_1);
+            if token_0_status == TokenStatus::Denied || token_1_status == TokenStatus::Denied {
+                return Err(InvariantError::TokenDenied);
+            }
+
+            self.collect_pool_creation_fee(caller)?;
+
+            let mut pool = Pool::create(
+                init_sqrt_price,
+                init_tick,
+                current_timestamp,
+                fee_tier.tick_spacing,
+                self.config.default_fee_receiver,
+            )?;
+            pool.curated =
+                token_0_status == TokenStatus::Allowed && token_1_status == TokenStatus::Allowed;
+            pool.hook = hook;
+            self.pools.add(pool_key, &pool)?;
+            self.pool_keys.add(pool_key)?;
+
+            self.emit_create_pool_event(
+                caller,
+                pool_key,
+                init_sqrt_price,
+                init_tick,
+                pool.fee_receiver,
+            );
+            Ok(pool_key)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn add_position(
+            &mut self,
+            pool_key: PoolKey,
             lower_tick: i32,
             upper_tick: i32,
-            current_sqrt_price: SqrtPrice,
-        ) {
-            let timestamp = self.get_timestamp();
-            self.env().emit_event(CreatePositionEvent {
-                timestamp,
-                address,
-                pool,
-                liquidity,
+            liquidity_delta: Liquidity,
+            slippage_limit_lower: SqrtPrice,
+            slippage_limit_upper: SqrtPrice,
+            min_amounts: (TokenAmount, TokenAmount),
+            max_amounts: (TokenAmount, TokenAmount),
+        ) -> Result<Position, InvariantError> {
+            let caller = self.env().caller();
+            let contract = self.env().account_id();
+            let current_timestamp_in_milliseconds = self.env().block_timestamp();
+            let current_block_number = self.env().block_number() as u64;
+
+            // liquidity delta = 0 => return
+            if liquidity_delta == Liquidity::new(0) {
+                return Err(InvariantError::ZeroLiquidity);
+            }
+
+            if lower_tick == upper_tick {
+                return Err(InvariantError::InvalidTickIndex);
+            }
+
+            let mut pool = self.pools.get(pool_key)?;
+            pool.ensure_active()?;
+
+            self.call_before_modify_position_hook(
+                pool.hook,
+                caller,
+                pool_key,
                 lower_tick,
                 upper_tick,
-                current_sqrt_price,
-            });
+                liquidity_delta,
+                true,
+            )?;
+
+            let mut lower_tick = match self.ticks.get(pool_key, lower_tick) {
+                Ok(tick) => tick,
+                Err(_) => self.create_tick(pool_key, lower_tick)?,
+            };
+
+            let mut upper_tick = match self.ticks.get(pool_key, upper_tick) {
+                Ok(tick) => tick,
+                Err(_) => self.create_tick(pool_key, upper_tick)?,
+            };
+
+            let (position, x, y) = Position::create(
+                &mut pool,
+                pool_key,
+                &mut lower_tick,
+                &mut upper_tick,
+                current_timestamp_in_milliseconds,
+                liquidity_delta,
+                slippage_limit_lower,
+                slippage_limit_upper,
+                current_block_number,
+                pool_key.fee_tier.tick_spacing,
+            )?;
+
+            if x > max_amounts.0 || y > max_amounts.1 {
+                return Err(InvariantError::AmountOverMaximumDeposit);
+            }
+
+            if x < min_amounts.0 || y < min_amounts.1 {
+                return Err(InvariantError::AmountUnderMinimumDeposit);
+            }
+
+            self.pools.update(pool_key, &pool)?;
+
+            let index = self.positions.get_length(caller);
+            self.positions.add(caller, &position);
+
+            self.ticks.update(pool_key, lower_tick.index, &lower_tick)?;
+            self.ticks.update(pool_key, upper_tick.index, &upper_tick)?;
+
+            transfer_from_v1!(pool_key.token_x, caller, contract, x.get());
+            transfer_from_v1!(pool_key.token_y, caller, contract, y.get());
+
+            self.emit_create_position_event(
+                caller,
+                pool_key,
+                index,
+                liquidity_delta,
+                lower_tick.index,
+                upper_tick.index,
+                pool.sqrt_price,
+                x,
+                y,
+            );
+
+            self.call_after_modify_position_hook(
+                pool.hook,
+                caller,
+                pool_key,
+                lower_tick.index,
+                upper_tick.index,
+                liquidity_delta,
+                true,
+                (x, y),
+            );
+            Ok(position)
         }
 
-        #[allow(clippy::too_many_arguments)]
-        fn emit_change_liquidity_event(
-            &self,
-            address: AccountId,
-            pool: PoolKey,
-            delta_liquidity: Liquidity,
-            add_liquidity: bool,
-            lower_tick: i32,
-            upper_tick: i32,
-            current_sqrt_price: SqrtPrice,
-        ) {
-            let timestamp = self.get_timestamp();
-            self.env().emit_event(ChangeLiquidityEvent {
-                timestamp,
-                address,
-                pool,
+        fn remove_tick(&mut self, key: PoolKey, tick: Tick) -> Result<(), InvariantError> {
+            if !tick.liquidity_gross.is_zero() {
+                return Err(InvariantError::NotEmptyTickDeinitialization);
+            }
+
+            self.tickmap
+                .flip(false, tick.index, key.fee_tier.tick_spacing, key);
+            self.ticks.remove(key, tick.index)?;
+            Ok(())
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn emit_swap_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            amount_in: TokenAmount,
+            amount_out: TokenAmount,
+            fee: TokenAmount,
+            start_sqrt_price: SqrtPrice,
+            target_sqrt_price: SqrtPrice,
+            x_to_y: bool,
+            liquidity: Liquidity,
+            current_tick_index: i32,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(SwapEvent {
+                timestamp,
+                address,
+                pool,
+                amount_in,
+                amount_out,
+                fee,
+                start_sqrt_price,
+                target_sqrt_price,
+                x_to_y,
+                liquidity,
+                current_tick_index,
+            });
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn emit_create_position_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            index: u32,
+            liquidity: Liquidity,
+            lower_tick: i32,
+            upper_tick: i32,
+            current_sqrt_price: SqrtPrice,
+            amount_x: TokenAmount,
+            amount_y: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(CreatePositionEvent {
+                timestamp,
+                address,
+                pool,
+                index,
+                liquidity,
+                lower_tick,
+                upper_tick,
+                current_sqrt_price,
+                amount_x,
+                amount_y,
+            });
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn emit_change_liquidity_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            index: u32,
+            delta_liquidity: Liquidity,
+            add_liquidity: bool,
+            lower_tick: i32,
+            upper_tick: i32,
+            current_sqrt_price: SqrtPrice,
+            amount_x: TokenAmount,
+            amount_y: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangeLiquidityEvent {
+                timestamp,
+                address,
+                pool,
+                index,
+                delta_liquidity,
+                add_liquidity,
+                lower_tick,
+                upper_tick,
+                current_sqrt_price,
+                amount_x,
+                amount_y,
+            });
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn emit_remove_position_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            index: u32,
+            liquidity: Liquidity,
+            lower_tick: i32,
+            upper_tick: i32,
+            current_sqrt_price: SqrtPrice,
+            amount_x: TokenAmount,
+            amount_y: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(RemovePositionEvent {
+                timestamp,
+                address,
+                pool,
+                index,
+                liquidity,
+                lower_tick,
+                upper_tick,
+                current_sqrt_price,
+                amount_x,
+                amount_y,
+            });
+        }
+
+        fn emit_cross_tick_event(&self, address: AccountId, pool: PoolKey, indexes: Vec<i32>) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(CrossTickEvent {
+                timestamp,
+                address,
+                pool,
+                indexes,
+            });
+        }
+
+        fn emit_create_pool_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            init_sqrt_price: SqrtPrice,
+            init_tick: i32,
+            fee_receiver: AccountId,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(CreatePoolEvent {
+                timestamp,
+                token_x: pool.token_x,
+                token_y: pool.token_y,
+                address,
+                fee_tier: pool.fee_tier,
+                init_sqrt_price,
+                init_tick,
+                fee_receiver,
+            });
+        }
+
+        fn emit_claim_fee_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            index: u32,
+            amount_x: TokenAmount,
+            amount_y: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ClaimFeeEvent {
+                timestamp,
+                address,
+                pool,
+                index,
+                amount_x,
+                amount_y,
+            });
+        }
+
+        fn emit_donate_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            amount_x: TokenAmount,
+            amount_y: TokenAmount,
+            liquidity: Liquidity,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(DonateEvent {
+                timestamp,
+                address,
+                pool,
+                amount_x,
+                amount_y,
+                liquidity,
+            });
+        }
+
+        fn emit_withdraw_protocol_fee_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            amount_x: TokenAmount,
+            amount_y: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(WithdrawProtocolFeeEvent {
+                timestamp,
+                address,
+                pool,
+                amount_x,
+                amount_y,
+            });
+        }
+
+        fn emit_add_fee_tier_event(&self, address: AccountId, fee_tier: FeeTier) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(AddFeeTierEvent {
+                timestamp,
+                address,
+                fee_tier,
+            });
+        }
+
+        fn emit_remove_fee_tier_event(&self, address: AccountId, fee_tier: FeeTier) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(RemoveFeeTierEvent {
+                timestamp,
+                address,
+                fee_tier,
+            });
+        }
+
+        fn emit_change_protocol_fee_event(&self, address: AccountId, protocol_fee: Percentage) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangeProtocolFeeEvent {
+                timestamp,
+                address,
+                protocol_fee,
+            });
+        }
+
+        fn emit_change_max_swap_steps_event(&self, address: AccountId, max_swap_steps: u32) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangeMaxSwapStepsEvent {
+                timestamp,
+                address,
+                max_swap_steps,
+            });
+        }
+
+        fn emit_change_pool_creation_fee_event(
+            &self,
+            address: AccountId,
+            pool_creation_fee: Option<PoolCreationFee>,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangePoolCreationFeeEvent {
+                timestamp,
+                address,
+                pool_creation_fee,
+            });
+        }
+
+        fn emit_withdraw_pool_creation_fee_event(
+            &self,
+            address: AccountId,
+            receiver: AccountId,
+            asset: FeeAsset,
+            amount: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(WithdrawPoolCreationFeeEvent {
+                timestamp,
+                address,
+                receiver,
+                asset,
+                amount,
+            });
+        }
+
+        fn emit_change_default_fee_receiver_event(
+            &self,
+            address: AccountId,
+            fee_receiver: AccountId,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangeDefaultFeeReceiverEvent {
+                timestamp,
+                address,
+                fee_receiver,
+            });
+        }
+
+        fn emit_change_fee_receiver_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            fee_receiver: AccountId,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangeFeeReceiverEvent {
+                timestamp,
+                pool,
+                fee_receiver,
+                address,
+            });
+        }
+
+        fn emit_change_pool_status_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            status: PoolStatus,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangePoolStatusEvent {
+                timestamp,
+                address,
+                pool,
+                status,
+            });
+        }
+
+        fn emit_change_pool_curation_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            curated: bool,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangePoolCurationEvent {
+                timestamp,
+                address,
+                pool,
+                curated,
+            });
+        }
+
+        fn emit_remove_pool_hook_event(&self, address: AccountId, pool: PoolKey, hook: PoolHook) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(RemovePoolHookEvent {
+                timestamp,
+                address,
+                pool,
+                hook,
+            });
+        }
+
+        fn emit_change_token_status_event(
+            &self,
+            address: AccountId,
+            token: AccountId,
+            status: TokenStatus,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangeTokenStatusEvent {
+                timestamp,
+                address,
+                token,
+                status,
+            });
+        }
+
+        fn emit_lock_position_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            index: u32,
+            locked_until: u64,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(LockPositionEvent {
+                timestamp,
+                address,
+                pool,
+                index,
+                locked_until,
+            });
+        }
+
+        fn emit_transfer_position_event(
+            &self,
+            address: AccountId,
+            receiver: AccountId,
+            pool: PoolKey,
+            index: u32,
+            receiver_index: u32,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(TransferPositionEvent {
+                timestamp,
+                address,
+                receiver,
+                pool,
+                index,
+                receiver_index,
+            });
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn emit_split_position_event(
+            &self,
+            address: AccountId,
+            receiver: AccountId,
+            pool: PoolKey,
+            index: u32,
+            receiver_index: u32,
+            liquidity: Liquidity,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(SplitPositionEvent {
+                timestamp,
+                address,
+                receiver,
+                pool,
+                index,
+                receiver_index,
+                liquidity,
+            });
+        }
+
+        fn emit_merge_positions_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            index_a: u32,
+            index_b: u32,
+            index: u32,
+            liquidity: Liquidity,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(MergePositionsEvent {
+                timestamp,
+                address,
+                pool,
+                index_a,
+                index_b,
+                index,
+                liquidity,
+            });
+        }
+
+        fn emit_change_admin_event(&self, address: AccountId, new_admin: AccountId) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ChangeAdminEvent {
+                timestamp,
+                address,
+                new_admin,
+            });
+        }
+
+        fn emit_set_code_event(&self, address: AccountId, code_hash: Hash) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(SetCodeEvent {
+                timestamp,
+                address,
+                code_hash,
+            });
+        }
+
+        fn emit_migrate_event(&self, address: AccountId, storage_version: u32, migrated: u32) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(MigrateEvent {
+                timestamp,
+                address,
+                storage_version,
+                migrated,
+            });
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn emit_create_incentive_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            incentive_id: u32,
+            reward_token: AccountId,
+            reward: TokenAmount,
+            start_timestamp: u64,
+            end_timestamp: u64,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(CreateIncentiveEvent {
+                timestamp,
+                address,
+                pool,
+                incentive_id,
+                reward_token,
+                reward,
+                start_timestamp,
+                end_timestamp,
+            });
+        }
+
+        fn emit_end_incentive_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            incentive_id: u32,
+            refund: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(EndIncentiveEvent {
+                timestamp,
+                address,
+                pool,
+                incentive_id,
+                refund,
+            });
+        }
+
+        fn emit_stake_position_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            incentive_id: u32,
+            index: u32,
+            liquidity: Liquidity,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(StakePositionEvent {
+                timestamp,
+                address,
+                pool,
+                incentive_id,
+                index,
+                liquidity,
+            });
+        }
+
+        fn emit_claim_incentive_reward_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            incentive_id: u32,
+            index: u32,
+            reward: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ClaimIncentiveRewardEvent {
+                timestamp,
+                address,
+                pool,
+                incentive_id,
+                index,
+                reward,
+            });
+        }
+
+        fn emit_unstake_position_event(
+            &self,
+            address: AccountId,
+            pool: PoolKey,
+            incentive_id: u32,
+            index: u32,
+            reward: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(UnstakePositionEvent {
+                timestamp,
+                address,
+                pool,
+                incentive_id,
+                index,
+                reward,
+            });
+        }
+
+        fn migrate_legacy_pools(&mut self, batch_size: u32) -> Result<(u32, bool), InvariantError> {
+            let offset = self.config.migration_cursor as u16;
+            let size = batch_size.min((u16::MAX - offset) as u32) as u16;
+
+            let pool_keys = self.pool_keys.get_all(size, offset);
+            for pool_key in pool_keys.iter() {
+                // decoding fills the fields missing in the legacy layout, the update writes them
+                let pool = self.pools.get(*pool_key)?;
+                self.pools.update(*pool_key, &pool)?;
+            }
+
+            let migrated = pool_keys.len() as u32;
+            let completed = offset as u32 + migrated >= self.pool_keys.count() as u32;
+            Ok((migrated, completed))
+        }
+
+        fn collect_pool_creation_fee(&mut self, caller: AccountId) -> Result<(), InvariantError> {
+            let transferred_value = self.env().transferred_value();
+
+            let Some(pool_creation_fee) = self.config.pool_creation_fee else {
+                if transferred_value != 0 {
+                    return Err(InvariantError::InvalidPoolCreationFee);
+                }
+                return Ok(());
+            };
+
+            match pool_creation_fee.asset {
+                FeeAsset::Native => {
+                    if transferred_value != pool_creation_fee.amount.get() {
+                        return Err(InvariantError::InvalidPoolCreationFee);
+                    }
+                }
+                FeeAsset::Token(token) => {
+                    if transferred_value != 0 {
+                        return Err(InvariantError::InvalidPoolCreationFee);
+                    }
+                    let contract = self.env().account_id();
+                    transfer_from_v1!(token, caller, contract, pool_creation_fee.amount.get());
+                }
+            }
+
+            self.pool_creation_fees
+                .add(pool_creation_fee.asset, pool_creation_fee.amount)
+        }
+
+        fn emit_referral_fee_event(
+            &self,
+            address: AccountId,
+            referrer: AccountId,
+            pool: PoolKey,
+            token: AccountId,
+            amount: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ReferralFeeEvent {
+                timestamp,
+                address,
+                referrer,
+                pool,
+                token,
+                amount,
+            });
+        }
+
+        fn emit_claim_referral_fee_event(
+            &self,
+            address: AccountId,
+            token: AccountId,
+            amount: TokenAmount,
+        ) {
+            let timestamp = self.get_timestamp();
+            self.env().emit_event(ClaimReferralFeeEvent {
+                timestamp,
+                address,
+                token,
+                amount,
+            });
+        }
+
+        fn get_timestamp(&self) -> u64 {
+            self.env().block_timestamp() / 1000
+        }
+
+        fn tickmap_slice_from_chunk_lookup(
+            &self,
+            pool_key: PoolKey,
+            chunk_lookup_start: u16,
+            chunk_lookup_bit_start: u16,
+            chunk_lookup_end: u16,
+            chunk_lookup_bit_end: u16,
+            range: impl Iterator<Item = u16>,
+        ) -> Result<Vec<(u16, u64)>, InvariantError> {
+            let mut tickmap_slice: Vec<(u16, u64)> = vec![];
+
+            for chunk_lookup_index in range {
+                if let Some(chunk_lookup) = self
+                    .tickmap
+                    .chunk_lookups
+                    .get((chunk_lookup_index, pool_key))
+                {
+                    let push = |range, slice: &mut Vec<(u16, u64)>| -> Result<(), InvariantError> {
+                        for i in range {
+                            if chunk_lookup & (1u64 << i) != 0 {
+                                if MAX_TICKMAP_QUERY_SIZE == slice.len() {
+                                    break;
+                                }
+
+                                let chunk_offset = chunk_lookup_index
+                                    .checked_mul(CHUNK_LOOKUP_SIZE as u16)
+                                    .ok_or(InvariantError::MulOverflow)?;
+
+                                let chunk_index = chunk_offset.checked_add(i).ok_or(
+                                    InvariantError::AddOverflow(chunk_offset as u128, i as u128),
+                                )?;
+                                if let Some(val) = self.tickmap.bitmap.get((chunk_index, pool_key))
+                                {
+                                    slice.push((chunk_index, val))
+                                }
+                            }
+                        }
+
+                        Ok(())
+                    };
+
+                    if chunk_lookup_index == chunk_lookup_start {
+                        push(
+                            chunk_lookup_bit_start..(CHUNK_SIZE as u16),
+                            &mut tickmap_slice,
+                        )?;
+                    } else if chunk_lookup_index == chunk_lookup_end {
+                        push(
+                            0..(chunk_lookup_bit_end.checked_add(1).ok_or(
+                                InvariantError::AddOverflow(chunk_lookup_bit_end as u128, 1),
+                            )?),
+                            &mut tickmap_slice,
+                        )?;
+                    } else {
+                        push(0..(CHUNK_SIZE as u16), &mut tickmap_slice)?;
+                    }
+                }
+            }
+
+            Ok(tickmap_slice)
+        }
+
+        fn tickmap_slice(
+            &self,
+            start: u16,
+            end: u16,
+            x_to_y: bool,
+            pool_key: PoolKey,
+        ) -> Result<Vec<(u16, u64)>, InvariantError> {
+            let chunk_lookup_bit_start = get_chunk_lookup_bit(start);
+            let chunk_lookup_bit_end = get_chunk_lookup_bit(end);
+            let chunk_lookup_start = get_chunk_lookup_index(start);
+            let chunk_lookup_end = get_chunk_lookup_index(end);
+            if x_to_y {
+                self.tickmap_slice_from_chunk_lookup(
+                    pool_key,
+                    chunk_lookup_start,
+                    chunk_lookup_bit_start,
+                    chunk_lookup_end,
+                    chunk_lookup_bit_end,
+                    (chunk_lookup_start..=chunk_lookup_end).rev(),
+                )
+            } else {
+                self.tickmap_slice_from_chunk_lookup(
+                    pool_key,
+                    chunk_lookup_start,
+                    chunk_lookup_bit_start,
+                    chunk_lookup_end,
+                    chunk_lookup_bit_end,
+                    chunk_lookup_start..=chunk_lookup_end,
+                )
+            }
+        }
+    }
+
+    impl InvariantEntrypoints for Invariant {
+        #[ink(message)]
+        fn get_protocol_fee(&self) -> Percentage {
+            self.config.protocol_fee
+        }
+
+        #[ink(message)]
+        fn withdraw_protocol_fee(&mut self, pool_key: PoolKey) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            let mut pool = self.pools.get(pool_key)?;
+
+            if pool.fee_receiver != caller {
+                return Err(InvariantError::NotFeeReceiver);
+            }
+
+            let (fee_protocol_token_x, fee_protocol_token_y) = pool.withdraw_protocol_fee();
+            self.pools.update(pool_key, &pool)?;
+
+            transfer_v1!(
+                pool_key.token_x,
+                pool.fee_receiver,
+                fee_protocol_token_x.get()
+            );
+
+            transfer_v1!(
+                pool_key.token_y,
+                pool.fee_receiver,
+                fee_protocol_token_y.get()
+            );
+
+            self.emit_withdraw_protocol_fee_event(
+                caller,
+                pool_key,
+                fee_protocol_token_x,
+                fee_protocol_token_y,
+            );
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_protocol_fee(&mut self, protocol_fee: Percentage) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            self.config.protocol_fee = protocol_fee;
+
+            self.emit_change_protocol_fee_event(caller, protocol_fee);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn get_max_swap_steps(&self) -> u32 {
+            self.config.max_swap_steps
+        }
+
+        #[ink(message)]
+        fn change_max_swap_steps(&mut self, max_swap_steps: u32) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            if max_swap_steps == 0 {
+                return Err(InvariantError::InvalidMaxSwapSteps);
+            }
+
+            self.config.max_swap_steps = max_swap_steps;
+
+            self.emit_change_max_swap_steps_event(caller, max_swap_steps);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn get_pool_creation_fee(&self) -> Option<PoolCreationFee> {
+            self.config.pool_creation_fee
+        }
+
+        #[ink(message)]
+        fn change_pool_creation_fee(
+            &mut self,
+            pool_creation_fee: Option<PoolCreationFee>,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            self.config.pool_creation_fee = pool_creation_fee;
+
+            self.emit_change_pool_creation_fee_event(caller, pool_creation_fee);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn get_collected_pool_creation_fee(&self, asset: FeeAsset) -> TokenAmount {
+            self.pool_creation_fees.get(asset)
+        }
+
+        #[ink(message)]
+        fn withdraw_pool_creation_fee(
+            &mut self,
+            asset: FeeAsset,
+        ) -> Result<TokenAmount, InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            let amount = self.pool_creation_fees.take(asset);
+            if amount.is_zero() {
+                return Err(InvariantError::AmountIsZero);
+            }
+
+            let receiver = self.config.default_fee_receiver;
+            match asset {
+                FeeAsset::Native => self
+                    .env()
+                    .transfer(receiver, amount.get())
+                    .map_err(|_| InvariantError::TransferError)?,
+                FeeAsset::Token(token) => {
+                    transfer_v1!(token, receiver, amount.get());
+                }
+            }
+
+            self.emit_withdraw_pool_creation_fee_event(caller, receiver, asset, amount);
+            Ok(amount)
+        }
+
+        #[ink(message)]
+        fn change_fee_receiver(
+            &mut self,
+            pool_key: PoolKey,
+            fee_receiver: AccountId,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            let mut pool = self.pools.get(pool_key)?;
+            pool.fee_receiver = fee_receiver;
+            self.pools.update(pool_key, &pool)?;
+
+            self.emit_change_fee_receiver_event(caller, pool_key, fee_receiver);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_fee_receivers(
+            &mut self,
+            pool_keys: Vec<PoolKey>,
+            fee_receiver: AccountId,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            for pool_key in pool_keys {
+                let mut pool = self.pools.get(pool_key)?;
+                pool.fee_receiver = fee_receiver;
+                self.pools.update(pool_key, &pool)?;
+
+                self.emit_change_fee_receiver_event(caller, pool_key, fee_receiver);
+            }
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn get_default_fee_receiver(&self) -> AccountId {
+            self.config.default_fee_receiver
+        }
+
+        #[ink(message)]
+        fn change_default_fee_receiver(
+            &mut self,
+            fee_receiver: AccountId,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            self.config.default_fee_receiver = fee_receiver;
+
+            self.emit_change_default_fee_receiver_event(caller, fee_receiver);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_pool_status(
+            &mut self,
+            pool_key: PoolKey,
+            status: PoolStatus,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            let mut pool = self.pools.get(pool_key)?;
+            pool.status = status;
+            self.pools.update(pool_key, &pool)?;
+
+            self.emit_change_pool_status_event(caller, pool_key, status);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_pool_curation(
+            &mut self,
+            pool_key: PoolKey,
+            curated: bool,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            let mut pool = self.pools.get(pool_key)?;
+            pool.curated = curated;
+            self.pools.update(pool_key, &pool)?;
+
+            self.emit_change_pool_curation_event(caller, pool_key, curated);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn remove_pool_hook(&mut self, pool_key: PoolKey) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            let mut pool = self.pools.get(pool_key)?;
+            let hook = pool.hook.take().ok_or(InvariantError::PoolHasNoHook)?;
+            self.pools.update(pool_key, &pool)?;
+
+            self.emit_remove_pool_hook_event(caller, pool_key, hook);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn change_token_status(
+            &mut self,
+            token: AccountId,
+            status: TokenStatus,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            self.token_statuses.set(token, status);
+
+            self.emit_change_token_status_event(caller, token, status);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn get_token_status(&self, token: AccountId) -> TokenStatus {
+            self.token_statuses.get(token)
+        }
+
+        #[ink(message)]
+        fn create_position(
+            &mut self,
+            pool_key: PoolKey,
+            lower_tick: i32,
+            upper_tick: i32,
+            liquidity_delta: Liquidity,
+            slippage_limit_lower: SqrtPrice,
+            slippage_limit_upper: SqrtPrice,
+        ) -> Result<Position, InvariantError> {
+            self.add_position(
+                pool_key,
+                lower_tick,
+                upper_tick,
+                liquidity_delta,
+                slippage_limit_lower,
+                slippage_limit_upper,
+                (TokenAmount::new(0), TokenAmount::new(0)),
+                (TokenAmount::max_instance(), TokenAmount::max_instance()),
+            )
+        }
+
+        #[ink(message)]
+        fn create_position_by_amounts(
+            &mut self,
+            pool_key: PoolKey,
+            lower_tick: i32,
+            upper_tick: i32,
+            max_x: TokenAmount,
+            max_y: TokenAmount,
+            min_x: TokenAmount,
+            min_y: TokenAmount,
+            slippage_limit_lower: SqrtPrice,
+            slippage_limit_upper: SqrtPrice,
+        ) -> Result<Position, InvariantError> {
+            let pool = self.pools.get(pool_key)?;
+
+            check_tick(lower_tick, pool_key.fee_tier.tick_spacing)
+                .map_err(|_| InvariantError::InvalidTickIndexOrTickSpacing)?;
+            check_tick(upper_tick, pool_key.fee_tier.tick_spacing)
+                .map_err(|_| InvariantError::InvalidTickIndexOrTickSpacing)?;
+
+            if lower_tick >= upper_tick {
+                return Err(InvariantError::InvalidTickIndex);
+            }
+
+            let liquidity_result =
+                get_liquidity(max_x, max_y, lower_tick, upper_tick, pool.sqrt_price, false)?;
+
+            self.add_position(
+                pool_key,
+                lower_tick,
+                upper_tick,
+                liquidity_result.l,
+                slippage_limit_lower,
+                slippage_limit_upper,
+                (min_x, min_y),
+                (max_x, max_y),
+            )
+        }
+
+        #[ink(message)]
+        fn change_liquidity(
+            &mut self,
+            index: u32,
+            delta_liquidity: Liquidity,
+            add_liquidity: bool,
+            slippage_limit_lower: SqrtPrice,
+            slippage_limit_upper: SqrtPrice,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+            let current_timestamp = self.get_timestamp();
+            let current_block_number = self.env().block_number() as u64;
+            let contract = self.env().account_id();
+
+            let mut position = self.positions.get(caller, index)?;
+            let pool_key = position.pool_key;
+            let mut pool = self.pools.get(pool_key)?;
+            let mut lower_tick = self.ticks.get(pool_key, position.lower_tick_index)?;
+            let mut upper_tick = self.ticks.get(pool_key, position.upper_tick_index)?;
+
+            if add_liquidity {
+                pool.ensure_active()?;
+            } else {
+                pool.ensure_not_closed()?;
+
+                if position.is_locked(current_timestamp) {
+                    return Err(InvariantError::PositionLocked);
+                }
+            }
+
+            if !add_liquidity && delta_liquidity == position.liquidity {
+                return Err(InvariantError::ZeroLiquidity);
+            }
+
+            if delta_liquidity.get() == 0 {
+                return Err(InvariantError::LiquidityChangeZero);
+            }
+
+            if pool.sqrt_price < slippage_limit_lower || pool.sqrt_price > slippage_limit_upper {
+                return Err(InvariantError::PriceLimitReached);
+            }
+
+            self.call_before_modify_position_hook(
+                pool.hook,
+                caller,
+                pool_key,
+                lower_tick.index,
+                upper_tick.index,
+                delta_liquidity,
+                add_liquidity,
+            )?;
+
+            position.update_seconds_per_liquidity(
+                &mut pool,
+                lower_tick,
+                upper_tick,
+                current_timestamp,
+                current_block_number,
+            );
+
+            let (x, y) = position.modify(
+                &mut pool,
+                &mut upper_tick,
+                &mut lower_tick,
+                delta_liquidity,
+                add_liquidity,
+                current_timestamp,
+                pool_key.fee_tier.tick_spacing,
+            )?;
+
+            self.pools.update(pool_key, &pool)?;
+            self.positions.update(caller, index, &position)?;
+            self.ticks.update(pool_key, lower_tick.index, &lower_tick)?;
+            self.ticks.update(pool_key, upper_tick.index, &upper_tick)?;
+
+            let x_is_zero = x.get() == 0;
+            let y_is_zero = y.get() == 0;
+
+            if y_is_zero && x_is_zero {
+                return Err(InvariantError::AmountIsZero);
+            }
+
+            if !x_is_zero {
+                if add_liquidity {
+                    transfer_from_v1!(pool_key.token_x, caller, contract, x.get());
+                } else {
+                    transfer_v1!(pool_key.token_x, caller, x.get());
+                }
+            }
+
+            if !y_is_zero {
+                if add_liquidity {
+                    transfer_from_v1!(pool_key.token_y, caller, contract, y.get());
+                } else {
+                    transfer_v1!(pool_key.token_y, caller, y.get());
+                }
+            }
+
+            self.emit_change_liquidity_event(
+                caller,
+                pool_key,
+                index,
                 delta_liquidity,
                 add_liquidity,
-                lower_tick,
-                upper_tick,
-                current_sqrt_price,
-            });
+                lower_tick.index,
+                upper_tick.index,
+                pool.sqrt_price,
+                x,
+                y,
+            );
+
+            self.call_after_modify_position_hook(
+                pool.hook,
+                caller,
+                pool_key,
+                lower_tick.index,
+                upper_tick.index,
+                delta_liquidity,
+                add_liquidity,
+                (x, y),
+            );
+
+            Ok(())
         }
 
-        fn emit_remove_position_event(
-            &self,
-            address: AccountId,
-            pool: PoolKey,
-            liquidity: Liquidity,
-            lower_tick: i32,
-            upper_tick: i32,
-            current_sqrt_price: SqrtPrice,
-        ) {
-            let timestamp = self.get_timestamp();
-            self.env().emit_event(RemovePositionEvent {
-                timestamp,
-                address,
-                pool,
-                liquidity,
-                lower_tick,
-                upper_tick,
-                current_sqrt_price,
-            });
+        #[ink(message)]
+        fn swap(
+            &mut self,
+            pool_key: PoolKey,
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+        ) -> Result<CalculateSwapResult, InvariantError> {
+            self.execute_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                false,
+                None,
+            )
         }
 
-        fn emit_cross_tick_event(&self, address: AccountId, pool: PoolKey, indexes: Vec<i32>) {
-            let timestamp = self.get_timestamp();
-            self.env().emit_event(CrossTickEvent {
-                timestamp,
-                address,
-                pool,
-                indexes,
-            });
+        #[ink(message)]
+        fn swap_partial(
+            &mut self,
+            pool_key: PoolKey,
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+        ) -> Result<CalculateSwapResult, InvariantError> {
+            self.execute_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                true,
+                None,
+            )
         }
 
-        fn get_timestamp(&self) -> u64 {
-            self.env().block_timestamp() / 1000
+        #[ink(message)]
+        fn swap_route(
+            &mut self,
+            amount_in: TokenAmount,
+            expected_amount_out: TokenAmount,
+            slippage: Percentage,
+            swaps: Vec<SwapHop>,
+        ) -> Result<(), InvariantError> {
+            self.execute_swap_route(amount_in, expected_amount_out, slippage, swaps, None)
         }
 
-        fn tickmap_slice_from_chunk_lookup(
-            &self,
+        #[ink(message)]
+        fn swap_with_referral(
+            &mut self,
             pool_key: PoolKey,
-            chunk_lookup_start: u16,
-            chunk_lookup_bit_start: u16,
-            chunk_lookup_end: u16,
-            chunk_lookup_bit_end: u16,
-            range: impl Iterator<Item = u16>,
-        ) -> Result<Vec<(u16, u64)>, InvariantError> {
-            let mut tickmap_slice: Vec<(u16, u64)> = vec![];
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+            referral: Referral,
+        ) -> Result<CalculateSwapResult, InvariantError> {
+            self.execute_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                false,
+                Some(referral),
+            )
+        }
 
-            for chunk_lookup_index in range {
-                if let Some(chunk_lookup) = self
-                    .tickmap
-                    .chunk_lookups
-                    .get((chunk_lookup_index, pool_key))
-                {
-                    let push = |range, slice: &mut Vec<(u16, u64)>| -> Result<(), InvariantError> {
-                        for i in range {
-                            if chunk_lookup & (1u64 << i) != 0 {
-                                if MAX_TICKMAP_QUERY_SIZE == slice.len() {
-                                    break;
-                                }
+        #[ink(message)]
+        fn swap_route_with_referral(
+            &mut self,
+            amount_in: TokenAmount,
+            expected_amount_out: TokenAmount,
+            slippage: Percentage,
+            swaps: Vec<SwapHop>,
+            referral: Referral,
+        ) -> Result<(), InvariantError> {
+            self.execute_swap_route(
+                amount_in,
+                expected_amount_out,
+                slippage,
+                swaps,
+                Some(referral),
+            )
+        }
 
-                                let chunk_offset = chunk_lookup_index
-                                    .checked_mul(CHUNK_LOOKUP_SIZE as u16)
-                                    .ok_or(InvariantError::MulOverflow)?;
+        #[ink(message)]
+        fn claim_referral_fee(&mut self, token: AccountId) -> Result<TokenAmount, InvariantError> {
+            let caller = self.env().caller();
 
-                                let chunk_index = chunk_offset.checked_add(i).ok_or(
-                                    InvariantError::AddOverflow(chunk_offset as u128, i as u128),
-                                )?;
-                                if let Some(val) = self.tickmap.bitmap.get((chunk_index, pool_key))
-                                {
-                                    slice.push((chunk_index, val))
-                                }
-                            }
-                        }
+            let amount = self.referral_fees.take(caller, token);
+            if amount.is_zero() {
+                return Err(InvariantError::AmountIsZero);
+            }
 
-                        Ok(())
-                    };
+            transfer_v1!(token, caller, amount.get());
 
-                    if chunk_lookup_index == chunk_lookup_start {
-                        push(
-                            chunk_lookup_bit_start..(CHUNK_SIZE as u16),
-                            &mut tickmap_slice,
-                        )?;
-                    } else if chunk_lookup_index == chunk_lookup_end {
-                        push(
-                            0..(chunk_lookup_bit_end.checked_add(1).ok_or(
-                                InvariantError::AddOverflow(chunk_lookup_bit_end as u128, 1),
-                            )?),
-                            &mut tickmap_slice,
-                        )?;
-                    } else {
-                        push(0..(CHUNK_SIZE as u16), &mut tickmap_slice)?;
-                    }
-                }
+            self.emit_claim_referral_fee_event(caller, token, amount);
+
+            Ok(amount)
+        }
+
+        #[ink(message)]
+        fn get_referral_fee(&self, referrer: AccountId, token: AccountId) -> TokenAmount {
+            self.referral_fees.get(referrer, token)
+        }
+
+        #[ink(message)]
+        fn swap_best(
+            &mut self,
+            token_in: AccountId,
+            token_out: AccountId,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            amount_limit: TokenAmount,
+        ) -> Result<(PoolKey, CalculateSwapResult), InvariantError> {
+            let (pool_key, result) =
+                self.find_best_pool(token_in, token_out, amount, by_amount_in)?;
+
+            let x_to_y = pool_key.token_x == token_in;
+            let sqrt_price_limit = if x_to_y {
+                SqrtPrice::new(MIN_SQRT_PRICE)
+            } else {
+                SqrtPrice::new(MAX_SQRT_PRICE)
+            };
+
+            // the winning pool was calculated with the same arguments, so it is settled directly
+            let result = self.settle_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                false,
+                None,
+                result,
+            )?;
+
+            if by_amount_in && result.amount_out < amount_limit {
+                return Err(InvariantError::AmountUnderMinimumAmountOut);
+            }
+            if !by_amount_in && result.amount_in > amount_limit {
+                return Err(InvariantError::AmountOverMaximumAmountIn);
             }
 
-            Ok(tickmap_slice)
+            Ok((pool_key, result))
         }
 
-        fn tickmap_slice(
+        #[ink(message)]
+        fn swap_split(
+            &mut self,
+            routes: Vec<SplitRoute>,
+            expected_amount_out: TokenAmount,
+            slippage: Percentage,
+        ) -> Result<Vec<TokenAmount>, InvariantError> {
+            Self::validate_split_routes(&routes)?;
+
+            let mut amounts_out = vec![];
+            let mut total_amount_out = TokenAmount(0);
+            for route in routes {
+                let amount_out = self.route_mut(route.amount_in, route.swaps, None)?;
+                total_amount_out = total_amount_out.checked_add(amount_out).map_err(|_| {
+                    InvariantError::AddOverflow(total_amount_out.get(), amount_out.get())
+                })?;
+                amounts_out.push(amount_out);
+            }
+
+            let min_amount_out = calculate_min_amount_out(expected_amount_out, slippage);
+
+            if total_amount_out < min_amount_out {
+                return Err(InvariantError::AmountUnderMinimumAmountOut);
+            }
+
+            Ok(amounts_out)
+        }
+
+        #[ink(message)]
+        fn quote(
             &self,
-            start: u16,
-            end: u16,
-            x_to_y: bool,
             pool_key: PoolKey,
-        ) -> Result<Vec<(u16, u64)>, InvariantError> {
-            let chunk_lookup_bit_start = get_chunk_lookup_bit(start);
-            let chunk_lookup_bit_end = get_chunk_lookup_bit(end);
-            let chunk_lookup_start = get_chunk_lookup_index(start);
-            let chunk_lookup_end = get_chunk_lookup_index(end);
-            if x_to_y {
-                self.tickmap_slice_from_chunk_lookup(
-                    pool_key,
-                    chunk_lookup_start,
-                    chunk_lookup_bit_start,
-                    chunk_lookup_end,
-                    chunk_lookup_bit_end,
-                    (chunk_lookup_start..=chunk_lookup_end).rev(),
-                )
-            } else {
-                self.tickmap_slice_from_chunk_lookup(
-                    pool_key,
-                    chunk_lookup_start,
-                    chunk_lookup_bit_start,
-                    chunk_lookup_end,
-                    chunk_lookup_bit_end,
-                    chunk_lookup_start..=chunk_lookup_end,
-                )
-            }
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+        ) -> Result<QuoteResult, InvariantError> {
+            let calculate_swap_result = self.calculate_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                false,
+            )?;
+
+            Ok(calculate_swap_result.into())
         }
-    }
 
-    impl InvariantEntrypoints for Invariant {
         #[ink(message)]
-        fn get_protocol_fee(&self) -> Percentage {
-            self.config.protocol_fee
+        fn quote_best(
+            &self,
+            token_in: AccountId,
+            token_out: AccountId,
+            amount: TokenAmount,
+            by_amount_in: bool,
+        ) -> Result<(PoolKey, QuoteResult), InvariantError> {
+            let (pool_key, calculate_swap_result) =
+                self.find_best_pool(token_in, token_out, amount, by_amount_in)?;
+
+            Ok((pool_key, calculate_swap_result.into()))
         }
 
         #[ink(message)]
-        fn withdraw_protocol_fee(&mut self, pool_key: PoolKey) -> Result<(), InvariantError> {
-            let caller = self.env().caller();
+        fn quote_partial(
+            &self,
+            pool_key: PoolKey,
+            x_to_y: bool,
+            amount: TokenAmount,
+            by_amount_in: bool,
+            sqrt_price_limit: SqrtPrice,
+        ) -> Result<QuoteResult, InvariantError> {
+            let calculate_swap_result = self.calculate_swap(
+                pool_key,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+                true,
+            )?;
 
-            let mut pool = self.pools.get(pool_key)?;
+            Ok(calculate_swap_result.into())
+        }
 
-            if pool.fee_receiver != caller {
-                return Err(InvariantError::NotFeeReceiver);
-            }
+        #[ink(message)]
+        fn quote_route(
+            &self,
+            amount_in: TokenAmount,
+            swaps: Vec<SwapHop>,
+        ) -> Result<TokenAmount, InvariantError> {
+            let amount_out = self.route(amount_in, swaps)?;
 
-            let (fee_protocol_token_x, fee_protocol_token_y) = pool.withdraw_protocol_fee();
-            self.pools.update(pool_key, &pool)?;
+            Ok(amount_out)
+        }
 
-            transfer_v1!(
-                pool_key.token_x,
-                pool.fee_receiver,
-                fee_protocol_token_x.get()
-            );
+        #[ink(message)]
+        fn quote_split(&self, routes: Vec<SplitRoute>) -> Result<Vec<TokenAmount>, InvariantError> {
+            Self::validate_split_routes(&routes)?;
 
-            transfer_v1!(
-                pool_key.token_y,
-                pool.fee_receiver,
-                fee_protocol_token_y.get()
-            );
+            let mut amounts_out = vec![];
+            for route in routes {
+                amounts_out.push(self.route(route.amount_in, route.swaps)?);
+            }
 
-            Ok(())
+            Ok(amounts_out)
         }
 
         #[ink(message)]
-        fn change_protocol_fee(&mut self, protocol_fee: Percentage) -> Result<(), InvariantError> {
-            let caller = self.env().caller();
-
-            if caller != self.config.admin {
-                return Err(InvariantError::NotAdmin);
+        fn multicall(
+            &mut self,
+            calls: Vec<InvariantCall>,
+        ) -> Result<Vec<InvariantCallResult>, InvariantError> {
+            let mut results = vec![];
+            for call in calls {
+                results.push(self.dispatch(call)?);
             }
 
-            self.config.protocol_fee = protocol_fee;
-            Ok(())
+            Ok(results)
         }
 
         #[ink(message)]
-        fn change_fee_receiver(
+        fn transfer_position(
             &mut self,
-            pool_key: PoolKey,
-            fee_receiver: AccountId,
+            index: u32,
+            receiver: AccountId,
         ) -> Result<(), InvariantError> {
             let caller = self.env().caller();
+            let current_timestamp = self.get_timestamp();
 
-            if caller != self.config.admin {
-                return Err(InvariantError::NotAdmin);
+            let position = self.positions.get(caller, index)?;
+            self.pools.get(position.pool_key)?.ensure_not_closed()?;
+            if position.is_locked(current_timestamp) {
+                return Err(InvariantError::PositionLocked);
             }
 
-            let mut pool = self.pools.get(pool_key)?;
-            pool.fee_receiver = fee_receiver;
-            self.pools.update(pool_key, &pool)?;
+            let receiver_index = self.positions.get_length(receiver);
+            self.positions.transfer(caller, index, receiver)?;
 
+            self.emit_transfer_position_event(
+                caller,
+                receiver,
+                position.pool_key,
+                index,
+                receiver_index,
+            );
             Ok(())
         }
 
         #[ink(message)]
-        fn create_position(
+        fn split_position(
             &mut self,
-            pool_key: PoolKey,
-            lower_tick: i32,
-            upper_tick: i32,
-            liquidity_delta: Liquidity,
-            slippage_limit_lower: SqrtPrice,
-            slippage_limit_upper: SqrtPrice,
-        ) -> Result<Position, InvariantError> {
+            index: u32,
+            liquidity: Liquidity,
+            receiver: AccountId,
+        ) -> Result<u32, InvariantError> {
             let caller = self.env().caller();
-            let contract = self.env().account_id();
             let current_timestamp_in_milliseconds = self.env().block_timestamp();
+            let current_timestamp = self.get_timestamp();
             let current_block_number = self.env().block_number() as u64;
 
-            // liquidity delta = 0 => return
-            if liquidity_delta == Liquidity::new(0) {
-                return Err(InvariantError::ZeroLiquidity);
+            let mut position = self.positions.get(caller, index)?;
+            if position.is_locked(current_timestamp) {
+                return Err(InvariantError::PositionLocked);
             }
 
-            if lower_tick == upper_tick {
-                return Err(InvariantError::InvalidTickIndex);
+            if liquidity.is_zero() || liquidity >= position.liquidity {
+                return Err(InvariantError::InvalidSplitLiquidity);
             }
 
+            let pool_key = position.pool_key;
             let mut pool = self.pools.get(pool_key)?;
+            pool.ensure_not_closed()?;
+            let lower_tick = self.ticks.get(pool_key, position.lower_tick_index)?;
+            let upper_tick = self.ticks.get(pool_key, position.upper_tick_index)?;
 
-            let mut lower_tick = self
-                .ticks
-                .get(pool_key, lower_tick)
-                .unwrap_or_else(|_| Self::create_tick(self, pool_key, lower_tick).unwrap());
-
-            let mut upper_tick = self
-                .ticks
-                .get(pool_key, upper_tick)
-                .unwrap_or_else(|_| Self::create_tick(self, pool_key, upper_tick).unwrap());
+            self.call_before_move_liquidity_hooks(
+                pool.hook,
+                caller,
+                receiver,
+                pool_key,
+                lower_tick.index,
+                upper_tick.index,
+                liquidity,
+            )?;
 
-            let (position, x, y) = Position::create(
+            position.checkpoint(
                 &mut pool,
-                pool_key,
-                &mut lower_tick,
-                &mut upper_tick,
-                current_timestamp_in_milliseconds,
-                liquidity_delta,
-                slippage_limit_lower,
-                slippage_limit_upper,
+                &lower_tick,
+                &upper_tick,
+                current_timestamp,
                 current_block_number,
-                pool_key.fee_tier.tick_spacing,
             )?;
+            let new_position = position.split(liquidity, current_timestamp_in_milliseconds)?;
 
             self.pools.update(pool_key, &pool)?;
+            self.positions.update(caller, index, &position)?;
 
-            self.positions.add(caller, &position);
-
-            self.ticks.update(pool_key, lower_tick.index, &lower_tick)?;
-            self.ticks.update(pool_key, upper_tick.index, &upper_tick)?;
+            let receiver_index = self.positions.get_length(receiver);
+            self.positions.add(receiver, &new_position);
 
-            transfer_from_v1!(pool_key.token_x, caller, contract, x.get());
-            transfer_from_v1!(pool_key.token_y, caller, contract, y.get());
+            self.emit_split_position_event(
+                caller,
+                receiver,
+                pool_key,
+                index,
+                receiver_index,
+                liquidity,
+            );
 
-            self.emit_create_position_event(
+            self.call_after_move_liquidity_hooks(
+                pool.hook,
                 caller,
+                receiver,
                 pool_key,
-                liquidity_delta,
                 lower_tick.index,
                 upper_tick.index,
-                pool.sqrt_price,
+                liquidity,
             );
-            Ok(position)
+            Ok(receiver_index)
         }
 
         #[ink(message)]
-        fn change_liquidity(
-            &mut self,
-            index: u32,
-            delta_liquidity: Liquidity,
-            add_liquidity: bool,
-            slippage_limit_lower: SqrtPrice,
-            slippage_limit_upper: SqrtPrice,
-        ) -> Result<(), InvariantError> {
+        fn merge_positions(&mut self, index_a: u32, index_b: u32) -> Result<u32, InvariantError> {
             let caller = self.env().caller();
             let current_timestamp = self.get_timestamp();
             let current_block_number = self.env().block_number() as u64;
-            let contract = self.env().account_id();
 
-            let mut position = self.positions.get(caller, index)?;
-            let pool_key = position.pool_key;
-            let mut pool = self.pools.get(pool_key)?;
-            let mut lower_tick = self.ticks.get(pool_key, position.lower_tick_index)?;
-            let mut upper_tick = self.ticks.get(pool_key, position.upper_tick_index)?;
+            let mut position_a = self.positions.get(caller, index_a)?;
+            let mut position_b = self.positions.get(caller, index_b)?;
 
-            if !add_liquidity && delta_liquidity == position.liquidity {
-                return Err(InvariantError::ZeroLiquidity);
+            if index_a == index_b
+                || position_a.pool_key != position_b.pool_key
+                || position_a.lower_tick_index != position_b.lower_tick_index
+                || position_a.upper_tick_index != position_b.upper_tick_index
+            {
+                return Err(InvariantError::PositionsNotMergeable);
             }
 
-            if delta_liquidity.get() == 0 {
-                return Err(InvariantError::LiquidityChangeZero);
-            }
+            let pool_key = position_a.pool_key;
+            let mut pool = self.pools.get(pool_key)?;
+            pool.ensure_not_closed()?;
+            let lower_tick = self.ticks.get(pool_key, position_a.lower_tick_index)?;
+            let upper_tick = self.ticks.get(pool_key, position_a.upper_tick_index)?;
 
-            if pool.sqrt_price < slippage_limit_lower || pool.sqrt_price > slippage_limit_upper {
-                return Err(InvariantError::PriceLimitReached);
-            }
+            let moved_liquidity = position_b.liquidity;
+            self.call_before_move_liquidity_hooks(
+                pool.hook,
+                caller,
+                caller,
+                pool_key,
+                lower_tick.index,
+                upper_tick.index,
+                moved_liquidity,
+            )?;
 
-            position.update_seconds_per_liquidity(
+            position_a.checkpoint(
                 &mut pool,
-                lower_tick,
-                upper_tick,
+                &lower_tick,
+                &upper_tick,
                 current_timestamp,
                 current_block_number,
-            );
-
-            let (x, y) = unwrap!(position.modify(
+            )?;
+            position_b.checkpoint(
                 &mut pool,
-                &mut upper_tick,
-                &mut lower_tick,
-                delta_liquidity,
-                add_liquidity,
+                &lower_tick,
+                &upper_tick,
                 current_timestamp,
-                pool_key.fee_tier.tick_spacing,
-            ));
+                current_block_number,
+            )?;
+            position_a.merge(&position_b)?;
 
             self.pools.update(pool_key, &pool)?;
-            self.positions.update(caller, index, &position)?;
-            self.ticks.update(pool_key, lower_tick.index, &lower_tick)?;
-            self.ticks.update(pool_key, upper_tick.index, &upper_tick)?;
-
-            let x_is_zero = x.get() == 0;
-            let y_is_zero = y.get() == 0;
-
-            if y_is_zero && x_is_zero {
-                return Err(InvariantError::AmountIsZero);
-            }
-
-            if !x_is_zero {
-                if add_liquidity {
-                    transfer_from_v1!(pool_key.token_x, caller, contract, x.get());
-                } else {
-                    transfer_v1!(pool_key.token_x, caller, x.get());
-                }
-            }
-
-            if !y_is_zero {
-                if add_liquidity {
-                    transfer_from_v1!(pool_key.token_y, caller, contract, y.get());
-                } else {
-                    transfer_v1!(pool_key.token_y, caller, y.get());
-                }
-            }
-
-            self.emit_change_liquidity_event(
-                caller,
-                pool_key,
-                delta_liquidity,
-                add_liquidity,
-                lower_tick.index,
-                upper_tick.index,
-                pool.sqrt_price,
-            );
-
-            Ok(())
-        }
-
-        #[ink(message)]
-        fn swap(
-            &mut self,
-            pool_key: PoolKey,
-            x_to_y: bool,
-            amount: TokenAmount,
-            by_amount_in: bool,
-            sqrt_price_limit: SqrtPrice,
-        ) -> Result<CalculateSwapResult, InvariantError> {
-            let caller = self.env().caller();
-            let contract = self.env().account_id();
-
-            let calculate_swap_result =
-                self.calculate_swap(pool_key, x_to_y, amount, by_amount_in, sqrt_price_limit)?;
-
-            let mut crossed_tick_indexes: Vec<i32> = vec![];
-
-            for tick in calculate_swap_result.ticks.iter() {
-                self.ticks.update(pool_key, tick.index, tick)?;
-                crossed_tick_indexes.push(tick.index);
-            }
-
-            if !crossed_tick_indexes.is_empty() {
-                self.emit_cross_tick_event(caller, pool_key, crossed_tick_indexes);
-            }
-
-            self.pools.update(pool_key, &calculate_swap_result.pool)?;
+            self.positions.update(caller, index_a, &position_a)?;
 
-            if x_to_y {
-                transfer_from_v1!(
-                    pool_key.token_x,
-                    caller,
-                    contract,
-                    calculate_swap_result.amount_in.get()
-                );
-                transfer_v1!(
-                    pool_key.token_y,
-                    caller,
-                    calculate_swap_result.amount_out.get()
-                );
+            // removing moves the last position into the freed index
+            let last_index = self.positions.get_length(caller) - 1;
+            self.positions.remove(caller, index_b)?;
+            let index = if index_a == last_index {
+                index_b
             } else {
-                transfer_from_v1!(
-                    pool_key.token_y,
-                    caller,
-                    contract,
-                    calculate_swap_result.amount_in.get()
-                );
-                transfer_v1!(
-                    pool_key.token_x,
-                    caller,
-                    calculate_swap_result.amount_out.get()
-                );
+                index_a
             };
 
-            self.emit_swap_event(
+            self.emit_merge_positions_event(
                 caller,
                 pool_key,
-                calculate_swap_result.amount_in,
-                calculate_swap_result.amount_out,
-                calculate_swap_result.fee,
-                calculate_swap_result.start_sqrt_price,
-                calculate_swap_result.target_sqrt_price,
-                x_to_y,
+                index_a,
+                index_b,
+                index,
+                position_a.liquidity,
             );
 
-            Ok(calculate_swap_result)
+            self.call_after_move_liquidity_hooks(
+                pool.hook,
+                caller,
+                caller,
+                pool_key,
+                lower_tick.index,
+                upper_tick.index,
+                moved_liquidity,
+            );
+            Ok(index)
         }
 
         #[ink(message)]
-        fn swap_route(
-            &mut self,
-            amount_in: TokenAmount,
-            expected_amount_out: TokenAmount,
-            slippage: Percentage,
-            swaps: Vec<SwapHop>,
-        ) -> Result<(), InvariantError> {
-            let amount_out = self.route_mut(amount_in, swaps)?;
+        fn lock_position(&mut self, index: u32, locked_until: u64) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+            let current_timestamp = self.get_timestamp();
 
-            let min_amount_out = calculate_min_amount_out(expected_amount_out, slippage);
+            let mut position = self.positions.get(caller, index)?;
+            self.pools.get(position.pool_key)?.ensure_not_closed()?;
+            position.lock(locked_until, current_timestamp)?;
 
-            if amount_out < min_amount_out {
-                return Err(InvariantError::AmountUnderMinimumAmountOut);
-            }
+            self.positions.update(caller, index, &position)?;
+            self.locked_positions.add_owner(position.pool_key, caller)?;
 
+            self.emit_lock_position_event(caller, position.pool_key, index, locked_until);
             Ok(())
         }
 
         #[ink(message)]
-        fn quote(
+        fn get_locked_positions(
             &self,
             pool_key: PoolKey,
-            x_to_y: bool,
-            amount: TokenAmount,
-            by_amount_in: bool,
-            sqrt_price_limit: SqrtPrice,
-        ) -> Result<QuoteResult, InvariantError> {
-            let calculate_swap_result =
-                self.calculate_swap(pool_key, x_to_y, amount, by_amount_in, sqrt_price_limit)?;
-
-            Ok(QuoteResult {
-                amount_in: calculate_swap_result.amount_in,
-                amount_out: calculate_swap_result.amount_out,
-                target_sqrt_price: calculate_swap_result.pool.sqrt_price,
-                ticks: calculate_swap_result.ticks,
-            })
-        }
-
-        #[ink(message)]
-        fn quote_route(
-            &self,
-            amount_in: TokenAmount,
-            swaps: Vec<SwapHop>,
-        ) -> Result<TokenAmount, InvariantError> {
-            let amount_out = self.route(amount_in, swaps)?;
-
-            Ok(amount_out)
-        }
-
-        #[ink(message)]
-        fn transfer_position(
-            &mut self,
-            index: u32,
-            receiver: AccountId,
-        ) -> Result<(), InvariantError> {
-            let caller = self.env().caller();
-
-            self.positions.transfer(caller, index, receiver)?;
-
-            Ok(())
+            size: u32,
+            offset: u32,
+        ) -> Vec<(AccountId, u32, Position)> {
+            let current_timestamp = self.get_timestamp();
+            let size = size.min(MAX_POSITIONS_RETURNED) as usize;
+
+            self.locked_positions
+                .get_owners(pool_key)
+                .into_iter()
+                .flat_map(|owner| {
+                    (0..self.positions.get_length(owner)).filter_map(move |index| {
+                        self.positions
+                            .get(owner, index)
+                            .ok()
+                            .map(|position| (owner, index, position))
+                    })
+                })
+                .filter(|(_, _, position)| {
+                    position.pool_key == pool_key && position.is_locked(current_timestamp)
+                })
+                .skip(offset as usize)
+                .take(size)
+                .collect()
         }
 
         #[ink(message)]
@@ -935,13 +3210,14 @@ pub mod invariant {
                 .get(position.pool_key, position.upper_tick_index)?;
 
             let mut pool = self.pools.get(position.pool_key)?;
+            pool.ensure_not_closed()?;
 
             let (x, y) = position.claim_fee(
                 &mut pool,
                 &mut upper_tick,
                 &mut lower_tick,
                 current_timestamp,
-            );
+            )?;
 
             self.positions.update(caller, index, &position)?;
             self.pools.update(position.pool_key, &pool)?;
@@ -958,9 +3234,46 @@ pub mod invariant {
                 transfer_v1!(position.pool_key.token_y, caller, y.get());
             }
 
+            self.emit_claim_fee_event(caller, position.pool_key, index, x, y);
             Ok((x, y))
         }
 
+        #[ink(message)]
+        fn donate(
+            &mut self,
+            pool_key: PoolKey,
+            amount_x: TokenAmount,
+            amount_y: TokenAmount,
+        ) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+            let contract = self.env().account_id();
+
+            if amount_x.is_zero() && amount_y.is_zero() {
+                return Err(InvariantError::AmountIsZero);
+            }
+
+            let mut pool = self.pools.get(pool_key)?;
+            pool.ensure_not_closed()?;
+
+            if pool.liquidity.is_zero() {
+                return Err(InvariantError::ZeroLiquidity);
+            }
+
+            pool.donate(amount_x, amount_y)?;
+            self.pools.update(pool_key, &pool)?;
+
+            if amount_x.get() > 0 {
+                transfer_from_v1!(pool_key.token_x, caller, contract, amount_x.get());
+            }
+
+            if amount_y.get() > 0 {
+                transfer_from_v1!(pool_key.token_y, caller, contract, amount_y.get());
+            }
+
+            self.emit_donate_event(caller, pool_key, amount_x, amount_y, pool.liquidity);
+            Ok(())
+        }
+
         #[ink(message)]
         fn remove_position(
             &mut self,
@@ -970,6 +3283,9 @@ pub mod invariant {
             let current_timestamp = self.get_timestamp();
 
             let mut position = self.positions.get(caller, index)?;
+            if position.is_locked(current_timestamp) {
+                return Err(InvariantError::PositionLocked);
+            }
             let withdrawed_liquidity = position.liquidity;
 
             let mut lower_tick = self
@@ -980,16 +3296,27 @@ pub mod invariant {
                 .ticks
                 .get(position.pool_key, position.upper_tick_index)?;
 
+            // removing is allowed in every pool status, it is the only way out of a closed pool
             let pool = &mut self.pools.get(position.pool_key)?;
 
+            self.call_before_modify_position_hook(
+                pool.hook,
+                caller,
+                position.pool_key,
+                position.lower_tick_index,
+                position.upper_tick_index,
+                withdrawed_liquidity,
+                false,
+            )?;
+
             let (amount_x, amount_y, deinitialize_lower_tick, deinitialize_upper_tick) = position
                 .remove(
-                    pool,
-                    current_timestamp,
-                    &mut lower_tick,
-                    &mut upper_tick,
-                    position.pool_key.fee_tier.tick_spacing,
-                );
+                pool,
+                current_timestamp,
+                &mut lower_tick,
+                &mut upper_tick,
+                position.pool_key.fee_tier.tick_spacing,
+            )?;
 
             self.pools.update(position.pool_key, pool)?;
 
@@ -1015,10 +3342,24 @@ pub mod invariant {
             self.emit_remove_position_event(
                 caller,
                 position.pool_key,
+                index,
                 withdrawed_liquidity,
                 lower_tick.index,
                 upper_tick.index,
                 pool.sqrt_price,
+                amount_x,
+                amount_y,
+            );
+
+            self.call_after_modify_position_hook(
+                pool.hook,
+                caller,
+                position.pool_key,
+                lower_tick.index,
+                upper_tick.index,
+                withdrawed_liquidity,
+                false,
+                (amount_x, amount_y),
             );
             Ok((amount_x, amount_y))
         }
@@ -1041,6 +3382,7 @@ pub mod invariant {
 
             self.fee_tiers.add(fee_tier)?;
 
+            self.emit_add_fee_tier_event(caller, fee_tier);
             Ok(())
         }
 
@@ -1054,6 +3396,7 @@ pub mod invariant {
 
             self.fee_tiers.remove(fee_tier)?;
 
+            self.emit_remove_fee_tier_event(caller, fee_tier);
             Ok(())
         }
 
@@ -1063,7 +3406,7 @@ pub mod invariant {
         }
 
         // Pools
-        #[ink(message)]
+        #[ink(message, payable)]
         fn create_pool(
             &mut self,
             token_0: AccountId,
@@ -1072,32 +3415,61 @@ pub mod invariant {
             init_sqrt_price: SqrtPrice,
             init_tick: i32,
         ) -> Result<(), InvariantError> {
-            let current_timestamp = self.get_timestamp();
-
-            if !self.fee_tiers.contains(fee_tier) {
-                return Err(InvariantError::FeeTierNotFound);
-            };
-
-            check_tick(init_tick, fee_tier.tick_spacing)
-                .map_err(|_| InvariantError::InvalidInitTick)?;
+            self.add_pool(token_0, token_1, fee_tier, init_sqrt_price, init_tick, None)?;
+            Ok(())
+        }
 
-            let pool_key = PoolKey::new(token_0, token_1, fee_tier)?;
-            if self.pools.get(pool_key).is_ok() {
-                return Err(InvariantError::PoolAlreadyExist);
-            };
-            let pool = Pool::create(
+        #[ink(message, payable)]
+        fn create_pool_with_hook(
+            &mut self,
+            token_0: AccountId,
+            token_1: AccountId,
+            fee_tier: FeeTier,
+            init_sqrt_price: SqrtPrice,
+            init_tick: i32,
+            hook: PoolHook,
+        ) -> Result<(), InvariantError> {
+            let hook = PoolHook::new(hook.address, hook.flags)?;
+            self.add_pool(
+                token_0,
+                token_1,
+                fee_tier,
                 init_sqrt_price,
                 init_tick,
-                current_timestamp,
-                fee_tier.tick_spacing,
-                self.config.admin,
+                Some(hook),
             )?;
-            self.pools.add(pool_key, &pool)?;
-            self.pool_keys.add(pool_key)?;
-
             Ok(())
         }
 
+        #[ink(message, payable)]
+        fn create_pool_with_position(
+            &mut self,
+            token_0: AccountId,
+            token_1: AccountId,
+            fee_tier: FeeTier,
+            init_sqrt_price: SqrtPrice,
+            lower_tick: i32,
+            upper_tick: i32,
+            liquidity_delta: Liquidity,
+        ) -> Result<Position, InvariantError> {
+            let init_tick = get_tick_at_sqrt_price(init_sqrt_price, fee_tier.tick_spacing)
+                .map_err(|_| InvariantError::InvalidInitSqrtPrice)?;
+
+            let pool_key =
+                self.add_pool(token_0, token_1, fee_tier, init_sqrt_price, init_tick, None)?;
+
+            self.add_position(
+                pool_key,
+                lower_tick,
+                upper_tick,
+                liquidity_delta,
+                init_sqrt_price,
+                init_sqrt_price,
+                (TokenAmount::new(0), TokenAmount::new(0)),
+                (TokenAmount::max_instance(), TokenAmount::max_instance()),
+            )
+        }
+
         #[ink(message)]
         fn get_pool(
             &self,
@@ -1111,13 +3483,20 @@ pub mod invariant {
             Ok(pool)
         }
 
+        #[ink(message)]
+        fn get_pool_stats(&self, pool_key: PoolKey) -> Result<PoolStats, InvariantError> {
+            self.pools.get(pool_key)?;
+
+            Ok(self.pool_statistics.get(pool_key))
+        }
+
         #[ink(message)]
         fn get_all_pools_for_pair(
             &self,
             token0: AccountId,
             token1: AccountId,
         ) -> Result<Vec<(FeeTier, Pool)>, InvariantError> {
-            let fee_tiers = self.fee_tiers.get_all();
+            let fee_tiers = self.fee_tiers.get_all()?;
             let mut pools: Vec<(FeeTier, Pool)> = vec![];
             for fee_tier in fee_tiers {
                 let pool_key = PoolKey::new(token0, token1, fee_tier)?;
@@ -1150,7 +3529,42 @@ pub mod invariant {
         }
 
         #[ink(message)]
-        fn get_fee_tiers(&self) -> Vec<FeeTier> {
+        fn get_pool_keys_with_status(
+            &self,
+            size: u16,
+            offset: u16,
+            curated: Option<bool>,
+        ) -> Result<(Vec<(PoolKey, PoolStatus)>, u16), InvariantError> {
+            let size = size.min(MAX_POOL_KEYS_WITH_STATUS_RETURNED);
+
+            let Some(curated) = curated else {
+                let mut pool_keys = vec![];
+                for pool_key in self.pool_keys.get_all(size, offset) {
+                    pool_keys.push((pool_key, self.pools.get(pool_key)?.status));
+                }
+                return Ok((pool_keys, self.pool_keys.count()));
+            };
+
+            // curation is not indexed, so the pages are taken from all of the matching pools
+            let mut pool_keys = vec![];
+            let mut pool_keys_count: u16 = 0;
+            for index in 0..self.pool_keys.count() {
+                let pool_key = self.pool_keys.get(index)?;
+                let pool = self.pools.get(pool_key)?;
+                if pool.curated != curated {
+                    continue;
+                }
+
+                if pool_keys_count >= offset && pool_keys.len() < size as usize {
+                    pool_keys.push((pool_key, pool.status));
+                }
+                pool_keys_count += 1;
+            }
+            Ok((pool_keys, pool_keys_count))
+        }
+
+        #[ink(message)]
+        fn get_fee_tiers(&self) -> Result<Vec<FeeTier>, InvariantError> {
             self.fee_tiers.get_all()
         }
 
@@ -1184,76 +3598,295 @@ pub mod invariant {
             let (start_chunk, _) = tick_to_position(lower_tick_index, tick_spacing);
             let (end_chunk, _) = tick_to_position(upper_tick_index, tick_spacing);
 
-            self.tickmap_slice(start_chunk, end_chunk, x_to_y, pool_key)
+            self.tickmap_slice(start_chunk, end_chunk, x_to_y, pool_key)
+        }
+
+        #[ink(message)]
+        fn get_liquidity_ticks(
+            &self,
+            pool_key: PoolKey,
+            tickmap: Vec<i32>,
+        ) -> Result<Vec<LiquidityTick>, InvariantError> {
+            let mut liqudity_ticks: Vec<LiquidityTick> = vec![];
+
+            if tickmap.len() > LIQUIDITY_TICK_LIMIT {
+                return Err(InvariantError::TickLimitReached);
+            }
+
+            for index in tickmap {
+                let tick = LiquidityTick::from(self.ticks.get(pool_key, index).unwrap());
+
+                liqudity_ticks.push(tick);
+            }
+
+            Ok(liqudity_ticks)
+        }
+
+        #[ink(message)]
+        fn get_user_position_amount(&self, owner: AccountId) -> u32 {
+            self.positions.get_length(owner)
+        }
+
+        #[ink(message)]
+        fn withdraw_all_wazero(&self, address: AccountId) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+            let contract = self.env().account_id();
+
+            #[cfg(not(feature = "dev"))]
+            {
+                let wazero = AccountId::from([
+                    36, 194, 34, 48, 111, 252, 26, 13, 113, 175, 229, 187, 186, 36, 84, 83, 9, 31,
+                    24, 227, 253, 232, 49, 168, 168, 78, 169, 46, 66, 157, 224, 0,
+                ]);
+
+                if address != wazero {
+                    return Err(InvariantError::WAZEROIncorrectMainnetAddressId);
+                }
+            }
+
+            let balance = balance_of_v1!(address, caller);
+            if balance > 0 {
+                transfer_from_v1!(address, caller, contract, balance);
+                withdraw_v1!(address, balance);
+                self.env()
+                    .transfer(caller, balance)
+                    .map_err(|_| InvariantError::TransferError)?;
+            }
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn set_code(&mut self, code_hash: Hash) -> Result<(), InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            ink::env::set_code_hash::<DefaultEnvironment>(&code_hash)
+                .map_err(|_| InvariantError::SetCodeHashError)?;
+
+            self.emit_set_code_event(caller, code_hash);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn get_storage_version(&self) -> u32 {
+            self.config.storage_version
+        }
+
+        #[ink(message)]
+        fn migrate(&mut self, batch_size: u32) -> Result<bool, InvariantError> {
+            let caller = self.env().caller();
+
+            if caller != self.config.admin {
+                return Err(InvariantError::NotAdmin);
+            }
+
+            // every step converts the records of one version to the next one
+            let (migrated, completed) = match self.config.storage_version {
+                0 => self.migrate_legacy_pools(batch_size)?,
+                1 => self.fee_tiers.migrate(batch_size)?,
+                _ => return Err(InvariantError::AlreadyMigrated),
+            };
+
+            if completed {
+                self.config.storage_version += 1;
+                self.config.migration_cursor = 0;
+            } else {
+                self.config.migration_cursor += migrated;
+            }
+
+            self.emit_migrate_event(caller, self.config.storage_version, migrated);
+            Ok(self.config.storage_version == STORAGE_VERSION)
         }
 
         #[ink(message)]
-        fn get_liquidity_ticks(
-            &self,
+        fn create_incentive(
+            &mut self,
             pool_key: PoolKey,
-            tickmap: Vec<i32>,
-        ) -> Result<Vec<LiquidityTick>, InvariantError> {
-            let mut liqudity_ticks: Vec<LiquidityTick> = vec![];
+            reward_token: AccountId,
+            reward: TokenAmount,
+            start_timestamp: u64,
+            end_timestamp: u64,
+        ) -> Result<u32, InvariantError> {
+            let caller = self.env().caller();
+            let contract = self.env().account_id();
+            let current_timestamp = self.get_timestamp();
 
-            if tickmap.len() > LIQUIDITY_TICK_LIMIT {
-                return Err(InvariantError::TickLimitReached);
-            }
+            self.pools.get(pool_key)?;
 
-            for index in tickmap {
-                let tick = LiquidityTick::from(self.ticks.get(pool_key, index).unwrap());
+            if reward.is_zero() {
+                return Err(InvariantError::AmountIsZero);
+            }
 
-                liqudity_ticks.push(tick);
+            if end_timestamp <= start_timestamp || end_timestamp <= current_timestamp {
+                return Err(InvariantError::InvalidIncentiveTime);
             }
 
-            Ok(liqudity_ticks)
+            let incentive = Incentive {
+                pool_key,
+                reward_token,
+                funder: caller,
+                total_reward: reward,
+                total_reward_unclaimed: reward,
+                total_seconds_claimed: 0,
+                start_timestamp,
+                end_timestamp,
+                number_of_stakes: 0,
+            };
+            let incentive_id = self.incentives.add(&incentive)?;
+
+            transfer_from_v1!(reward_token, caller, contract, reward.get());
+
+            self.emit_create_incentive_event(
+                caller,
+                pool_key,
+                incentive_id,
+                reward_token,
+                reward,
+                start_timestamp,
+                end_timestamp,
+            );
+            Ok(incentive_id)
         }
 
         #[ink(message)]
-        fn get_user_position_amount(&self, owner: AccountId) -> u32 {
-            self.positions.get_length(owner)
+        fn end_incentive(&mut self, incentive_id: u32) -> Result<TokenAmount, InvariantError> {
+            let current_timestamp = self.get_timestamp();
+            let mut incentive = self.incentives.get(incentive_id)?;
+
+            if current_timestamp < incentive.end_timestamp {
+                return Err(InvariantError::IncentiveNotEnded);
+            }
+
+            // rewards earned by stakes are only paid out when they are claimed or unstaked
+            if incentive.number_of_stakes != 0 {
+                return Err(InvariantError::IncentiveHasStakes);
+            }
+
+            let refund = incentive.total_reward_unclaimed;
+            incentive.total_reward_unclaimed = TokenAmount(0);
+            self.incentives.update(incentive_id, &incentive)?;
+
+            if !refund.is_zero() {
+                transfer_v1!(incentive.reward_token, incentive.funder, refund.get());
+            }
+
+            self.emit_end_incentive_event(
+                incentive.funder,
+                incentive.pool_key,
+                incentive_id,
+                refund,
+            );
+            Ok(refund)
         }
 
         #[ink(message)]
-        fn withdraw_all_wazero(&self, address: AccountId) -> Result<(), InvariantError> {
+        fn stake_position(&mut self, index: u32, incentive_id: u32) -> Result<u32, InvariantError> {
             let caller = self.env().caller();
-            let contract = self.env().account_id();
+            let current_timestamp = self.get_timestamp();
 
-            #[cfg(not(feature = "dev"))]
-            {
-                let wazero = AccountId::from([
-                    36, 194, 34, 48, 111, 252, 26, 13, 113, 175, 229, 187, 186, 36, 84, 83, 9, 31,
-                    24, 227, 253, 232, 49, 168, 168, 78, 169, 46, 66, 157, 224, 0,
-                ]);
+            let mut incentive = self.incentives.get(incentive_id)?;
+            let position = self.positions.get(caller, index)?;
 
-                if address != wazero {
-                    return Err(InvariantError::WAZEROIncorrectMainnetAddressId);
-                }
+            if !incentive.is_active(current_timestamp) {
+                return Err(InvariantError::IncentiveNotActive);
             }
 
-            let balance = balance_of_v1!(address, caller);
-            if balance > 0 {
-                transfer_from_v1!(address, caller, contract, balance);
-                withdraw_v1!(address, balance);
-                self.env()
-                    .transfer(caller, balance)
-                    .map_err(|_| InvariantError::TransferError)?;
+            if position.pool_key != incentive.pool_key {
+                return Err(InvariantError::InvalidIncentivePool);
             }
 
-            Ok(())
+            self.pools.get(position.pool_key)?.ensure_not_closed()?;
+
+            if position.liquidity.is_zero() {
+                return Err(InvariantError::ZeroLiquidity);
+            }
+
+            let seconds_per_liquidity_inside =
+                self.position_seconds_per_liquidity_inside(&position)?;
+
+            incentive.number_of_stakes =
+                incentive
+                    .number_of_stakes
+                    .checked_add(1)
+                    .ok_or(InvariantError::AddOverflow(
+                        incentive.number_of_stakes as u128,
+                        1,
+                    ))?;
+            self.incentives.update(incentive_id, &incentive)?;
+
+            self.positions.remove(caller, index)?;
+            let stake_index = self.stakes.add(
+                caller,
+                &Stake {
+                    incentive_id,
+                    seconds_per_liquidity_inside_initial: seconds_per_liquidity_inside,
+                    position,
+                },
+            )?;
+
+            self.emit_stake_position_event(
+                caller,
+                position.pool_key,
+                incentive_id,
+                stake_index,
+                position.liquidity,
+            );
+            Ok(stake_index)
         }
 
         #[ink(message)]
-        fn set_code(&mut self, code_hash: Hash) -> Result<(), InvariantError> {
+        fn claim_incentive_reward(&mut self, index: u32) -> Result<TokenAmount, InvariantError> {
             let caller = self.env().caller();
 
-            if caller != self.config.admin {
-                return Err(InvariantError::NotAdmin);
+            let mut stake = self.stakes.get(caller, index)?;
+            let reward = self.claim_stake_reward(caller, &mut stake)?;
+            self.stakes.update(caller, index, &stake)?;
+
+            self.emit_claim_incentive_reward_event(
+                caller,
+                stake.position.pool_key,
+                stake.incentive_id,
+                index,
+                reward,
+            );
+            Ok(reward)
+        }
+
+        #[ink(message)]
+        fn unstake_position(&mut self, index: u32) -> Result<TokenAmount, InvariantError> {
+            let caller = self.env().caller();
+            self.unstake(caller, index)
+        }
+
+        #[ink(message)]
+        fn force_unstake_position(
+            &mut self,
+            owner: AccountId,
+            index: u32,
+        ) -> Result<TokenAmount, InvariantError> {
+            let current_timestamp = self.get_timestamp();
+
+            let stake = self.stakes.get(owner, index)?;
+            let incentive = self.incentives.get(stake.incentive_id)?;
+            if current_timestamp < incentive.end_timestamp {
+                return Err(InvariantError::IncentiveNotEnded);
             }
 
-            ink::env::set_code_hash::<DefaultEnvironment>(&code_hash)
-                .map_err(|_| InvariantError::SetCodeHashError)?;
+            self.unstake(owner, index)
+        }
 
-            Ok(())
+        #[ink(message)]
+        fn get_incentive(&self, incentive_id: u32) -> Result<Incentive, InvariantError> {
+            self.incentives.get(incentive_id)
+        }
+
+        #[ink(message)]
+        fn get_stake(&self, owner: AccountId, index: u32) -> Result<Stake, InvariantError> {
+            self.stakes.get(owner, index)
         }
 
         #[ink(message)]
@@ -1274,6 +3907,7 @@ pub mod invariant {
             let upper_tick = self.ticks.get(pool_key, position.upper_tick_index)?;
 
             let pool = &mut self.pools.get(pool_key)?;
+            pool.ensure_not_closed()?;
 
             position.update_seconds_per_liquidity(
                 pool,
@@ -1302,6 +3936,8 @@ pub mod invariant {
             }
 
             self.config.admin = new_admin;
+
+            self.emit_change_admin_event(caller, new_admin);
             Ok(())
         }
     }
@@ -1309,89 +3945,377 @@ pub mod invariant {
     #[cfg(test)]
     mod tests {
 
-        use super::*;
+        use super::*;
+
+        use crate::contracts::MathError;
+        use crate::math::consts::MAX_TICK;
+        use crate::math::fee_growth::FeeGrowth;
+        use crate::math::percentage::Percentage;
+        use crate::math::sqrt_price::calculate_sqrt_price;
+        use crate::math::MAX_SWAP_STEPS;
+        use ink::env::test::{recorded_events, EmittedEvent};
+        use ink::scale::{Decode, Encode};
+
+        #[ink::test]
+        fn initialize_works() {
+            let _ = Invariant::new(Percentage::new(0));
+        }
+
+        #[ink::test]
+        fn test_add_pool() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let fee_tier = FeeTier {
+                fee: Percentage::new(1),
+                tick_spacing: 1,
+            };
+
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+
+            contract.add_fee_tier(fee_tier).unwrap();
+
+            let result = contract.create_pool(
+                token_0,
+                token_1,
+                FeeTier {
+                    fee: Percentage::new(1),
+                    tick_spacing: 1,
+                },
+                init_sqrt_price,
+                0,
+            );
+            assert!(result.is_ok());
+            let result = contract.create_pool(
+                token_1,
+                token_0,
+                FeeTier {
+                    fee: Percentage::new(1),
+                    tick_spacing: 1,
+                },
+                init_sqrt_price,
+                0,
+            );
+            assert_eq!(result, Err(InvariantError::PoolAlreadyExist));
+        }
+
+        #[ink::test]
+        fn test_get_pool() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+            let result = contract.get_pool(
+                token_1,
+                token_0,
+                FeeTier {
+                    fee: Percentage::new(1),
+                    tick_spacing: 1,
+                },
+            );
+            assert_eq!(result, Err(InvariantError::PoolNotFound));
+
+            let fee_tier = FeeTier {
+                fee: Percentage::new(1),
+                tick_spacing: 1,
+            };
+
+            contract.add_fee_tier(fee_tier).unwrap();
+
+            let result = contract.create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0);
+            assert!(result.is_ok());
+            let result = contract.get_pool(
+                token_1,
+                token_0,
+                FeeTier {
+                    fee: Percentage::new(1),
+                    tick_spacing: 1,
+                },
+            );
+            assert!(result.is_ok());
+        }
+
+        #[ink::test]
+        fn test_change_pool_status() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+            let fee_tier = FeeTier {
+                fee: Percentage::new(1),
+                tick_spacing: 1,
+            };
+            let pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            contract
+                .create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0)
+                .unwrap();
+            assert_eq!(
+                contract.get_pool_keys_with_status(1, 0, None),
+                Ok((vec![(pool_key, PoolStatus::Active)], 1))
+            );
+
+            contract
+                .change_pool_status(pool_key, PoolStatus::WithdrawOnly)
+                .unwrap();
+            let pool = contract.get_pool(token_0, token_1, fee_tier).unwrap();
+            assert_eq!(pool.status, PoolStatus::WithdrawOnly);
+            assert_eq!(
+                contract.get_pool_keys_with_status(1, 0, None),
+                Ok((vec![(pool_key, PoolStatus::WithdrawOnly)], 1))
+            );
+
+            let result = contract.create_position(
+                pool_key,
+                -10,
+                10,
+                Liquidity::from_integer(1),
+                pool.sqrt_price,
+                pool.sqrt_price,
+            );
+            assert_eq!(result, Err(InvariantError::PoolNotActive));
+            let result = contract.quote(
+                pool_key,
+                true,
+                TokenAmount(10),
+                true,
+                SqrtPrice::new(MIN_SQRT_PRICE),
+            );
+            assert_eq!(result.unwrap_err(), InvariantError::PoolNotActive);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x03; 32]));
+            let result = contract.change_pool_status(pool_key, PoolStatus::Active);
+            assert_eq!(result, Err(InvariantError::NotAdmin));
+        }
+
+        #[ink::test]
+        fn test_token_statuses() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let token_2 = AccountId::from([0x03; 32]);
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+            let fee_tier = FeeTier {
+                fee: Percentage::new(1),
+                tick_spacing: 1,
+            };
+            contract.add_fee_tier(fee_tier).unwrap();
+
+            contract
+                .change_token_status(token_2, TokenStatus::Denied)
+                .unwrap();
+            let result = contract.create_pool(token_0, token_2, fee_tier, init_sqrt_price, 0);
+            assert_eq!(result, Err(InvariantError::TokenDenied));
+
+            contract
+                .change_token_status(token_0, TokenStatus::Allowed)
+                .unwrap();
+            contract
+                .change_token_status(token_1, TokenStatus::Allowed)
+                .unwrap();
+            assert_eq!(contract.get_token_status(token_0), TokenStatus::Allowed);
+            contract
+                .create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0)
+                .unwrap();
+            let pool = contract.get_pool(token_0, token_1, fee_tier).unwrap();
+            assert!(pool.curated);
+
+            let pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+            assert_eq!(
+                contract.get_pool_keys_with_status(1, 0, Some(false)),
+                Ok((vec![], 0))
+            );
+            contract.change_pool_curation(pool_key, false).unwrap();
+            assert_eq!(
+                contract.get_pool_keys_with_status(1, 0, Some(false)),
+                Ok((vec![(pool_key, PoolStatus::Active)], 1))
+            );
+        }
+
+        #[ink::test]
+        fn test_get_pool_keys_with_status_pages_matching_pools() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+
+            let pool_keys: Vec<PoolKey> = (1..=5)
+                .map(|tick_spacing| {
+                    let fee_tier = FeeTier::new(Percentage::new(1), tick_spacing).unwrap();
+                    contract.add_fee_tier(fee_tier).unwrap();
+                    contract
+                        .create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0)
+                        .unwrap();
+                    PoolKey::new(token_0, token_1, fee_tier).unwrap()
+                })
+                .collect();
+            for index in [1, 3, 4] {
+                contract
+                    .change_pool_curation(pool_keys[index], true)
+                    .unwrap();
+            }
+            let active = |index: usize| (pool_keys[index], PoolStatus::Active);
+
+            // the uncurated pool at index 2 falls between the first two pages
+            assert_eq!(
+                contract.get_pool_keys_with_status(2, 0, Some(true)),
+                Ok((vec![active(1), active(3)], 3))
+            );
+            assert_eq!(
+                contract.get_pool_keys_with_status(2, 2, Some(true)),
+                Ok((vec![active(4)], 3))
+            );
+            assert_eq!(
+                contract.get_pool_keys_with_status(2, 3, Some(true)),
+                Ok((vec![], 3))
+            );
+            assert_eq!(
+                contract.get_pool_keys_with_status(1, 1, Some(false)),
+                Ok((vec![active(2)], 2))
+            );
+            assert_eq!(
+                contract.get_pool_keys_with_status(2, 4, None),
+                Ok((vec![active(4)], 5))
+            );
+        }
+
+        #[ink::test]
+        fn test_create_position_by_amounts_limits() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+            let fee_tier = FeeTier {
+                fee: Percentage::new(1),
+                tick_spacing: 10,
+            };
+            let pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            contract
+                .create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0)
+                .unwrap();
+
+            let result = contract.create_position_by_amounts(
+                pool_key,
+                -20,
+                10,
+                TokenAmount(500),
+                TokenAmount(1000),
+                TokenAmount(501),
+                TokenAmount(0),
+                init_sqrt_price,
+                init_sqrt_price,
+            );
+            assert_eq!(result, Err(InvariantError::AmountUnderMinimumDeposit));
+
+            let result = contract.create_position_by_amounts(
+                pool_key,
+                10,
+                -20,
+                TokenAmount(500),
+                TokenAmount(1000),
+                TokenAmount(0),
+                TokenAmount(0),
+                init_sqrt_price,
+                init_sqrt_price,
+            );
+            assert_eq!(result, Err(InvariantError::InvalidTickIndex));
 
-        use crate::math::consts::MAX_TICK;
-        use crate::math::percentage::Percentage;
-        use crate::math::sqrt_price::calculate_sqrt_price;
+            let result = contract.create_position_by_amounts(
+                pool_key,
+                -20,
+                10,
+                TokenAmount(0),
+                TokenAmount(0),
+                TokenAmount(0),
+                TokenAmount(0),
+                init_sqrt_price,
+                init_sqrt_price,
+            );
+            assert_eq!(result, Err(InvariantError::ZeroLiquidity));
 
-        #[ink::test]
-        fn initialize_works() {
-            let _ = Invariant::new(Percentage::new(0));
+            let result = contract.create_position_by_amounts(
+                pool_key,
+                -20,
+                10,
+                TokenAmount(500),
+                TokenAmount(1000),
+                TokenAmount(0),
+                TokenAmount(0),
+                calculate_sqrt_price(1).unwrap(),
+                calculate_sqrt_price(2).unwrap(),
+            );
+            assert_eq!(result, Err(InvariantError::PriceLimitReached));
         }
 
         #[ink::test]
-        fn test_add_pool() {
+        fn test_create_pool_with_position_invalid_price() {
             let mut contract = Invariant::new(Percentage::new(0));
             let token_0 = AccountId::from([0x01; 32]);
             let token_1 = AccountId::from([0x02; 32]);
             let fee_tier = FeeTier {
                 fee: Percentage::new(1),
-                tick_spacing: 1,
+                tick_spacing: 10,
             };
-
-            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
-
             contract.add_fee_tier(fee_tier).unwrap();
 
-            let result = contract.create_pool(
+            let result = contract.create_pool_with_position(
                 token_0,
                 token_1,
-                FeeTier {
-                    fee: Percentage::new(1),
-                    tick_spacing: 1,
-                },
-                init_sqrt_price,
-                0,
+                fee_tier,
+                SqrtPrice::new(MAX_SQRT_PRICE + 1),
+                -10,
+                10,
+                Liquidity::from_integer(1),
             );
-            assert!(result.is_ok());
-            let result = contract.create_pool(
-                token_1,
-                token_0,
-                FeeTier {
-                    fee: Percentage::new(1),
-                    tick_spacing: 1,
-                },
-                init_sqrt_price,
-                0,
+            assert_eq!(result, Err(InvariantError::InvalidInitSqrtPrice));
+            assert_eq!(
+                contract.get_pool(token_0, token_1, fee_tier),
+                Err(InvariantError::PoolNotFound)
             );
-            assert_eq!(result, Err(InvariantError::PoolAlreadyExist));
         }
 
         #[ink::test]
-        fn test_get_pool() {
+        fn test_math_error_is_returned() {
             let mut contract = Invariant::new(Percentage::new(0));
             let token_0 = AccountId::from([0x01; 32]);
             let token_1 = AccountId::from([0x02; 32]);
             let init_sqrt_price = calculate_sqrt_price(0).unwrap();
-            let result = contract.get_pool(
-                token_1,
-                token_0,
-                FeeTier {
-                    fee: Percentage::new(1),
-                    tick_spacing: 1,
-                },
-            );
-            assert_eq!(result, Err(InvariantError::PoolNotFound));
-
             let fee_tier = FeeTier {
                 fee: Percentage::new(1),
                 tick_spacing: 1,
             };
+            let pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
 
             contract.add_fee_tier(fee_tier).unwrap();
+            contract
+                .create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0)
+                .unwrap();
 
-            let result = contract.create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0);
-            assert!(result.is_ok());
-            let result = contract.get_pool(
-                token_1,
-                token_0,
-                FeeTier {
-                    fee: Percentage::new(1),
-                    tick_spacing: 1,
-                },
+            let result = contract.create_position_by_amounts(
+                pool_key,
+                -1,
+                1,
+                TokenAmount::max_instance(),
+                TokenAmount::max_instance(),
+                TokenAmount(0),
+                TokenAmount(0),
+                init_sqrt_price,
+                init_sqrt_price,
             );
-            assert!(result.is_ok());
+            assert_eq!(result, Err(InvariantError::MathError(MathError::Overflow)));
+
+            let result = contract.create_position(
+                pool_key,
+                -1,
+                1,
+                Liquidity::max_instance(),
+                init_sqrt_price,
+                init_sqrt_price,
+            );
+            assert!(matches!(result, Err(InvariantError::MathError(_))));
         }
 
         #[ink::test]
@@ -1434,10 +4358,622 @@ pub mod invariant {
             };
 
             contract.add_fee_tier(fee_tier_value).unwrap();
-            assert_eq!(contract.fee_tiers.get_all().len(), 1);
+            assert_eq!(contract.fee_tiers.get_all().unwrap().len(), 1);
             contract.add_fee_tier(fee_tier_value).unwrap_err();
             contract.remove_fee_tier(fee_tier).unwrap();
-            assert_eq!(contract.fee_tiers.get_all().len(), 0);
+            assert_eq!(contract.fee_tiers.get_all().unwrap().len(), 0);
+        }
+
+        #[ink::test]
+        fn test_change_max_swap_steps() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            assert_eq!(contract.get_max_swap_steps(), MAX_SWAP_STEPS);
+
+            let result = contract.change_max_swap_steps(0);
+            assert_eq!(result, Err(InvariantError::InvalidMaxSwapSteps));
+
+            contract.change_max_swap_steps(10).unwrap();
+            assert_eq!(contract.get_max_swap_steps(), 10);
+
+            let events: Vec<EmittedEvent> = recorded_events().collect();
+            assert_eq!(events.len(), 1);
+            let event = ChangeMaxSwapStepsEvent::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!(event.max_swap_steps, 10);
+
+            contract.change_admin(AccountId::from([0x03; 32])).unwrap();
+            let result = contract.change_max_swap_steps(20);
+            assert_eq!(result, Err(InvariantError::NotAdmin));
+            assert_eq!(contract.get_max_swap_steps(), 10);
+        }
+
+        #[ink::test]
+        fn test_quote_best_without_pools() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let amount = TokenAmount(100);
+
+            let result = contract.quote_best(token_0, token_0, amount, true);
+            assert_eq!(result.unwrap_err(), InvariantError::TokensAreSame);
+            let result = contract.quote_best(token_0, token_1, amount, true);
+            assert_eq!(result.unwrap_err(), InvariantError::PoolNotFound);
+
+            contract
+                .add_fee_tier(FeeTier::new(Percentage::new(1), 1).unwrap())
+                .unwrap();
+
+            let result = contract.quote_best(token_0, token_1, amount, true);
+            assert_eq!(result.unwrap_err(), InvariantError::PoolNotFound);
+
+            let result = contract.swap_best(token_0, token_1, amount, true, TokenAmount(0));
+            assert_eq!(result.unwrap_err(), InvariantError::PoolNotFound);
+        }
+
+        #[ink::test]
+        fn test_quote_split_validation() {
+            let contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let token_2 = AccountId::from([0x03; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let other_fee_tier = FeeTier::new(Percentage::new(2), 1).unwrap();
+            let amount_in = TokenAmount(100);
+
+            let hop = |token_in: AccountId, token_out: AccountId, fee_tier: FeeTier| {
+                let pool_key = PoolKey::new(token_in, token_out, fee_tier).unwrap();
+                SwapHop {
+                    pool_key,
+                    x_to_y: pool_key.token_x == token_in,
+                }
+            };
+            let route = |swaps: Vec<SwapHop>| SplitRoute { amount_in, swaps };
+
+            let result = contract.quote_split(vec![]);
+            assert_eq!(result, Err(InvariantError::InvalidSplitRoutes));
+
+            let result = contract.quote_split(vec![route(vec![])]);
+            assert_eq!(result, Err(InvariantError::InvalidSplitRoutes));
+
+            let result = contract.quote_split(vec![
+                route(vec![hop(token_0, token_1, fee_tier)]),
+                route(vec![hop(token_1, token_0, other_fee_tier)]),
+            ]);
+            assert_eq!(result, Err(InvariantError::InvalidSplitRoutes));
+
+            let result = contract.quote_split(vec![
+                route(vec![hop(token_0, token_1, fee_tier)]),
+                route(vec![
+                    hop(token_0, token_2, fee_tier),
+                    hop(token_2, token_1, fee_tier),
+                ]),
+                route(vec![hop(token_0, token_1, fee_tier)]),
+            ]);
+            assert_eq!(result, Err(InvariantError::InvalidSplitRoutes));
+
+            let result = contract.quote_split(vec![
+                route(vec![hop(token_0, token_1, fee_tier)]),
+                route(vec![hop(token_0, token_1, other_fee_tier)]),
+            ]);
+            assert_eq!(result, Err(InvariantError::PoolNotFound));
+        }
+
+        #[ink::test]
+        fn test_multicall() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+
+            let results = contract
+                .multicall(vec![
+                    InvariantCall::AddFeeTier { fee_tier },
+                    InvariantCall::CreatePool {
+                        token_0,
+                        token_1,
+                        fee_tier,
+                        init_sqrt_price,
+                        init_tick: 0,
+                    },
+                    InvariantCall::ChangeProtocolFee {
+                        protocol_fee: Percentage::from_scale(1, 2),
+                    },
+                ])
+                .unwrap();
+            assert_eq!(results, vec![InvariantCallResult::Empty; 3]);
+            assert!(contract.fee_tier_exist(fee_tier));
+            assert!(contract.get_pool(token_0, token_1, fee_tier).is_ok());
+            assert_eq!(contract.get_protocol_fee(), Percentage::from_scale(1, 2));
+
+            let result = contract.multicall(vec![
+                InvariantCall::RemoveFeeTier { fee_tier },
+                InvariantCall::AddFeeTier { fee_tier },
+                InvariantCall::ClaimFee { index: 0 },
+            ]);
+            assert_eq!(result, Err(InvariantError::PositionNotFound));
+        }
+
+        #[ink::test]
+        fn test_incentive_validation() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let reward_token = AccountId::from([0x03; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+            let reward = TokenAmount(1000);
+
+            let result = contract.create_incentive(pool_key, reward_token, reward, 0, 100);
+            assert_eq!(result, Err(InvariantError::PoolNotFound));
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            contract
+                .create_pool(
+                    token_0,
+                    token_1,
+                    fee_tier,
+                    calculate_sqrt_price(0).unwrap(),
+                    0,
+                )
+                .unwrap();
+
+            let result = contract.create_incentive(pool_key, reward_token, TokenAmount(0), 0, 100);
+            assert_eq!(result, Err(InvariantError::AmountIsZero));
+            let result = contract.create_incentive(pool_key, reward_token, reward, 100, 100);
+            assert_eq!(result, Err(InvariantError::InvalidIncentiveTime));
+            let result = contract.create_incentive(pool_key, reward_token, reward, 0, 0);
+            assert_eq!(result, Err(InvariantError::InvalidIncentiveTime));
+
+            assert_eq!(
+                contract.get_incentive(0),
+                Err(InvariantError::IncentiveNotFound)
+            );
+            assert_eq!(
+                contract.end_incentive(0),
+                Err(InvariantError::IncentiveNotFound)
+            );
+            assert_eq!(
+                contract.stake_position(0, 0),
+                Err(InvariantError::IncentiveNotFound)
+            );
+            assert_eq!(
+                contract.claim_incentive_reward(0),
+                Err(InvariantError::StakeNotFound)
+            );
+            assert_eq!(
+                contract.unstake_position(0),
+                Err(InvariantError::StakeNotFound)
+            );
+            assert_eq!(
+                contract.force_unstake_position(AccountId::from([0x04; 32]), 0),
+                Err(InvariantError::StakeNotFound)
+            );
+            assert_eq!(
+                contract.get_stake(AccountId::from([0x04; 32]), 0),
+                Err(InvariantError::StakeNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn test_default_fee_receiver() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let admin = contract.get_admin();
+            let receiver = AccountId::from([0x03; 32]);
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let other_fee_tier = FeeTier::new(Percentage::new(2), 1).unwrap();
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+
+            assert_eq!(contract.get_default_fee_receiver(), admin);
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            contract.add_fee_tier(other_fee_tier).unwrap();
+            contract
+                .create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0)
+                .unwrap();
+            contract.change_default_fee_receiver(receiver).unwrap();
+            contract
+                .create_pool(token_0, token_1, other_fee_tier, init_sqrt_price, 0)
+                .unwrap();
+
+            let pool = contract.get_pool(token_0, token_1, fee_tier).unwrap();
+            assert_eq!(pool.fee_receiver, admin);
+            let pool = contract.get_pool(token_0, token_1, other_fee_tier).unwrap();
+            assert_eq!(pool.fee_receiver, receiver);
+
+            let pool_keys = vec![
+                PoolKey::new(token_0, token_1, fee_tier).unwrap(),
+                PoolKey::new(token_0, token_1, other_fee_tier).unwrap(),
+            ];
+            contract
+                .change_fee_receivers(pool_keys.clone(), token_0)
+                .unwrap();
+            let pool = contract.get_pool(token_0, token_1, fee_tier).unwrap();
+            assert_eq!(pool.fee_receiver, token_0);
+            let pool = contract.get_pool(token_0, token_1, other_fee_tier).unwrap();
+            assert_eq!(pool.fee_receiver, token_0);
+
+            let missing_pool_key = PoolKey::new(
+                token_0,
+                token_1,
+                FeeTier::new(Percentage::new(3), 1).unwrap(),
+            )
+            .unwrap();
+            let result = contract.change_fee_receivers(vec![missing_pool_key], token_0);
+            assert_eq!(result, Err(InvariantError::PoolNotFound));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(receiver);
+            let result = contract.change_default_fee_receiver(receiver);
+            assert_eq!(result, Err(InvariantError::NotAdmin));
+            let result = contract.change_fee_receivers(pool_keys, receiver);
+            assert_eq!(result, Err(InvariantError::NotAdmin));
+        }
+
+        #[ink::test]
+        fn test_pool_creation_fee() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let admin = contract.get_admin();
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+            let pool_creation_fee = PoolCreationFee {
+                asset: FeeAsset::Native,
+                amount: TokenAmount(100),
+            };
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            assert_eq!(contract.get_pool_creation_fee(), None);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1);
+            let result = contract.create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0);
+            assert_eq!(result, Err(InvariantError::InvalidPoolCreationFee));
+
+            contract
+                .change_pool_creation_fee(Some(pool_creation_fee))
+                .unwrap();
+            assert_eq!(contract.get_pool_creation_fee(), Some(pool_creation_fee));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(99);
+            let result = contract.create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0);
+            assert_eq!(result, Err(InvariantError::InvalidPoolCreationFee));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            contract
+                .create_pool(token_0, token_1, fee_tier, init_sqrt_price, 0)
+                .unwrap();
+            assert_eq!(
+                contract.get_collected_pool_creation_fee(FeeAsset::Native),
+                TokenAmount(100)
+            );
+
+            // the value transferred to a multicall cannot be attributed to its calls
+            let other_fee_tier = FeeTier::new(Percentage::new(2), 1).unwrap();
+            contract.add_fee_tier(other_fee_tier).unwrap();
+            let result = contract.multicall(vec![InvariantCall::CreatePool {
+                token_0,
+                token_1,
+                fee_tier: other_fee_tier,
+                init_sqrt_price,
+                init_tick: 0,
+            }]);
+            assert_eq!(result, Err(InvariantError::MulticallNotPayable));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            let result = contract.withdraw_pool_creation_fee(FeeAsset::Token(token_0));
+            assert_eq!(result, Err(InvariantError::AmountIsZero));
+
+            let contract_address = ink::env::account_id::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                contract_address,
+                100,
+            );
+            let admin_balance =
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(admin).unwrap();
+            let amount = contract
+                .withdraw_pool_creation_fee(FeeAsset::Native)
+                .unwrap();
+            assert_eq!(amount, TokenAmount(100));
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(admin).unwrap(),
+                admin_balance + 100
+            );
+            assert_eq!(
+                contract.get_collected_pool_creation_fee(FeeAsset::Native),
+                TokenAmount(0)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x03; 32]));
+            let result = contract.change_pool_creation_fee(None);
+            assert_eq!(result, Err(InvariantError::NotAdmin));
+            let result = contract.withdraw_pool_creation_fee(FeeAsset::Native);
+            assert_eq!(result, Err(InvariantError::NotAdmin));
+        }
+
+        #[ink::test]
+        fn test_create_pool_with_hook() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let other_fee_tier = FeeTier::new(Percentage::new(2), 1).unwrap();
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+            let hook = PoolHook {
+                address: AccountId::from([0x03; 32]),
+                flags: PoolHook::BEFORE_SWAP | PoolHook::AFTER_MODIFY_POSITION,
+            };
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            contract.add_fee_tier(other_fee_tier).unwrap();
+
+            let result = contract.create_pool_with_hook(
+                token_0,
+                token_1,
+                fee_tier,
+                init_sqrt_price,
+                0,
+                PoolHook { flags: 0, ..hook },
+            );
+            assert_eq!(result, Err(InvariantError::InvalidHookFlags));
+            let result = contract.create_pool_with_hook(
+                token_0,
+                token_1,
+                fee_tier,
+                init_sqrt_price,
+                0,
+                PoolHook { flags: 16, ..hook },
+            );
+            assert_eq!(result, Err(InvariantError::InvalidHookFlags));
+
+            contract
+                .create_pool_with_hook(token_0, token_1, fee_tier, init_sqrt_price, 0, hook)
+                .unwrap();
+            let pool = contract.get_pool(token_0, token_1, fee_tier).unwrap();
+            assert_eq!(pool.hook, Some(hook));
+
+            contract
+                .create_pool(token_0, token_1, other_fee_tier, init_sqrt_price, 0)
+                .unwrap();
+            let pool = contract.get_pool(token_0, token_1, other_fee_tier).unwrap();
+            assert_eq!(pool.hook, None);
+        }
+
+        #[ink::test]
+        fn test_remove_pool_hook() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let admin = ink::env::caller::<ink::env::DefaultEnvironment>();
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+            let hook = PoolHook::new(AccountId::from([0x03; 32]), PoolHook::ALL).unwrap();
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            contract
+                .create_pool_with_hook(token_0, token_1, fee_tier, init_sqrt_price, 0, hook)
+                .unwrap();
+            let pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x04; 32]));
+            assert_eq!(
+                contract.remove_pool_hook(pool_key),
+                Err(InvariantError::NotAdmin)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(admin);
+            contract.remove_pool_hook(pool_key).unwrap();
+            let pool = contract.get_pool(token_0, token_1, fee_tier).unwrap();
+            assert_eq!(pool.hook, None);
+            assert_eq!(
+                contract.remove_pool_hook(pool_key),
+                Err(InvariantError::PoolHasNoHook)
+            );
+        }
+
+        #[ink::test]
+        fn test_split_and_merge_without_positions() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let receiver = AccountId::from([0x01; 32]);
+
+            let result = contract.split_position(0, Liquidity::from_integer(1), receiver);
+            assert_eq!(result, Err(InvariantError::PositionNotFound));
+            let result = contract.merge_positions(0, 1);
+            assert_eq!(result, Err(InvariantError::PositionNotFound));
+        }
+
+        #[ink::test]
+        fn test_lock_position_without_positions() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let pool_key = PoolKey::new(
+                AccountId::from([0x01; 32]),
+                AccountId::from([0x02; 32]),
+                FeeTier::new(Percentage::new(1), 1).unwrap(),
+            )
+            .unwrap();
+
+            let result = contract.lock_position(0, 100);
+            assert_eq!(result, Err(InvariantError::PositionNotFound));
+            assert_eq!(contract.get_locked_positions(pool_key, 10, 0), vec![]);
+        }
+
+        #[ink::test]
+        fn test_referral_fee_validation() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let pool_key = PoolKey::new(
+                token_0,
+                token_1,
+                FeeTier::new(Percentage::new(1), 1).unwrap(),
+            )
+            .unwrap();
+            let referrer = AccountId::from([0x03; 32]);
+
+            let referral = Referral {
+                referrer,
+                fee: MAX_REFERRAL_FEE + Percentage::new(1),
+            };
+            let result = contract.swap_with_referral(
+                pool_key,
+                true,
+                TokenAmount(10),
+                true,
+                SqrtPrice::new(MIN_SQRT_PRICE),
+                referral,
+            );
+            assert_eq!(result, Err(InvariantError::InvalidReferralFee));
+
+            let referral = Referral {
+                referrer,
+                fee: MAX_REFERRAL_FEE,
+            };
+            let result = contract.swap_with_referral(
+                pool_key,
+                true,
+                TokenAmount(10),
+                true,
+                SqrtPrice::new(MIN_SQRT_PRICE),
+                referral,
+            );
+            assert_eq!(result, Err(InvariantError::PoolNotFound));
+
+            assert_eq!(contract.get_referral_fee(referrer, token_0), TokenAmount(0));
+            let result = contract.claim_referral_fee(token_0);
+            assert_eq!(result, Err(InvariantError::AmountIsZero));
+        }
+
+        #[ink::test]
+        fn test_admin_events() {
+            let mut contract = Invariant::new(Percentage::new(0));
+            let admin = contract.get_admin();
+            let new_admin = AccountId::from([0x03; 32]);
+            let token_0 = AccountId::from([0x01; 32]);
+            let token_1 = AccountId::from([0x02; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let init_sqrt_price = calculate_sqrt_price(0).unwrap();
+
+            contract.add_fee_tier(fee_tier).unwrap();
+            contract
+                .create_pool(token_1, token_0, fee_tier, init_sqrt_price, 0)
+                .unwrap();
+            contract
+                .change_protocol_fee(Percentage::from_scale(1, 2))
+                .unwrap();
+            contract.remove_fee_tier(fee_tier).unwrap();
+            contract.change_admin(new_admin).unwrap();
+
+            let events: Vec<EmittedEvent> = recorded_events().collect();
+            assert_eq!(events.len(), 5);
+
+            let event = AddFeeTierEvent::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!(event.address, admin);
+            assert_eq!(event.fee_tier, fee_tier);
+
+            let event = CreatePoolEvent::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(events[1].topics.len(), 4);
+            assert_eq!(event.token_x, token_0);
+            assert_eq!(event.token_y, token_1);
+            assert_eq!(event.fee_tier, fee_tier);
+            assert_eq!(event.init_sqrt_price, init_sqrt_price);
+            assert_eq!(event.init_tick, 0);
+            assert_eq!(event.fee_receiver, admin);
+
+            let event = ChangeProtocolFeeEvent::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(event.protocol_fee, Percentage::from_scale(1, 2));
+
+            let event = RemoveFeeTierEvent::decode(&mut &events[3].data[..]).unwrap();
+            assert_eq!(event.fee_tier, fee_tier);
+
+            let event = ChangeAdminEvent::decode(&mut &events[4].data[..]).unwrap();
+            assert_eq!(event.address, admin);
+            assert_eq!(event.new_admin, new_admin);
+        }
+
+        #[ink::test]
+        fn test_migrate_legacy_storage() {
+            use ink::primitives::KeyComposer;
+            use ink::storage::traits::StorageKey;
+
+            let admin = AccountId::from([0x01; 32]);
+            let fee_tier = FeeTier::new(Percentage::new(1), 1).unwrap();
+            let pool_key = PoolKey::new(
+                AccountId::from([0x02; 32]),
+                AccountId::from([0x03; 32]),
+                fee_tier,
+            )
+            .unwrap();
+            let legacy_pool = (
+                Liquidity::new(10),
+                calculate_sqrt_price(0).unwrap(),
+                0i32,
+                FeeGrowth::default(),
+                FeeGrowth::default(),
+                TokenAmount(0),
+                TokenAmount(0),
+                1u64,
+                1u64,
+                admin,
+                SecondsPerLiquidity::new(0),
+            );
+
+            // storage written by the version without storage versioning, pool statuses, curation and hooks
+            let key = |struct_name: &str, field_name: &str| {
+                KeyComposer::compute_key(struct_name, "", field_name).unwrap()
+            };
+            ink::env::set_contract_storage(
+                &<Invariant as StorageKey>::KEY,
+                &(vec![fee_tier], 1u16, admin, Percentage::new(1)),
+            );
+            ink::env::set_contract_storage(&(key("Pools", "pools"), pool_key), &legacy_pool);
+            ink::env::set_contract_storage(&(key("PoolKeys", "pool_keys"), pool_key), &0u16);
+            ink::env::set_contract_storage(
+                &(key("PoolKeys", "pool_keys_by_index"), 0u16),
+                &pool_key,
+            );
+
+            let mut contract: Invariant =
+                ink::env::get_contract_storage(&<Invariant as StorageKey>::KEY)
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(contract.get_fee_tiers(), Ok(vec![fee_tier]));
+            assert_eq!(
+                contract.get_pool_keys_with_status(10, 0, None),
+                Ok((vec![(pool_key, PoolStatus::Active)], 1))
+            );
+            assert_eq!(contract.get_admin(), admin);
+            assert_eq!(contract.get_protocol_fee(), Percentage::new(1));
+            assert_eq!(contract.get_default_fee_receiver(), admin);
+            assert_eq!(contract.get_storage_version(), 0);
+
+            let pool = contract
+                .get_pool(pool_key.token_x, pool_key.token_y, fee_tier)
+                .unwrap();
+            assert_eq!(pool.liquidity, legacy_pool.0);
+            assert_eq!(pool.status, PoolStatus::Active);
+            assert!(!pool.curated);
+            assert_eq!(pool.hook, None);
+            assert_eq!(
+                ink::env::contains_contract_storage(&(key("Pools", "pools"), pool_key)),
+                Some(legacy_pool.encode().len() as u32)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x04; 32]));
+            assert_eq!(contract.migrate(10), Err(InvariantError::NotAdmin));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(admin);
+            assert_eq!(contract.migrate(10), Ok(false));
+            assert_eq!(contract.get_storage_version(), 1);
+            assert_eq!(contract.migrate(10), Ok(true));
+            assert_eq!(contract.get_storage_version(), STORAGE_VERSION);
+            assert_eq!(contract.get_fee_tiers(), Ok(vec![fee_tier]));
+            assert_eq!(contract.migrate(10), Err(InvariantError::AlreadyMigrated));
+
+            // the pool is stored with all of its fields after the migration
+            assert_eq!(
+                ink::env::contains_contract_storage(&(key("Pools", "pools"), pool_key)),
+                Some(pool.encode().len() as u32)
+            );
         }
     }
 }
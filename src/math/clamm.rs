@@ -37,7 +37,7 @@ pub fn compute_swap_step(
         let amount_after_fee = amount.big_mul(
             Percentage::from_integer(1)
                 .checked_sub(fee)
-                .map_err(|_| err!("Underflow while calculating amount after fee"))?,
+                .map_err(|_| err!(TrackableError::SUB))?,
         );
 
         amount_in = ok_or_mark_trace!(if x_to_y {
@@ -121,7 +121,7 @@ pub fn compute_swap_step(
     let fee_amount = if by_amount_in && next_sqrt_price != target_sqrt_price {
         amount
             .checked_sub(amount_in)
-            .map_err(|_| err!("Underflow while calculating fee amount"))?
+            .map_err(|_| err!(TrackableError::SUB))?
     } else {
         amount_in.big_mul_up(fee)
     };
@@ -143,11 +143,11 @@ pub fn get_delta_x(
     let delta_price: SqrtPrice = if sqrt_price_a > sqrt_price_b {
         sqrt_price_a
             .checked_sub(sqrt_price_b)
-            .map_err(|_| err!("Underflow while calculating delta price"))?
+            .map_err(|_| err!(TrackableError::SUB))?
     } else {
         sqrt_price_b
             .checked_sub(sqrt_price_a)
-            .map_err(|_| err!("Underflow while calculating delta price"))?
+            .map_err(|_| err!(TrackableError::SUB))?
     };
     let nominator = delta_price.big_mul_to_value(liquidity);
 
@@ -180,11 +180,11 @@ pub fn get_delta_y(
     let delta: SqrtPrice = if sqrt_price_a > sqrt_price_b {
         sqrt_price_a
             .checked_sub(sqrt_price_b)
-            .map_err(|_| err!("Underflow while calculating delta"))?
+            .map_err(|_| err!(TrackableError::SUB))?
     } else {
         sqrt_price_b
             .checked_sub(sqrt_price_a)
-            .map_err(|_| err!("Underflow while calculating delta"))?
+            .map_err(|_| err!(TrackableError::SUB))?
     };
 
     let delta_y = match rounding_up {
@@ -201,7 +201,7 @@ pub fn get_delta_y(
     };
 
     Ok(TokenAmount(delta_y.try_into().map_err(|_| {
-        err!(TrackableError::cast::<TokenAmount>().as_str())
+        err!(&TrackableError::cast::<TokenAmount>())
     })?))
 }
 
@@ -246,8 +246,9 @@ pub fn get_next_sqrt_price_x_up(
     if x.is_zero() {
         return Ok(starting_sqrt_price);
     };
-    let price_delta = ok_or_mark_trace!(SqrtPrice::checked_from_decimal_to_value(liquidity)
-        .map_err(|_| err!("extending liquidity overflow")))?;
+    let price_delta =
+        ok_or_mark_trace!(SqrtPrice::checked_from_decimal_to_value(liquidity)
+            .map_err(|_| err!(TrackableError::MUL)))?;
 
     let denominator = ok_or_mark_trace!(match add_x {
         true => price_delta.checked_add(starting_sqrt_price.big_mul_to_value(x)),
@@ -267,19 +268,24 @@ fn get_next_sqrt_price_y_down(
     y: TokenAmount,
     add_y: bool,
 ) -> TrackableResult<SqrtPrice> {
-    let numerator: U256 = from_result!(SqrtPrice::checked_from_decimal_to_value(y))?;
+    let numerator: U256 =
+        SqrtPrice::checked_from_decimal_to_value(y).map_err(|_| err!(TrackableError::MUL))?;
 
     let denominator: U256 = SqrtPrice::checked_from_decimal_to_value(liquidity)
-        .map_err(|_| err!("extending liquidity overflow"))?;
+        .map_err(|_| err!(TrackableError::MUL))?;
 
     if add_y {
         let quotient =
             ok_or_mark_trace!(SqrtPrice::checked_big_div_values(numerator, denominator))?;
-        from_result!(starting_sqrt_price.checked_add(quotient))
+        starting_sqrt_price
+            .checked_add(quotient)
+            .map_err(|_| err!(TrackableError::ADD))
     } else {
         let quotient =
             ok_or_mark_trace!(SqrtPrice::checked_big_div_values_up(numerator, denominator))?;
-        from_result!(starting_sqrt_price.checked_sub(quotient))
+        starting_sqrt_price
+            .checked_sub(quotient)
+            .map_err(|_| err!(TrackableError::SUB))
     }
 }
 
@@ -1437,7 +1443,7 @@ mod tests {
                 )
                 .unwrap_err();
                 let (_, cause, stack) = result.get();
-                assert_eq!(cause, "checked_add: (self + rhs) additional overflow");
+                assert_eq!(cause, TrackableError::ADD.message);
                 assert_eq!(stack.len(), 1);
             }
             {
@@ -1449,7 +1455,7 @@ mod tests {
                 )
                 .unwrap_err();
                 let (_, cause, stack) = result.get();
-                assert_eq!(cause, "checked_sub: (self - rhs) subtraction underflow");
+                assert_eq!(cause, TrackableError::SUB.message);
                 assert_eq!(stack.len(), 1);
             }
         }
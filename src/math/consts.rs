@@ -1,6 +1,21 @@
+use traceable_result::{Cause, ErrorKind};
+
 pub const MAX_TICK: i32 = 665455;
 pub const MIN_TICK: i32 = -MAX_TICK;
 pub const MAX_SQRT_PRICE: u128 = 281481114768267672330495791029795421271; //real 281481114768267672330498244929173903930;
 pub const MIN_SQRT_PRICE: u128 = 3552636207;
 
 pub const TICK_SEARCH_RANGE: i32 = 256;
+
+pub const SQRT_PRICE_OUT_OF_RANGE: Cause =
+    Cause::new(ErrorKind::PriceOutOfRange, "sqrt_price out of range");
+pub const TICK_OUT_OF_RANGE: Cause = Cause::new(ErrorKind::PriceOutOfRange, "tick over bounds");
+pub const INVALID_TICKS: Cause = Cause::new(ErrorKind::PriceOutOfRange, "Invalid Ticks");
+pub const UPPER_SQRT_PRICE_BELOW_CURRENT: Cause = Cause::new(
+    ErrorKind::PriceOutOfRange,
+    "Upper Sqrt Price < Current Sqrt Price",
+);
+pub const CURRENT_SQRT_PRICE_BELOW_LOWER: Cause = Cause::new(
+    ErrorKind::PriceOutOfRange,
+    "Current Sqrt Price < Lower Sqrt Price",
+);
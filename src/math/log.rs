@@ -110,7 +110,7 @@ fn log2_iterative_approximation_x64(mut sqrt_price_x64: u128) -> (bool, u128) {
 
 pub fn get_tick_at_sqrt_price(sqrt_price: SqrtPrice, tick_spacing: u16) -> TrackableResult<i32> {
     if sqrt_price.get() > MAX_SQRT_PRICE || sqrt_price.get() < MIN_SQRT_PRICE {
-        return Err(err!("sqrt_price out of range"));
+        return Err(err!(SQRT_PRICE_OUT_OF_RANGE));
     }
 
     let sqrt_price_x64: u128 = sqrt_price_to_x64(sqrt_price);
@@ -1,9 +1,11 @@
 pub mod clamm;
 pub mod consts;
 pub mod log;
+pub mod swap_steps;
 pub mod types;
 
 pub use clamm::*;
 pub use consts::*;
 pub use log::*;
+pub use swap_steps::*;
 pub use types::*;
@@ -0,0 +1,3 @@
+/// Default maximum number of swap steps performed within a single swap.
+/// The value is shared with the SDK, which includes the same file.
+pub const MAX_SWAP_STEPS: u32 = include!("max_swap_steps.in");
@@ -43,7 +43,7 @@ impl FeeGrowth {
                 ))))
                 .ok_or_else(|| err!(TrackableError::DIV))?
                 .try_into()
-                .map_err(|_| err!(TrackableError::cast::<TokenAmount>().as_str()))?,
+                .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?,
         ))
     }
 }
@@ -28,7 +28,7 @@ impl SecondsPerLiquidity {
         }
         let delta_time = current_timestamp
             .checked_sub(last_timestamp)
-            .ok_or(err!("Underflow while calculating delta time"))?;
+            .ok_or(err!(TrackableError::SUB))?;
 
         Ok(Self::new(
             u128::from(delta_time)
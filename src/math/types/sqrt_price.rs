@@ -33,11 +33,11 @@ impl SqrtPrice {
             .ok_or_else(|| err!(TrackableError::DIV))?;
 
         let result = U256::uint_checked_cast(intermediate_u448)
-            .map_err(|e| err!(&e))?
+            .map_err(|_| err!(&TrackableError::cast::<U256>()))?
             .checked_div(U256::from(Self::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<TokenAmount>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?;
         Ok(TokenAmount(result))
     }
 
@@ -59,13 +59,13 @@ impl SqrtPrice {
             .ok_or_else(|| err!(TrackableError::DIV))?;
 
         let result = U256::uint_checked_cast(intermediate_u448)
-            .map_err(|e| err!(&e))?
+            .map_err(|_| err!(&TrackableError::cast::<U256>()))?
             .checked_add(U256::from(Self::almost_one().get()))
             .ok_or_else(|| err!(TrackableError::ADD))?
             .checked_div(U256::from(Self::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<TokenAmount>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<TokenAmount>()))?;
         Ok(TokenAmount::new(result))
     }
 
@@ -94,7 +94,7 @@ impl SqrtPrice {
                 .checked_div(denominator)
                 .ok_or_else(|| err!(TrackableError::DIV))?
                 .try_into()
-                .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?,
+                .map_err(|_| err!(&TrackableError::cast::<Self>()))?,
         ))
     }
 
@@ -117,7 +117,7 @@ impl SqrtPrice {
                 .checked_div(denominator)
                 .ok_or_else(|| err!(TrackableError::DIV))?
                 .try_into()
-                .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?,
+                .map_err(|_| err!(&TrackableError::cast::<Self>()))?,
         ))
     }
 }
@@ -150,7 +150,7 @@ pub fn calculate_sqrt_price(tick_index: i32) -> TrackableResult<SqrtPrice> {
     let tick = tick_index.abs();
 
     if tick > MAX_TICK {
-        return Err(err!("tick over bounds"));
+        return Err(err!(TICK_OUT_OF_RANGE));
     }
 
     let mut sqrt_price = FixedPoint::one();
@@ -226,7 +226,7 @@ pub fn calculate_sqrt_price(tick_index: i32) -> TrackableResult<SqrtPrice> {
                 .checked_mul(SqrtPrice::one().here())
                 .unwrap()
                 .checked_div(sqrt_price.here())
-                .ok_or(err!("calculate_sqrt_price::checked_div division failed"))?,
+                .ok_or(err!(TrackableError::DIV))?,
         ))
     })
 }
@@ -16,7 +16,7 @@ impl TokenAmount {
             .checked_div(U256::from(SqrtPrice::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<Self>()))?;
 
         Ok(TokenAmount(result))
     }
@@ -28,7 +28,7 @@ impl TokenAmount {
             .checked_div(U256::from(SqrtPrice::one().get()))
             .ok_or_else(|| err!(TrackableError::DIV))?
             .try_into()
-            .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?;
+            .map_err(|_| err!(&TrackableError::cast::<Self>()))?;
         Ok(TokenAmount(result))
     }
 }
@@ -0,0 +1,117 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Test fixture receiving the `InvariantHooks` callbacks of a pool, used by the pool hook e2e tests.
+/// The callbacks are dispatched by the selectors of the trait messages and record their arguments
+/// encoded as they are, so the fixture does not depend on the DEX types.
+#[ink::contract]
+pub mod mock_hooks {
+    use ink::prelude::vec::Vec;
+    use ink::scale::{Decode, Encode, Error, Input, Output};
+
+    // same values as the `PoolHook` flags
+    pub const BEFORE_SWAP: u8 = 1;
+    pub const AFTER_SWAP: u8 = 1 << 1;
+    pub const BEFORE_MODIFY_POSITION: u8 = 1 << 2;
+    pub const AFTER_MODIFY_POSITION: u8 = 1 << 3;
+
+    /// Arguments of a callback, taking the rest of the input without a length prefix.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[ink::scale_derive(TypeInfo)]
+    pub struct RawArgs(pub Vec<u8>);
+
+    impl Encode for RawArgs {
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+
+        fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(&self.0);
+        }
+    }
+
+    impl Decode for RawArgs {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+            let mut bytes = ink::prelude::vec![0; input.remaining_len()?.unwrap_or_default()];
+            input.read(&mut bytes)?;
+            Ok(Self(bytes))
+        }
+    }
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct MockHooks {
+        calls: Vec<(u8, Vec<u8>)>,
+        veto_swaps: bool,
+        fail: bool,
+        burn_gas: bool,
+    }
+
+    impl MockHooks {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Makes `before_swap` return `false`.
+        #[ink(message)]
+        pub fn set_veto_swaps(&mut self, veto_swaps: bool) {
+            self.veto_swaps = veto_swaps;
+        }
+
+        /// Makes every callback trap.
+        #[ink(message)]
+        pub fn set_fail(&mut self, fail: bool) {
+            self.fail = fail;
+        }
+
+        /// Makes every callback run until it is out of gas.
+        #[ink(message)]
+        pub fn set_burn_gas(&mut self, burn_gas: bool) {
+            self.burn_gas = burn_gas;
+        }
+
+        /// Returns the received callbacks as the flag of the callback and its encoded arguments.
+        #[ink(message)]
+        pub fn get_calls(&self) -> Vec<(u8, Vec<u8>)> {
+            self.calls.clone()
+        }
+
+        /// `InvariantHooks::before_swap`
+        #[ink(message, selector = 0x951bb01c)]
+        pub fn before_swap(&mut self, args: RawArgs) -> bool {
+            self.record(BEFORE_SWAP, args);
+            !self.veto_swaps
+        }
+
+        /// `InvariantHooks::after_swap`
+        #[ink(message, selector = 0x6d9cd4ea)]
+        pub fn after_swap(&mut self, args: RawArgs) {
+            self.record(AFTER_SWAP, args);
+        }
+
+        /// `InvariantHooks::before_modify_position`
+        #[ink(message, selector = 0xd151de0e)]
+        pub fn before_modify_position(&mut self, args: RawArgs) {
+            self.record(BEFORE_MODIFY_POSITION, args);
+        }
+
+        /// `InvariantHooks::after_modify_position`
+        #[ink(message, selector = 0x1f6565e8)]
+        pub fn after_modify_position(&mut self, args: RawArgs) {
+            self.record(AFTER_MODIFY_POSITION, args);
+        }
+
+        fn record(&mut self, flag: u8, args: RawArgs) {
+            if self.fail {
+                panic!("hook failed");
+            }
+            if self.burn_gas {
+                loop {
+                    core::hint::black_box(());
+                }
+            }
+
+            self.calls.push((flag, args.0));
+        }
+    }
+}
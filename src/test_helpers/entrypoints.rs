@@ -84,14 +84,941 @@ macro_rules! change_protocol_fee {
     }};
 }
 
+#[macro_export]
+macro_rules! get_max_swap_steps {
+    ($client:ident, $dex:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_max_swap_steps();
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! change_max_swap_steps {
+    ($client:ident, $dex:ident, $max_swap_steps:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_max_swap_steps($max_swap_steps);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! get_pool_creation_fee {
+    ($client:ident, $dex:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_pool_creation_fee();
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! change_pool_creation_fee {
+    ($client:ident, $dex:ident, $pool_creation_fee:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_pool_creation_fee($pool_creation_fee);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! get_collected_pool_creation_fee {
+    ($client:ident, $dex:ident, $asset:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_collected_pool_creation_fee($asset);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! withdraw_pool_creation_fee {
+    ($client:ident, $dex:ident, $asset:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.withdraw_pool_creation_fee($asset);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
 #[macro_export]
 macro_rules! change_fee_receiver {
     ($client:ident, $dex:ident, $pool_key:expr, $fee_receiver:expr, $caller:ident) => {{
         let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.change_fee_receiver($pool_key, $fee_receiver);
+        let call = call_builder.change_fee_receiver($pool_key, $fee_receiver);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(u64::MAX)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! change_fee_receivers {
+    ($client:ident, $dex:ident, $pool_keys:expr, $fee_receiver:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_fee_receivers($pool_keys, $fee_receiver);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! get_default_fee_receiver {
+    ($client:ident, $dex:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_default_fee_receiver();
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! change_default_fee_receiver {
+    ($client:ident, $dex:ident, $fee_receiver:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_default_fee_receiver($fee_receiver);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! change_pool_status {
+    ($client:ident, $dex:ident, $pool_key:expr, $status:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_pool_status($pool_key, $status);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(u64::MAX)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! change_pool_curation {
+    ($client:ident, $dex:ident, $pool_key:expr, $curated:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_pool_curation($pool_key, $curated);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(u64::MAX)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! remove_pool_hook {
+    ($client:ident, $dex:ident, $pool_key:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.remove_pool_hook($pool_key);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(u64::MAX)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! change_token_status {
+    ($client:ident, $dex:ident, $token:expr, $status:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_token_status($token, $status);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(u64::MAX)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! create_position {
+    ($client:ident, $dex:ident, $pool_key:expr, $lower_tick:expr, $upper_tick:expr, $liquidity_delta:expr, $slippage_limit_lower:expr, $slippage_limit_upper:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.create_position(
+            $pool_key,
+            $lower_tick,
+            $upper_tick,
+            $liquidity_delta,
+            $slippage_limit_lower,
+            $slippage_limit_upper,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! create_position_by_amounts {
+    ($client:ident, $dex:ident, $pool_key:expr, $lower_tick:expr, $upper_tick:expr, $max_x:expr, $max_y:expr, $min_x:expr, $min_y:expr, $slippage_limit_lower:expr, $slippage_limit_upper:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.create_position_by_amounts(
+            $pool_key,
+            $lower_tick,
+            $upper_tick,
+            $max_x,
+            $max_y,
+            $min_x,
+            $min_y,
+            $slippage_limit_lower,
+            $slippage_limit_upper,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! change_liquidity {
+    ($client:ident, $dex:ident, $index:expr, $liquidity_delta:expr, $add_liquidity:expr, $slippage_limit_lower:expr, $slippage_limit_upper:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.change_liquidity(
+            $index,
+            $liquidity_delta,
+            $add_liquidity,
+            $slippage_limit_lower,
+            $slippage_limit_upper,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! swap {
+    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.swap(
+            $pool_key,
+            $x_to_y,
+            $amount,
+            $by_amount_in,
+            $sqrt_price_limit,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+
+    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr, $caller:ident, $expected_panic: expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.swap(
+            $pool_key,
+            $x_to_y,
+            $amount,
+            $by_amount_in,
+            $sqrt_price_limit,
+        );
+        let result = $client.call(&$caller, &call).dry_run().await;
+
+        if result.is_err() {
+            assert!(result.unwrap_err().to_string().contains($expected_panic));
+        } else {
+            std::panic!("Swap did not panic");
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! swap_best {
+    ($client:ident, $dex:ident, $token_in:expr, $token_out:expr, $amount:expr, $by_amount_in:expr, $amount_limit:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call =
+            call_builder.swap_best($token_in, $token_out, $amount, $by_amount_in, $amount_limit);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! swap_partial {
+    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.swap_partial(
+            $pool_key,
+            $x_to_y,
+            $amount,
+            $by_amount_in,
+            $sqrt_price_limit,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! swap_route {
+    ($client:ident, $dex:ident, $amount_in:expr, $expected_amount_out:expr, $slippage:expr, $swaps:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.swap_route($amount_in, $expected_amount_out, $slippage, $swaps);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! swap_with_referral {
+    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr, $referral:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.swap_with_referral(
+            $pool_key,
+            $x_to_y,
+            $amount,
+            $by_amount_in,
+            $sqrt_price_limit,
+            $referral,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! swap_route_with_referral {
+    ($client:ident, $dex:ident, $amount_in:expr, $expected_amount_out:expr, $slippage:expr, $swaps:expr, $referral:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.swap_route_with_referral(
+            $amount_in,
+            $expected_amount_out,
+            $slippage,
+            $swaps,
+            $referral,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! claim_referral_fee {
+    ($client:ident, $dex:ident, $token:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.claim_referral_fee($token);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! get_referral_fee {
+    ($client:ident, $dex:ident, $referrer:expr, $token:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_referral_fee($referrer, $token);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! swap_split {
+    ($client:ident, $dex:ident, $routes:expr, $expected_amount_out:expr, $slippage:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.swap_split($routes, $expected_amount_out, $slippage);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! quote {
+    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.quote(
+            $pool_key,
+            $x_to_y,
+            $amount,
+            $by_amount_in,
+            $sqrt_price_limit,
+        );
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! quote_best {
+    ($client:ident, $dex:ident, $token_in:expr, $token_out:expr, $amount:expr, $by_amount_in:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.quote_best($token_in, $token_out, $amount, $by_amount_in);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! quote_partial {
+    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.quote_partial(
+            $pool_key,
+            $x_to_y,
+            $amount,
+            $by_amount_in,
+            $sqrt_price_limit,
+        );
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! quote_route {
+    ($client:ident, $dex:ident, $amount_in:expr, $swaps:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.quote_route($amount_in, $swaps);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! quote_split {
+    ($client:ident, $dex:ident, $routes:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.quote_split($routes);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! multicall {
+    ($client:ident, $dex:ident, $calls:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.multicall($calls);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! create_incentive {
+    ($client:ident, $dex:ident, $pool_key:expr, $reward_token:expr, $reward:expr, $start_timestamp:expr, $end_timestamp:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.create_incentive(
+            $pool_key,
+            $reward_token,
+            $reward,
+            $start_timestamp,
+            $end_timestamp,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! end_incentive {
+    ($client:ident, $dex:ident, $incentive_id:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.end_incentive($incentive_id);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! stake_position {
+    ($client:ident, $dex:ident, $index:expr, $incentive_id:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.stake_position($index, $incentive_id);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! claim_incentive_reward {
+    ($client:ident, $dex:ident, $index:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.claim_incentive_reward($index);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! unstake_position {
+    ($client:ident, $dex:ident, $index:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.unstake_position($index);
         let result = $client
             .call(&$caller, &call)
-            .extra_gas_portion(u64::MAX)
+            .extra_gas_portion(1000)
             .dry_run()
             .await
             .unwrap()
@@ -112,17 +1039,10 @@ macro_rules! change_fee_receiver {
 }
 
 #[macro_export]
-macro_rules! create_position {
-    ($client:ident, $dex:ident, $pool_key:expr, $lower_tick:expr, $upper_tick:expr, $liquidity_delta:expr, $slippage_limit_lower:expr, $slippage_limit_upper:expr, $caller:ident) => {{
+macro_rules! force_unstake_position {
+    ($client:ident, $dex:ident, $owner:expr, $index:expr, $caller:ident) => {{
         let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.create_position(
-            $pool_key,
-            $lower_tick,
-            $upper_tick,
-            $liquidity_delta,
-            $slippage_limit_lower,
-            $slippage_limit_upper,
-        );
+        let call = call_builder.force_unstake_position($owner, $index);
         let result = $client
             .call(&$caller, &call)
             .extra_gas_portion(1000)
@@ -146,16 +1066,38 @@ macro_rules! create_position {
 }
 
 #[macro_export]
-macro_rules! change_liquidity {
-    ($client:ident, $dex:ident, $index:expr, $liquidity_delta:expr, $add_liquidity:expr, $slippage_limit_lower:expr, $slippage_limit_upper:expr, $caller:ident) => {{
+macro_rules! get_incentive {
+    ($client:ident, $dex:ident, $incentive_id:expr) => {{
         let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.change_liquidity(
-            $index,
-            $liquidity_delta,
-            $add_liquidity,
-            $slippage_limit_lower,
-            $slippage_limit_upper,
-        );
+        let call = call_builder.get_incentive($incentive_id);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! get_stake {
+    ($client:ident, $dex:ident, $owner:expr, $index:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_stake($owner, $index);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! split_position {
+    ($client:ident, $dex:ident, $index:expr, $liquidity:expr, $receiver:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.split_position($index, $liquidity, $receiver);
         let result = $client
             .call(&$caller, &call)
             .extra_gas_portion(1000)
@@ -179,16 +1121,10 @@ macro_rules! change_liquidity {
 }
 
 #[macro_export]
-macro_rules! swap {
-    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr, $caller:ident) => {{
+macro_rules! merge_positions {
+    ($client:ident, $dex:ident, $index_a:expr, $index_b:expr, $caller:ident) => {{
         let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.swap(
-            $pool_key,
-            $x_to_y,
-            $amount,
-            $by_amount_in,
-            $sqrt_price_limit,
-        );
+        let call = call_builder.merge_positions($index_a, $index_b);
         let result = $client
             .call(&$caller, &call)
             .extra_gas_portion(1000)
@@ -209,31 +1145,13 @@ macro_rules! swap {
             result
         }
     }};
-
-    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr, $caller:ident, $expected_panic: expr) => {{
-        let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.swap(
-            $pool_key,
-            $x_to_y,
-            $amount,
-            $by_amount_in,
-            $sqrt_price_limit,
-        );
-        let result = $client.call(&$caller, &call).dry_run().await;
-
-        if result.is_err() {
-            assert!(result.unwrap_err().to_string().contains($expected_panic));
-        } else {
-            std::panic!("Swap did not panic");
-        }
-    }};
 }
 
 #[macro_export]
-macro_rules! swap_route {
-    ($client:ident, $dex:ident, $amount_in:expr, $expected_amount_out:expr, $slippage:expr, $swaps:expr, $caller:ident) => {{
+macro_rules! lock_position {
+    ($client:ident, $dex:ident, $index:expr, $locked_until:expr, $caller:ident) => {{
         let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.swap_route($amount_in, $expected_amount_out, $slippage, $swaps);
+        let call = call_builder.lock_position($index, $locked_until);
         let result = $client
             .call(&$caller, &call)
             .extra_gas_portion(1000)
@@ -257,30 +1175,10 @@ macro_rules! swap_route {
 }
 
 #[macro_export]
-macro_rules! quote {
-    ($client:ident, $dex:ident, $pool_key:expr, $x_to_y:expr, $amount:expr, $by_amount_in:expr, $sqrt_price_limit:expr) => {{
-        let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.quote(
-            $pool_key,
-            $x_to_y,
-            $amount,
-            $by_amount_in,
-            $sqrt_price_limit,
-        );
-        $client
-            .call(&ink_e2e::alice(), &call)
-            .dry_run()
-            .await
-            .unwrap()
-            .return_value()
-    }};
-}
-
-#[macro_export]
-macro_rules! quote_route {
-    ($client:ident, $dex:ident, $amount_in:expr, $swaps:expr) => {{
+macro_rules! get_locked_positions {
+    ($client:ident, $dex:ident, $pool_key:expr, $size:expr, $offset:expr) => {{
         let mut call_builder = $dex.call_builder::<Invariant>();
-        let call = call_builder.quote_route($amount_in, $swaps);
+        let call = call_builder.get_locked_positions($pool_key, $size, $offset);
         $client
             .call(&ink_e2e::alice(), &call)
             .dry_run()
@@ -417,6 +1315,33 @@ macro_rules! claim_fee {
     }};
 }
 
+#[macro_export]
+macro_rules! donate {
+    ($client:ident, $dex:ident, $pool_key:expr, $amount_x:expr, $amount_y:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.donate($pool_key, $amount_x, $amount_y);
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
 #[macro_export]
 macro_rules! remove_position {
     ($client:ident, $dex:ident, $index:expr, $caller:ident) => {{
@@ -538,6 +1463,101 @@ macro_rules! create_pool {
             result
         }
     }};
+    ($client:ident, $dex:ident, $token_0:expr, $token_1:expr, $fee_tier:expr, $init_sqrt_price:expr, $init_tick:expr, $caller:ident, $value:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call =
+            call_builder.create_pool($token_0, $token_1, $fee_tier, $init_sqrt_price, $init_tick);
+        let result = $client
+            .call(&$caller, &call)
+            .value($value)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .value($value)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! create_pool_with_hook {
+    ($client:ident, $dex:ident, $token_0:expr, $token_1:expr, $fee_tier:expr, $init_sqrt_price:expr, $init_tick:expr, $hook:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.create_pool_with_hook(
+            $token_0,
+            $token_1,
+            $fee_tier,
+            $init_sqrt_price,
+            $init_tick,
+            $hook,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! create_pool_with_position {
+    ($client:ident, $dex:ident, $token_0:expr, $token_1:expr, $fee_tier:expr, $init_sqrt_price:expr, $lower_tick:expr, $upper_tick:expr, $liquidity_delta:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.create_pool_with_position(
+            $token_0,
+            $token_1,
+            $fee_tier,
+            $init_sqrt_price,
+            $lower_tick,
+            $upper_tick,
+            $liquidity_delta,
+        );
+        let result = $client
+            .call(&$caller, &call)
+            .extra_gas_portion(1000)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
 }
 
 #[macro_export]
@@ -554,6 +1574,20 @@ macro_rules! get_pool {
     }};
 }
 
+#[macro_export]
+macro_rules! get_pool_stats {
+    ($client:ident, $dex:ident, $pool_key:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_pool_stats($pool_key);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
 #[macro_export]
 macro_rules! get_tick {
     ($client:ident, $dex:ident, $key:expr, $index:expr) => {{
@@ -596,6 +1630,23 @@ macro_rules! get_pool_keys {
     }};
 }
 
+#[macro_export]
+macro_rules! get_pool_keys_with_status {
+    ($client:ident, $dex:ident, $size:expr, $offset:expr) => {{
+        $crate::get_pool_keys_with_status!($client, $dex, $size, $offset, None)
+    }};
+    ($client:ident, $dex:ident, $size:expr, $offset:expr, $curated:expr) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_pool_keys_with_status($size, $offset, $curated);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
 #[macro_export]
 macro_rules! get_fee_tiers {
     ($client:ident, $dex:ident) => {{
@@ -678,6 +1729,46 @@ macro_rules! set_code {
     }};
 }
 
+#[macro_export]
+macro_rules! get_storage_version {
+    ($client:ident, $dex:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.get_storage_version();
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! migrate {
+    ($client:ident, $dex:ident, $batch_size:expr, $caller:ident) => {{
+        let mut call_builder = $dex.call_builder::<Invariant>();
+        let call = call_builder.migrate($batch_size);
+        let result = $client
+            .call(&$caller, &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value();
+
+        if result.is_ok() {
+            $client
+                .call(&$caller, &call)
+                .extra_gas_portion(1000)
+                .submit()
+                .await
+                .unwrap()
+                .return_value()
+        } else {
+            result
+        }
+    }};
+}
+
 #[macro_export]
 macro_rules! get_admin {
     ($client:ident, $dex:ident) => {{
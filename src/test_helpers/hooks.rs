@@ -0,0 +1,67 @@
+#[macro_export]
+macro_rules! create_mock_hooks {
+    ($client:ident) => {{
+        let mut constructor = MockHooksRef::new();
+        $client
+            .instantiate("mock_hooks", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed")
+    }};
+}
+
+#[macro_export]
+macro_rules! set_hook_veto_swaps {
+    ($client:ident, $hooks:ident, $veto_swaps:expr) => {{
+        let mut call_builder = $hooks.call_builder::<MockHooks>();
+        let call = call_builder.set_veto_swaps($veto_swaps);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .submit()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! set_hook_fail {
+    ($client:ident, $hooks:ident, $fail:expr) => {{
+        let mut call_builder = $hooks.call_builder::<MockHooks>();
+        let call = call_builder.set_fail($fail);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .submit()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! set_hook_burn_gas {
+    ($client:ident, $hooks:ident, $burn_gas:expr) => {{
+        let mut call_builder = $hooks.call_builder::<MockHooks>();
+        let call = call_builder.set_burn_gas($burn_gas);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .submit()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
+
+#[macro_export]
+macro_rules! get_hook_calls {
+    ($client:ident, $hooks:ident) => {{
+        let call_builder = $hooks.call_builder::<MockHooks>();
+        let call = call_builder.get_calls();
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .dry_run()
+            .await
+            .unwrap()
+            .return_value()
+    }};
+}
@@ -1,3 +1,4 @@
-pub mod entrypoints;
-pub mod snippets;
-pub mod token;
+pub mod entrypoints;
+pub mod hooks;
+pub mod snippets;
+pub mod token;
@@ -10,6 +10,33 @@ macro_rules! create_dex {
     }};
 }
 
+#[macro_export]
+macro_rules! create_legacy_dex {
+    ($client:ident, $entries:expr) => {{
+        let mut constructor = LegacyStorageRef::new();
+        let dex = $client
+            .instantiate("legacy_storage", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("instantiate failed");
+        let code_hash = $client
+            .upload("invariant", &ink_e2e::alice())
+            .submit()
+            .await
+            .expect("upload failed")
+            .code_hash;
+
+        let call_builder = dex.call_builder::<LegacyStorage>();
+        let call = call_builder.set_code_with_storage($entries, code_hash);
+        $client
+            .call(&ink_e2e::alice(), &call)
+            .submit()
+            .await
+            .expect("set code failed");
+        dex
+    }};
+}
+
 #[macro_export]
 macro_rules! create_tokens {
     ($client:ident, $token_x_supply:expr, $token_y_supply:expr) => {{
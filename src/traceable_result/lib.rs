@@ -2,6 +2,7 @@
 
 extern crate alloc;
 
+use alloc::borrow::Cow;
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -10,26 +11,85 @@ use core::any;
 
 pub type TrackableResult<T> = Result<T, TrackableError>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Overflow,
+    Underflow,
+    DivByZero,
+    Cast,
+    PriceOutOfRange,
+    Other,
+}
+
+// the message of an error along with its kind, plain messages are of the `Other` kind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cause {
+    pub kind: ErrorKind,
+    pub message: Cow<'static, str>,
+}
+
+impl Cause {
+    pub const fn new(kind: ErrorKind, message: &'static str) -> Self {
+        Self {
+            kind,
+            message: Cow::Borrowed(message),
+        }
+    }
+}
+
+impl From<&Cause> for Cause {
+    fn from(cause: &Cause) -> Self {
+        cause.clone()
+    }
+}
+
+impl From<&str> for Cause {
+    fn from(message: &str) -> Self {
+        Self {
+            kind: ErrorKind::Other,
+            message: Cow::Owned(message.to_string()),
+        }
+    }
+}
+
+impl From<&String> for Cause {
+    fn from(message: &String) -> Self {
+        message.as_str().into()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackableError {
+    pub kind: ErrorKind,
     pub cause: String,
     pub stack: Vec<String>,
 }
 
 impl TrackableError {
-    pub const ADD: &'static str = "addition overflow";
-    pub const SUB: &'static str = "subtraction underflow";
-    pub const MUL: &'static str = "multiplication overflow";
-    pub const DIV: &'static str = "division overflow or division by zero";
-    pub fn cast<T: ?Sized>() -> String {
-        format!("conversion to {} type failed", any::type_name::<T>())
+    pub const ADD: Cause = Cause::new(ErrorKind::Overflow, "addition overflow");
+    pub const SUB: Cause = Cause::new(ErrorKind::Underflow, "subtraction underflow");
+    pub const MUL: Cause = Cause::new(ErrorKind::Overflow, "multiplication overflow");
+    pub const DIV: Cause = Cause::new(
+        ErrorKind::DivByZero,
+        "division overflow or division by zero",
+    );
+    pub fn cast<T: ?Sized>() -> Cause {
+        Cause {
+            kind: ErrorKind::Cast,
+            message: Cow::Owned(format!(
+                "conversion to {} type failed",
+                any::type_name::<T>()
+            )),
+        }
     }
 }
 
 impl TrackableError {
-    pub fn new(cause: &str, location: &str) -> Self {
+    pub fn new(cause: impl Into<Cause>, location: &str) -> Self {
+        let cause = cause.into();
         Self {
-            cause: cause.to_string(),
+            kind: cause.kind,
+            cause: cause.message.into_owned(),
             stack: vec![location.to_string()],
         }
     }